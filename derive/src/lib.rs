@@ -0,0 +1,536 @@
+//! Derive macros for `unofficial_appwrite`. Use them through the crate's
+//! `derive` feature; see `unofficial_appwrite::schema` for the attributes.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, meta::ParseNestedMeta, parenthesized, parse_macro_input, spanned::Spanned, Data,
+    DeriveInput, Error, Expr, ExprArray, Fields, GenericArgument, Lit, LitBool, LitStr,
+    PathArguments, Result, Token, Type,
+};
+
+/// Implement `AppwriteCollection` for a struct with named fields and add a
+/// typed `Field` constant per attribute.
+#[proc_macro_derive(AppwriteCollection, attributes(appwrite))]
+pub fn derive_appwrite_collection(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct Collection {
+    id: Option<String>,
+    name: Option<String>,
+    document_security: bool,
+    rename_all: Option<String>,
+    indexes: Vec<TokenStream2>,
+}
+
+#[derive(Default)]
+struct Attribute {
+    key: Option<String>,
+    skip: bool,
+    required: Option<bool>,
+    array: bool,
+    kind: Option<String>,
+    size: Option<Expr>,
+    min: Option<Expr>,
+    max: Option<Expr>,
+    default: Option<Expr>,
+    elements: Vec<String>,
+    relationship: Option<TokenStream2>,
+    index: Option<LitStr>,
+}
+
+const KINDS: &[&str] = &[
+    "string", "integer", "float", "boolean", "datetime", "email", "url", "ip",
+];
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "AppwriteCollection can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            input.ident.span(),
+            "AppwriteCollection needs a struct with named fields",
+        ));
+    };
+
+    let mut collection = Collection {
+        id: None,
+        name: None,
+        document_security: false,
+        rename_all: None,
+        indexes: Vec::new(),
+    };
+    for attr in &input.attrs {
+        if attr.path().is_ident("appwrite") {
+            attr.parse_nested_meta(|meta| parse_collection(&mut collection, meta))?;
+        } else if attr.path().is_ident("serde") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") && meta.input.peek(Token![=]) {
+                    collection.rename_all = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    skip_meta(meta)
+                }
+            })?;
+        }
+    }
+
+    let mut attributes = Vec::new();
+    let mut field_indexes = Vec::new();
+    let mut constants = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("named field");
+        let mut attribute = Attribute::default();
+        for attr in &field.attrs {
+            if attr.path().is_ident("appwrite") {
+                attr.parse_nested_meta(|meta| parse_attribute(&mut attribute, meta))?;
+            } else if attr.path().is_ident("serde") {
+                let mut rename = None;
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") && meta.input.peek(Token![=]) {
+                        rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                        Ok(())
+                    } else if meta.path.is_ident("skip") {
+                        attribute.skip = true;
+                        Ok(())
+                    } else {
+                        skip_meta(meta)
+                    }
+                })?;
+                attribute.key = attribute.key.take().or(rename);
+            }
+        }
+        if attribute.skip {
+            continue;
+        }
+
+        let name = ident.unraw().to_string();
+        let key = match (attribute.key.take(), &collection.rename_all) {
+            (Some(key), _) => key,
+            (None, Some(rule)) => rename(&name, rule, field.span())?,
+            (None, None) => name.clone(),
+        };
+        let (ty, array) = strip(&field.ty);
+        let array = array || attribute.array;
+
+        let kind = attribute_kind(&attribute, ty)?;
+        let required = attribute
+            .required
+            .unwrap_or_else(|| option_inner(&field.ty).is_none() && attribute.default.is_none());
+        let default = attribute
+            .default
+            .as_ref()
+            .map(|default| quote!(.default_value(#default)));
+        attributes.push(quote! {
+            AttributeSchema::new(#key, #kind)
+                .required(#required)
+                .array(#array)
+                #default
+        });
+
+        if let Some(index) = &attribute.index {
+            let index_type = index_type(index)?;
+            let index_key = format!("{key}_index");
+            field_indexes.push(quote!(IndexSchema::new(#index_key, #index_type, [#key])));
+        }
+
+        let constant = format_ident!("{}", name.to_uppercase(), span = ident.span());
+        let field_ty = option_inner(&field.ty).unwrap_or(&field.ty);
+        let vis = &field.vis;
+        constants.push(quote! {
+            #vis const #constant: ::unofficial_appwrite::schema::Field<#field_ty> =
+                ::unofficial_appwrite::schema::Field::new(#key);
+        });
+    }
+
+    let ident = &input.ident;
+    let id = collection
+        .id
+        .unwrap_or_else(|| rename(&ident.to_string(), "snake_case", Span::call_site()).unwrap());
+    let name = collection.name.unwrap_or_else(|| ident.to_string());
+    let document_security = collection.document_security;
+    let indexes = collection.indexes.iter().chain(&field_indexes);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::unofficial_appwrite::schema::AppwriteCollection
+            for #ident #ty_generics #where_clause
+        {
+            fn schema() -> ::unofficial_appwrite::schema::CollectionSchema {
+                #[allow(unused_imports)]
+                use ::unofficial_appwrite::{
+                    enums::{
+                        index_type::IndexType, relation_mutate::RelationMutate,
+                        relationship_type::RelationshipType,
+                    },
+                    schema::{
+                        AttributeKind, AttributeSchema, CollectionSchema, IndexSchema,
+                        RelationshipSchema,
+                    },
+                };
+
+                CollectionSchema::new(#id, #name)
+                    .document_security(#document_security)
+                    #(.attribute(#attributes))*
+                    #(.index(#indexes))*
+            }
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#constants)*
+        }
+    })
+}
+
+fn parse_collection(collection: &mut Collection, meta: ParseNestedMeta) -> Result<()> {
+    if meta.path.is_ident("collection") {
+        collection.id = Some(meta.value()?.parse::<LitStr>()?.value());
+    } else if meta.path.is_ident("name") {
+        collection.name = Some(meta.value()?.parse::<LitStr>()?.value());
+    } else if meta.path.is_ident("document_security") {
+        collection.document_security = true;
+    } else if meta.path.is_ident("index") {
+        let (mut key, mut kind, mut attributes, mut orders) = (None, None, None, None);
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("key") {
+                key = Some(meta.value()?.parse::<LitStr>()?);
+            } else if meta.path.is_ident("type") {
+                kind = Some(index_type(&meta.value()?.parse::<LitStr>()?)?);
+            } else if meta.path.is_ident("attributes") {
+                attributes = Some(strings(&meta)?);
+            } else if meta.path.is_ident("orders") {
+                orders = Some(strings(&meta)?);
+            } else {
+                return Err(meta.error("expected `key`, `type`, `attributes` or `orders`"));
+            }
+            Ok(())
+        })?;
+        let Some(key) = key else {
+            return Err(meta.error("index needs a `key`"));
+        };
+        let Some(attributes) = attributes else {
+            return Err(meta.error("index needs `attributes`"));
+        };
+        let kind = kind.unwrap_or_else(|| quote!(IndexType::Key));
+        let orders = orders.map(|orders| quote!(.orders([#(#orders),*])));
+        collection.indexes.push(quote! {
+            IndexSchema::new(#key, #kind, [#(#attributes),*]) #orders
+        });
+    } else {
+        return Err(
+            meta.error("expected `collection`, `name`, `document_security` or `index(...)`")
+        );
+    }
+    Ok(())
+}
+
+fn parse_attribute(attribute: &mut Attribute, meta: ParseNestedMeta) -> Result<()> {
+    let path = &meta.path;
+    if path.is_ident("key") {
+        attribute.key = Some(meta.value()?.parse::<LitStr>()?.value());
+    } else if path.is_ident("skip") {
+        attribute.skip = true;
+    } else if path.is_ident("required") {
+        attribute.required = Some(match meta.input.peek(Token![=]) {
+            true => meta.value()?.parse::<LitBool>()?.value,
+            false => true,
+        });
+    } else if path.is_ident("array") {
+        attribute.array = true;
+    } else if path.is_ident("size") {
+        attribute.size = Some(meta.value()?.parse()?);
+    } else if path.is_ident("min") {
+        attribute.min = Some(meta.value()?.parse()?);
+    } else if path.is_ident("max") {
+        attribute.max = Some(meta.value()?.parse()?);
+    } else if path.is_ident("default") {
+        attribute.default = Some(meta.value()?.parse()?);
+    } else if path.is_ident("elements") {
+        attribute.elements = strings(&meta)?;
+        attribute.kind = Some(String::from("enum"));
+    } else if path.is_ident("index") {
+        attribute.index = Some(meta.value()?.parse()?);
+    } else if path.is_ident("relationship") {
+        attribute.relationship = Some(relationship(&meta)?);
+    } else if let Some(kind) = KINDS.iter().find(|kind| path.is_ident(kind)) {
+        attribute.kind = Some(kind.to_string());
+    } else {
+        return Err(meta.error("unknown `appwrite` field attribute"));
+    }
+    Ok(())
+}
+
+fn relationship(meta: &ParseNestedMeta) -> Result<TokenStream2> {
+    let (mut collection, mut kind, mut two_way, mut two_way_key) = (None, None, false, None);
+    let mut on_delete = quote!(RelationMutate::Restrict);
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("collection") {
+            collection = Some(meta.value()?.parse::<LitStr>()?);
+        } else if meta.path.is_ident("kind") {
+            let lit = meta.value()?.parse::<LitStr>()?;
+            kind = Some(match lit.value().as_str() {
+                "oneToOne" => quote!(RelationshipType::OneToOne),
+                "oneToMany" => quote!(RelationshipType::OneToMany),
+                "manyToOne" => quote!(RelationshipType::ManyToOne),
+                "manyToMany" => quote!(RelationshipType::ManyToMany),
+                _ => {
+                    return Err(Error::new(
+                        lit.span(),
+                        "expected `oneToOne`, `oneToMany`, `manyToOne` or `manyToMany`",
+                    ))
+                }
+            });
+        } else if meta.path.is_ident("two_way") {
+            two_way = true;
+        } else if meta.path.is_ident("two_way_key") {
+            two_way_key = Some(meta.value()?.parse::<LitStr>()?);
+        } else if meta.path.is_ident("on_delete") {
+            let lit = meta.value()?.parse::<LitStr>()?;
+            on_delete = match lit.value().as_str() {
+                "cascade" => quote!(RelationMutate::Cascade),
+                "restrict" => quote!(RelationMutate::Restrict),
+                "setNull" => quote!(RelationMutate::SetNull),
+                _ => {
+                    return Err(Error::new(
+                        lit.span(),
+                        "expected `cascade`, `restrict` or `setNull`",
+                    ))
+                }
+            };
+        } else {
+            return Err(meta
+                .error("expected `collection`, `kind`, `two_way`, `two_way_key` or `on_delete`"));
+        }
+        Ok(())
+    })?;
+    let Some(collection) = collection else {
+        return Err(meta.error("relationship needs a `collection`"));
+    };
+    let Some(kind) = kind else {
+        return Err(meta.error("relationship needs a `kind`"));
+    };
+    let two_way_key = match two_way_key {
+        Some(key) => quote!(Some(String::from(#key))),
+        None => quote!(None),
+    };
+    Ok(quote! {
+        AttributeKind::Relationship(RelationshipSchema {
+            related_collection: String::from(#collection),
+            relation_type: #kind,
+            two_way: #two_way,
+            two_way_key: #two_way_key,
+            on_delete: #on_delete,
+        })
+    })
+}
+
+fn attribute_kind(attribute: &Attribute, ty: &Type) -> Result<TokenStream2> {
+    if let Some(relationship) = &attribute.relationship {
+        return Ok(relationship.clone());
+    }
+    let kind = match &attribute.kind {
+        Some(kind) => kind.clone(),
+        None => infer_kind(ty)?,
+    };
+    let bound = |bound: &Option<Expr>, ty| match bound {
+        Some(bound) => quote!(Some((#bound) as #ty)),
+        None => quote!(None),
+    };
+    Ok(match kind.as_str() {
+        "string" => match &attribute.size {
+            Some(size) => quote!(AttributeKind::String { size: #size }),
+            None => quote! {
+                AttributeKind::String { size: ::unofficial_appwrite::schema::DEFAULT_STRING_SIZE }
+            },
+        },
+        "integer" => {
+            let (min, max) = (
+                bound(&attribute.min, quote!(i64)),
+                bound(&attribute.max, quote!(i64)),
+            );
+            quote!(AttributeKind::Integer { min: #min, max: #max })
+        }
+        "float" => {
+            let (min, max) = (
+                bound(&attribute.min, quote!(f64)),
+                bound(&attribute.max, quote!(f64)),
+            );
+            quote!(AttributeKind::Float { min: #min, max: #max })
+        }
+        "boolean" => quote!(AttributeKind::Boolean),
+        "datetime" => quote!(AttributeKind::Datetime),
+        "email" => quote!(AttributeKind::Email),
+        "url" => quote!(AttributeKind::Url),
+        "ip" => quote!(AttributeKind::Ip),
+        "enum" => {
+            let elements = &attribute.elements;
+            quote!(AttributeKind::Enum {
+                elements: vec![#(String::from(#elements)),*]
+            })
+        }
+        _ => unreachable!(),
+    })
+}
+
+fn infer_kind(ty: &Type) -> Result<String> {
+    let name = match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        Type::Reference(reference) => return infer_kind(&reference.elem),
+        _ => None,
+    };
+    let kind = match name.as_deref() {
+        Some("String" | "str") => "string",
+        Some("i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize") => {
+            "integer"
+        }
+        Some("f32" | "f64") => "float",
+        Some("bool") => "boolean",
+        Some("DateTime" | "NaiveDateTime" | "OffsetDateTime") => "datetime",
+        _ => {
+            return Err(Error::new(
+                ty.span(),
+                "can't infer the attribute type; add one of `string`, `integer`, `float`, \
+                 `boolean`, `datetime`, `email`, `url`, `ip`, `elements = [...]` or \
+                 `relationship(...)`",
+            ))
+        }
+    };
+    Ok(String::from(kind))
+}
+
+/// The type inside `Option<_>` and `Vec<_>`, and whether it was a `Vec`.
+fn strip(ty: &Type) -> (&Type, bool) {
+    let ty = option_inner(ty).unwrap_or(ty);
+    match generic_inner(ty, "Vec") {
+        Some(inner) => (option_inner(inner).unwrap_or(inner), true),
+        None => (ty, false),
+    }
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, "Option")
+}
+
+fn generic_inner<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+fn index_type(lit: &LitStr) -> Result<TokenStream2> {
+    match lit.value().as_str() {
+        "key" => Ok(quote!(IndexType::Key)),
+        "unique" => Ok(quote!(IndexType::Unique)),
+        "fulltext" => Ok(quote!(IndexType::Fulltext)),
+        _ => Err(Error::new(
+            lit.span(),
+            "expected `key`, `unique` or `fulltext`",
+        )),
+    }
+}
+
+/// `name = ["a", "b"]`.
+fn strings(meta: &ParseNestedMeta) -> Result<Vec<String>> {
+    let array = meta.value()?.parse::<ExprArray>()?;
+    array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Str(lit) => Ok(lit.value()),
+                lit => Err(Error::new(lit.span(), "expected a string")),
+            },
+            elem => Err(Error::new(elem.span(), "expected a string")),
+        })
+        .collect()
+}
+
+/// Consume a `serde` item this macro doesn't read.
+fn skip_meta(meta: ParseNestedMeta) -> Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in meta.input);
+        content.parse::<TokenStream2>()?;
+    }
+    Ok(())
+}
+
+/// Apply a `serde` `rename_all` rule to a snake_case field or PascalCase
+/// struct name.
+fn rename(name: &str, rule: &str, span: Span) -> Result<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for c in name.chars() {
+        if c == '_' || (c.is_uppercase() && !word.is_empty()) {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            if c == '_' {
+                continue;
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    let capitalize = |word: &String| {
+        let mut chars = word.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+            .unwrap_or_default()
+    };
+    Ok(match rule {
+        "lowercase" => words.concat(),
+        "UPPERCASE" => words.concat().to_uppercase(),
+        "PascalCase" => words.iter().map(capitalize).collect(),
+        "camelCase" => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.clone()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+        "snake_case" => words.join("_"),
+        "SCREAMING_SNAKE_CASE" => words.join("_").to_uppercase(),
+        "kebab-case" => words.join("-"),
+        "SCREAMING-KEBAB-CASE" => words.join("-").to_uppercase(),
+        _ => {
+            return Err(Error::new(
+                span,
+                format!("unknown `rename_all` rule `{rule}`"),
+            ))
+        }
+    })
+}
@@ -0,0 +1,23 @@
+//! # Account
+//!
+//! Blocking counterpart of [`crate::services::server::account`].
+
+use serde_json::Value;
+
+use crate::{
+    blocking::{blocking_service, Client},
+    enums::{
+        authentication_factor::AuthenticationFactor, authentication_type::AuthenticationType,
+        o_auth_provider::OAuthProvider,
+    },
+    error::Error,
+    models::{
+        identity::Identity, identity_list::IdentityList, jwt::JWT, log::Log, log_list::LogList,
+        mfa_challenge::MfaChallenge, mfa_factors::MfaFactors, mfa_recovery_codes::MfaRecoveryCodes,
+        mfa_type::MfaType, preferences::Preferences, session::Session, session_list::SessionList,
+        token::Token, user::User,
+    },
+    query::Query,
+};
+
+crate::endpoints::account!(blocking_service!(Account));
@@ -0,0 +1,11 @@
+//! # Avatars
+//!
+//! Blocking counterpart of [`crate::services::server::avatar`].
+
+use crate::{
+    blocking::{blocking_service, Client},
+    enums::flag::Flag,
+    error::Error,
+};
+
+crate::endpoints::avatar!(blocking_service!(Avatars));
@@ -0,0 +1,29 @@
+//! # Databases
+//!
+//! Blocking counterpart of [`crate::services::server::databases`].
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    blocking::{blocking_service, Client},
+    enums::{
+        index_type::IndexType, relation_mutate::RelationMutate, relationship_type::RelationshipType,
+    },
+    error::Error,
+    models::{
+        attribute_boolean::AttributeBoolean, attribute_datetime::AttributeDateTime,
+        attribute_email::AttributeEmail, attribute_enum::AttributeEnum,
+        attribute_float::AttributeFloat, attribute_integer::AttributeInteger,
+        attribute_ip::AttributeIp, attribute_list::AttributeList,
+        attribute_relationship::AttributeRelationship, attribute_string::AttributeString,
+        attribute_url::AttributeUrl, collection::Collection, collection_list::CollectionList,
+        database::Database, database_list::DatabaseList, document::Document,
+        document_list::DocumentList, index::Index, index_list::IndexList,
+        typed_document::TypedDocument, typed_document_list::TypedDocumentList,
+    },
+    query::Query,
+    wait::WaitOptions,
+};
+
+crate::endpoints::databases!(blocking_service!(Databases));
@@ -0,0 +1,50 @@
+//! # Functions
+//!
+//! Blocking counterpart of [`crate::services::server::functions`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    blocking::{blocking_service, Client},
+    enums::{execution_method::ExecutionMethod, runtime::Runtime},
+    error::Error,
+    models::UploadType,
+    models::{
+        deployment::Deployment, deployment_list::DeploymentList, execution::Execution,
+        execution_list::ExecutionList, function::Func, function_list::FunctionList,
+        runtime_list::RuntimeList, variable::Variable, variable_list::VariableList,
+    },
+    query::Query,
+    upload_progress::UploadProgress,
+};
+
+crate::endpoints::functions!(blocking_service!(Functions));
+
+impl Functions {
+    /// Blocking version of [`crate::services::server::functions::Functions::create_deployments_streamed`].
+    ///
+    /// Yields the upload progress after every chunk.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_deployments_streamed<'a>(
+        client: &'a Client,
+        function_id: &'a str,
+        file_path: &'a str,
+        file_name: String,
+        activate: bool,
+        entrypoint: Option<&'a str>,
+        commands: Option<&'a str>,
+    ) -> impl Iterator<Item = Result<(UploadType, UploadProgress), Error>> + 'a {
+        let stream = client.block_on(
+            crate::services::server::functions::Functions::create_deployments_streamed(
+                client.as_async(),
+                function_id,
+                file_path,
+                file_name,
+                activate,
+                entrypoint,
+                commands,
+            ),
+        );
+        client.iter(stream)
+    }
+}
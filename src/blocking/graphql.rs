@@ -0,0 +1,12 @@
+//! # Graphql
+//!
+//! Blocking counterpart of [`crate::services::server::graphql`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    blocking::{blocking_service, Client},
+    error::Error,
+};
+
+crate::endpoints::graphql!(blocking_service!(Graphql));
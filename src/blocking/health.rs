@@ -0,0 +1,15 @@
+//! # Health
+//!
+//! Blocking counterpart of [`crate::services::server::health`].
+
+use crate::{
+    blocking::{blocking_service, Client},
+    enums::name::Name,
+    error::Error,
+    models::{
+        health_antivirus::HealthAntivirus, health_certificate::HealthCertificate,
+        health_queue::HealthQueue, health_status::HealthStatus, health_time::HealthTime,
+    },
+};
+
+crate::endpoints::health!(blocking_service!(Health));
@@ -0,0 +1,15 @@
+//! # Locale
+//!
+//! Blocking counterpart of [`crate::services::server::locale`].
+
+use crate::{
+    blocking::{blocking_service, Client},
+    error::Error,
+    models::{
+        continent_list::ContinentList, country_list::CountryList, currency_list::CurrencyList,
+        language_list::LanguageList, locale::Locale as MLocale, locale_code::LocaleCode,
+        phone_list::PhoneList,
+    },
+};
+
+crate::endpoints::locale!(blocking_service!(Locale));
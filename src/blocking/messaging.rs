@@ -0,0 +1,20 @@
+//! # Messaging
+//!
+//! Blocking counterpart of [`crate::services::server::messaging`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    blocking::{blocking_service, Client},
+    enums::smtp_encryption::SmtpEncryption,
+    error::Error,
+    models::{
+        log::Log, log_list::LogList, message::Message, message_list::MessageList,
+        provider::Provider, provider_list::ProviderList, subscriber::Subscriber,
+        subscriber_list::SubscriberList, target::Target, target_list::TargetList, topic::Topic,
+        topic_list::TopicList,
+    },
+    query::Query,
+};
+
+crate::endpoints::messaging!(blocking_service!(Messaging));
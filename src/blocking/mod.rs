@@ -0,0 +1,226 @@
+//! # Blocking
+//!
+//! Synchronous counterparts of the services in [`crate::services::server`],
+//! for code that can't host a tokio runtime (CLI tools, build scripts).
+//!
+//! Every call takes a [`blocking::Client`](Client) and drives the async
+//! implementation to completion on a private runtime, so models and errors are
+//! the same as in the async API. Don't use these from inside an async context:
+//! blocking on a runtime from within another one panics. The paginated
+//! `*_stream` endpoints return a [`BlockingIter`] instead of a stream.
+//!
+//! ```no_run
+//! use unofficial_appwrite::blocking::{users::Users, Client};
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//!
+//! fn main() -> Result<(), Error> {
+//!     let client = ClientBuilder::default()
+//!         .set_project("5ff3...")?
+//!         .set_key("cd868c7af8bdc893b4...93b7535db89")?
+//!         .build_blocking()?;
+//!
+//!     let users = Users::list(&client, None, None)?;
+//!     dbg!(users);
+//!     Ok(())
+//! }
+//! ```
+
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use futures_util::{Stream, StreamExt};
+use tokio::runtime::Runtime;
+
+use crate::{client::ClientBuilder, error::Error};
+
+pub mod account;
+pub mod avatar;
+pub mod databases;
+pub mod functions;
+pub mod graphql;
+pub mod health;
+pub mod locale;
+pub mod messaging;
+pub mod storage;
+pub mod teams;
+pub mod users;
+
+/// Blocking Appwrite client. Cloning is cheap: clones share the connection
+/// pool and the runtime.
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: crate::client::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Wrap an async [`Client`](crate::client::Client).
+    pub fn new(client: crate::client::Client) -> Result<Self, Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client this blocking client drives.
+    pub fn as_async(&self) -> &crate::client::Client {
+        &self.inner
+    }
+
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Turn an async stream into an iterator that blocks for every item.
+    pub(crate) fn iter<'a, T: 'a>(
+        &'a self,
+        stream: impl Stream<Item = T> + 'a,
+    ) -> BlockingIter<'a, T> {
+        BlockingIter {
+            runtime: &self.runtime,
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Build a [`blocking::Client`](Client).
+    pub fn build_blocking(&self) -> Result<Client, Error> {
+        Client::new(self.build()?)
+    }
+}
+
+/// Iterator over an async stream, blocking the current thread for every item.
+pub struct BlockingIter<'a, T> {
+    runtime: &'a Runtime,
+    stream: Pin<Box<dyn Stream<Item = T> + 'a>>,
+}
+
+impl<T> Iterator for BlockingIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+/// Declare a blocking service whose functions forward to the async service
+/// of the same name.
+macro_rules! blocking_service {
+    (
+        $service:ident => $async_service:ty {
+            $(
+                fn $name:ident $( < $gen:ident : $bound:ident $( + $bounds:ident )* > )?
+                    ( $( $arg:ident : $ty:ty ),* $(,)? ) -> $ret:ty;
+            )*
+            $(
+                stream fn $stream:ident < $lt:lifetime $( , $sgen:ident : $sbound:ident $( + $sbounds:ident )* )? >
+                    ( $( $sarg:ident : $sty:ty ),* $(,)? ) -> $item:ty;
+            )*
+        }
+    ) => {
+        pub struct $service;
+
+        impl $service {
+            $(
+                #[allow(clippy::too_many_arguments)]
+                #[doc = concat!("Blocking version of [`", stringify!($async_service), "::", stringify!($name), "`].")]
+                pub fn $name $( < $gen: $bound $( + $bounds )* > )? (
+                    client: &Client,
+                    $( $arg: $ty ),*
+                ) -> Result<$ret, Error> {
+                    client.block_on(<$async_service>::$name(client.as_async(), $( $arg ),*))
+                }
+            )*
+
+            $(
+                #[doc = concat!("Blocking version of [`", stringify!($async_service), "::", stringify!($stream), "`].")]
+                pub fn $stream<$lt $( , $sgen: $sbound $( + $sbounds )* + $lt )?>(
+                    client: &$lt Client,
+                    $( $sarg: $sty ),*
+                ) -> $crate::blocking::BlockingIter<$lt, Result<$item, Error>> {
+                    client.iter(<$async_service>::$stream(client.as_async(), $( $sarg ),*))
+                }
+            )*
+        }
+    };
+}
+pub(crate) use blocking_service;
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use serde_json::{json, Map, Value};
+
+    use super::*;
+    use crate::transport::{CannedTransport, HttpResponse};
+
+    /// Answers every request with a body every model used below can be read
+    /// from.
+    fn any_response() -> CannedTransport {
+        CannedTransport::new().fallback(HttpResponse::from_json(
+            StatusCode::OK,
+            &json!({
+                "name": "en",
+                "code": "en",
+                "ping": 1,
+                "status": "pass",
+                "total": 0,
+                "teams": [],
+                "documents": [],
+            }),
+        ))
+    }
+
+    #[test]
+    fn test_one_call_per_service() {
+        let transport = any_response();
+        let client = ClientBuilder::default()
+            .set_transport(transport.clone())
+            .unwrap()
+            .build_blocking()
+            .unwrap();
+
+        account::Account::delete_session(&client, "current").unwrap();
+        avatar::Avatars::get_fav_icon(&client, "https://example.com").unwrap();
+        databases::Databases::delete(&client, "blog").unwrap();
+        functions::Functions::delete(&client, "resize").unwrap();
+        graphql::Graphql::query(&client, Map::new()).unwrap();
+        health::Health::get_db(&client).unwrap();
+        locale::Locale::list_codes(&client).unwrap();
+        messaging::Messaging::delete_topic(&client, "news").unwrap();
+        storage::Storage::delete_file(&client, "photos", "cat").unwrap();
+        teams::Teams::delete(&client, "staff").unwrap();
+        users::Users::delete(&client, "alice").unwrap();
+        assert_eq!(
+            teams::Teams::list_stream(&client, None, None, None).count(),
+            0
+        );
+        let posts = databases::Databases::list_documents_as_stream::<Map<String, Value>>(
+            &client, "blog", "posts", None, None,
+        );
+        assert_eq!(posts.count(), 0);
+
+        assert_eq!(
+            transport.paths(),
+            [
+                "/v1/account/sessions/current",
+                "/v1/avatars/favicon",
+                "/v1/databases/blog",
+                "/v1/functions/resize",
+                "/v1/graphql",
+                "/v1/health/db",
+                "/v1/locale/codes",
+                "/v1/messaging/topics/news",
+                "/v1/storage/buckets/photos/files/cat",
+                "/v1/teams/staff",
+                "/v1/users/alice",
+                "/v1/teams",
+                "/v1/databases/blog/collections/posts/documents",
+            ]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! # Storage
+//!
+//! Blocking counterpart of [`crate::services::server::storage`].
+
+use crate::{
+    blocking::{blocking_service, Client},
+    enums::{compression::Compression, image_format::ImageFormat, image_gravity::ImageGravity},
+    error::Error,
+    models::UploadType,
+    models::{bucket::Bucket, bucket_list::BucketList, file::File, file_list::FileList},
+    query::Query,
+    upload_progress::UploadProgress,
+};
+
+crate::endpoints::storage!(blocking_service!(Storage));
+
+impl Storage {
+    /// Blocking version of [`crate::services::server::storage::Storage::create_files_streamed`].
+    ///
+    /// Yields the upload progress after every chunk.
+    pub fn create_files_streamed<'a>(
+        client: &'a Client,
+        bucket_id: &'a str,
+        file_id: &'a str,
+        file_path: &'a str,
+        file_name: String,
+        permissions: Option<Vec<String>>,
+    ) -> impl Iterator<Item = Result<(UploadType, UploadProgress), Error>> + 'a {
+        let stream = client.block_on(
+            crate::services::server::storage::Storage::create_files_streamed(
+                client.as_async(),
+                bucket_id,
+                file_id,
+                file_path,
+                file_name,
+                permissions,
+            ),
+        );
+        client.iter(stream)
+    }
+}
@@ -0,0 +1,17 @@
+//! # Teams
+//!
+//! Blocking counterpart of [`crate::services::server::teams`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    blocking::{blocking_service, Client},
+    error::Error,
+    models::{
+        membership::Membership, membership_list::MembershipList, preferences::Preferences,
+        team::Team, team_list::TeamList,
+    },
+    query::Query,
+};
+
+crate::endpoints::teams!(blocking_service!(Teams));
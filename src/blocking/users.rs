@@ -0,0 +1,24 @@
+//! # Users
+//!
+//! Blocking counterpart of [`crate::services::server::users`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    blocking::{blocking_service, Client},
+    enums::{
+        authentication_type::AuthenticationType, messaging_provider_type::MessagingProviderType,
+        password_hash::PasswordHash,
+    },
+    error::Error,
+    models::{
+        identity::Identity, identity_list::IdentityList, log::Log, log_list::LogList,
+        membership_list::MembershipList, mfa_factors::MfaFactors,
+        mfa_recovery_codes::MfaRecoveryCodes, preferences::Preferences, session::Session,
+        session_list::SessionList, target::Target, target_list::TargetList, token::Token,
+        user::User, user_list::UserList,
+    },
+    query::Query,
+};
+
+crate::endpoints::users!(blocking_service!(Users));
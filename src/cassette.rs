@@ -0,0 +1,460 @@
+//! # Cassettes
+//!
+//! Record real Appwrite exchanges once and replay them offline, e.g. in CI.
+//!
+//! A cassette is a JSON (or, for `.yaml` / `.yml` paths, YAML) file holding
+//! one [`Interaction`] per request. Credentials are scrubbed while recording:
+//! the `x-appwrite-key`, `x-appwrite-jwt` and `x-appwrite-session` headers,
+//! cookies, sensitive query parameters and `password` / `secret` / `jwt`
+//! fields in bodies are replaced with `[REDACTED]`.
+//!
+//! ```no_run
+//! use unofficial_appwrite::cassette::CassetteMode;
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//! use unofficial_appwrite::services::server::users::Users;
+//!
+//! # async fn run() -> Result<(), Error> {
+//! // Set `CassetteMode::Record` (and a real key) once to refresh the recording.
+//! let client = ClientBuilder::default()
+//!     .set_project("5ff3...")?
+//!     .set_cassette("tests/cassettes/users.json", CassetteMode::Replay)?
+//!     .build()?;
+//!
+//! let users = Users::list(&client, None, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use futures_util::future::BoxFuture;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use url::Url;
+
+use crate::{
+    error::Error,
+    transport::{HttpRequest, HttpResponse, MultipartPart, RequestBody, Transport},
+    utils::SENSITIVE_PARAMS,
+};
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Headers whose values are never written to a cassette.
+const SENSITIVE_HEADERS: &[&str] = &[
+    "x-appwrite-key",
+    "x-appwrite-jwt",
+    "x-appwrite-session",
+    "authorization",
+    "cookie",
+    "set-cookie",
+    "x-fallback-cookies",
+];
+
+/// Body fields whose values are never written to a cassette.
+const SENSITIVE_FIELDS: &[&str] = &["password", "secret", "jwt"];
+
+/// What [`ClientBuilder::set_cassette`](crate::client::ClientBuilder::set_cassette)
+/// does with the cassette file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests as usual and append every exchange to the file,
+    /// replacing what it held before.
+    Record,
+    /// Answer requests from the file without touching the network.
+    Replay,
+}
+
+/// A recorded list of request/response exchanges.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+/// One request/response exchange.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// The parts of a request that are recorded. Everything but `headers` is used
+/// to match replayed requests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// URL path, e.g. `/v1/users`.
+    pub path: String,
+    /// Decoded query parameters, sorted by name.
+    #[serde(default)]
+    pub query: Vec<(String, String)>,
+    #[serde(default)]
+    pub body: Value,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    pub body: RecordedBody,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordedBody {
+    Empty,
+    Json(Value),
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl Cassette {
+    /// Read a cassette, as YAML when `path` ends in `.yaml` or `.yml` and as
+    /// JSON otherwise.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|err| {
+            Error::Cassette(format!("cannot read cassette {}: {err}", path.display()))
+        })?;
+        let invalid = |err: &dyn std::fmt::Display| {
+            Error::Cassette(format!("invalid cassette {}: {err}", path.display()))
+        };
+        match is_yaml(path) {
+            true => serde_yaml::from_str(&content).map_err(|err| invalid(&err)),
+            false => serde_json::from_str(&content).map_err(|err| invalid(&err)),
+        }
+    }
+
+    /// Write the cassette, in the format [`load`](Self::load) expects for `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let content = match is_yaml(path) {
+            true => serde_yaml::to_string(self).map_err(|err| Error::Cassette(err.to_string()))?,
+            false => serde_json::to_string_pretty(self)
+                .map_err(|err| Error::Cassette(err.to_string()))?,
+        };
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        Ok(fs::write(path, content)?)
+    }
+}
+
+fn is_yaml(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml" | "yml")
+    )
+}
+
+impl RecordedRequest {
+    /// The scrubbed, normalized form of `request`.
+    pub fn from_request(request: &HttpRequest) -> Result<Self, Error> {
+        let url = Url::parse(&request.url)
+            .map_err(|err| Error::Cassette(format!("invalid url {}: {err}", request.url)))?;
+        let mut query = url
+            .query_pairs()
+            .map(|(key, value)| {
+                let value = match SENSITIVE_PARAMS.contains(&key.to_lowercase().as_str()) {
+                    true => REDACTED.to_string(),
+                    false => value.into_owned(),
+                };
+                (key.into_owned(), value)
+            })
+            .collect::<Vec<_>>();
+        query.sort_by(|a, b| a.0.cmp(&b.0));
+        let body = match &request.body {
+            RequestBody::Empty => Value::Null,
+            RequestBody::Json(body) => scrub_value(body.clone()),
+            RequestBody::Multipart(form) => form
+                .parts
+                .iter()
+                .map(|part| match part {
+                    MultipartPart::Text { name, value } => json!({"name": name, "value": value}),
+                    MultipartPart::File {
+                        name,
+                        file_name,
+                        bytes,
+                    } => json!({"name": name, "fileName": file_name, "size": bytes.len()}),
+                })
+                .collect(),
+        };
+        Ok(Self {
+            method: format!("{:?}", request.method),
+            path: url.path().to_string(),
+            query,
+            body,
+            headers: scrub_headers(&request.headers),
+        })
+    }
+
+    fn matches(&self, other: &Self) -> bool {
+        self.method == other.method
+            && self.path == other.path
+            && self.query == other.query
+            && self.body == other.body
+    }
+
+    fn describe(&self) -> String {
+        let query = self
+            .query
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join("&");
+        match query.is_empty() {
+            true => format!("{} {}", self.method, self.path),
+            false => format!("{} {}?{query}", self.method, self.path),
+        }
+    }
+}
+
+impl RecordedResponse {
+    fn from_response(response: &HttpResponse) -> Self {
+        let body = if response.body.is_empty() {
+            RecordedBody::Empty
+        } else if let Ok(body) = serde_json::from_slice::<Value>(&response.body) {
+            RecordedBody::Json(scrub_value(body))
+        } else if let Ok(text) = String::from_utf8(response.body.clone()) {
+            RecordedBody::Text(text)
+        } else {
+            RecordedBody::Bytes(response.body.clone())
+        };
+        Self {
+            status: response.status.as_u16(),
+            headers: scrub_headers(&response.headers),
+            body,
+        }
+    }
+
+    fn to_response(&self) -> Result<HttpResponse, Error> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|_| Error::Cassette(format!("invalid header name {name}")))?,
+                HeaderValue::from_str(value)?,
+            );
+        }
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(self.status)
+                .map_err(|_| Error::Cassette(format!("invalid status {}", self.status)))?,
+            headers,
+            body: match &self.body {
+                RecordedBody::Empty => Vec::new(),
+                RecordedBody::Json(body) => serde_json::to_vec(body)?,
+                RecordedBody::Text(text) => text.as_bytes().to_vec(),
+                RecordedBody::Bytes(bytes) => bytes.clone(),
+            },
+        })
+    }
+}
+
+fn scrub_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = match SENSITIVE_HEADERS.contains(&name.as_str()) {
+                true => REDACTED,
+                false => value.to_str().ok()?,
+            };
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn scrub_value(value: Value) -> Value {
+    match value {
+        Value::Object(map) => map
+            .into_iter()
+            .map(
+                |(key, value)| match SENSITIVE_FIELDS.contains(&key.as_str()) {
+                    true => (key, json!(REDACTED)),
+                    false => (key, scrub_value(value)),
+                },
+            )
+            .collect(),
+        Value::Array(values) => values.into_iter().map(scrub_value).collect(),
+        value => value,
+    }
+}
+
+/// [`Transport`] that forwards to another transport and writes every exchange
+/// to a cassette file as it happens.
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl RecordingTransport {
+    pub fn new(inner: Arc<dyn Transport>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+            cassette: Mutex::default(),
+        }
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            let recorded = RecordedRequest::from_request(&request)?;
+            let response = self.inner.send(request).await?;
+            let mut cassette = self.cassette.lock().unwrap_or_else(|err| err.into_inner());
+            cassette.interactions.push(Interaction {
+                request: recorded,
+                response: RecordedResponse::from_response(&response),
+            });
+            cassette.save(&self.path)?;
+            Ok(response)
+        })
+    }
+}
+
+/// [`Transport`] that answers from a [`Cassette`] and fails with
+/// [`Error::Cassette`] on any request that wasn't recorded.
+///
+/// Every interaction is replayed at most once, in recording order, so the
+/// same request recorded twice can get two different responses.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    cassette: Cassette,
+    used: Mutex<HashSet<usize>>,
+}
+
+impl ReplayTransport {
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            cassette,
+            used: Mutex::default(),
+        }
+    }
+
+    fn replay(&self, request: &HttpRequest) -> Result<HttpResponse, Error> {
+        let recorded = RecordedRequest::from_request(request)?;
+        let mut used = self.used.lock().unwrap_or_else(|err| err.into_inner());
+        let found = self
+            .cassette
+            .interactions
+            .iter()
+            .enumerate()
+            .find(|(i, interaction)| !used.contains(i) && interaction.request.matches(&recorded));
+        let Some((i, interaction)) = found else {
+            let unused = (0..self.cassette.interactions.len())
+                .filter(|i| !used.contains(i))
+                .map(|i| self.cassette.interactions[i].request.describe())
+                .collect::<Vec<_>>();
+            return Err(Error::Cassette(format!(
+                "no recorded interaction matches {} (body: {}); unused recordings: [{}]",
+                recorded.describe(),
+                recorded.body,
+                unused.join(", ")
+            )));
+        };
+        used.insert(i);
+        interaction.response.to_response()
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move { self.replay(&request) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderValue, SET_COOKIE};
+
+    use crate::{
+        client::ClientBuilder, services::server::users::Users, transport::CannedTransport,
+    };
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let fake_appwrite = CannedTransport::new().respond(
+            "/users",
+            HttpResponse::from_json(
+                StatusCode::OK,
+                &serde_json::json!({"total": 0, "users": []}),
+            )
+            .with_header(SET_COOKIE, HeaderValue::from_static("a_session=abc")),
+        );
+        let path =
+            std::env::temp_dir().join(format!("appwrite-cassette-{}.json", uuid::Uuid::new_v4()));
+
+        let client = ClientBuilder::default()
+            .set_project("5ff3...")
+            .unwrap()
+            .set_key("secret-key")
+            .unwrap()
+            .set_transport(RecordingTransport::new(Arc::new(fake_appwrite), &path))
+            .unwrap()
+            .build()
+            .unwrap();
+        let users = Users::list(&client, None, Some("ada".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(users.total, 0);
+
+        let cassette = Cassette::load(&path).unwrap();
+        let request = &cassette.interactions[0].request;
+        assert_eq!(request.path, "/v1/users");
+        assert_eq!(
+            request.query,
+            vec![("search".to_string(), "ada".to_string())]
+        );
+        assert_eq!(request.headers["x-appwrite-key"], REDACTED);
+        assert_eq!(
+            cassette.interactions[0].response.headers["set-cookie"],
+            REDACTED
+        );
+
+        let client = ClientBuilder::default()
+            .set_project("5ff3...")
+            .unwrap()
+            .set_cassette(&path, CassetteMode::Replay)
+            .unwrap()
+            .build()
+            .unwrap();
+        let users = Users::list(&client, None, Some("ada".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(users.total, 0);
+        let err = Users::list(&client, None, Some("ada".to_string()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Cassette(_)));
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let dir = std::env::temp_dir();
+        for name in ["invalid.json", "invalid.yaml"] {
+            let path = dir.join(format!("appwrite-cassette-{}-{name}", uuid::Uuid::new_v4()));
+            fs::write(&path, "interactions: [").unwrap();
+            let err = Cassette::load(&path).unwrap_err();
+            assert!(matches!(err, Error::Cassette(_)), "{name}: {err:?}");
+            fs::remove_file(path).unwrap();
+        }
+    }
+}
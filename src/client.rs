@@ -1,30 +1,100 @@
-use std::{fs, str::FromStr};
+use std::{
+    fs,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use async_fn_stream::try_fn_stream;
 use futures_util::Stream;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
-    multipart::{self, Form, Part},
-    Response, StatusCode,
+    StatusCode,
 };
 use serde::Serialize;
 use serde_json::{json, Value};
 use uuid::Uuid;
 
+#[cfg(feature = "cassette")]
+use crate::cassette::{Cassette, CassetteMode, RecordingTransport, ReplayTransport};
 use crate::{
+    cookie::CookieJar,
     enumm::HttpMethod,
     error::{AppWriteError, Error},
+    middleware::{Middleware, MiddlewareStack, RequestParts, ResponseParts},
     models::{deployment::Deployment, file::File, UploadType},
+    query::{self, QuerySyntax},
+    retry::RetryPolicy,
+    transport::{
+        HttpRequest, HttpResponse, MultipartForm, RequestBody, ReqwestTransport, Transport,
+    },
     upload_progress::UploadProgress,
+    utils::trace_event,
+    version::{ServerVersion, VersionCheck, MAX_SUPPORTED_EXCLUSIVE},
 };
 
+/// Everything but the RFC 3986 unreserved characters, like `encodeURIComponent`.
+const QUERY_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// How much of an unexpected response body [`Error::UnexpectedResponse`] keeps.
+const BODY_EXCERPT_CHARS: usize = 512;
+
+/// Appwrite client.
+///
+/// Requests go through a [`Transport`], by default a pooled HTTP client. The
+/// configuration is shared, so cloning a `Client` or deriving a scoped view
+/// with [`with_project`](Self::with_project), [`with_locale`](Self::with_locale),
+/// [`with_jwt`](Self::with_jwt), [`with_cookie_jar`](Self::with_cookie_jar) or
+/// [`impersonate_user`](Self::impersonate_user) is cheap and every view reuses
+/// the same connections.
 #[derive(Debug, Clone)]
 pub struct Client {
+    config: Arc<ClientConfig>,
+    /// Headers changed by scoped views, applied in order over the configured
+    /// ones. `None` removes the header.
+    overrides: Vec<(HeaderName, Option<HeaderValue>)>,
+    /// Cookie jar of a [`with_cookie_jar`](Self::with_cookie_jar) view.
+    cookies: Option<CookieJar>,
+}
+
+/// Everything a [`Client`] and its scoped views share.
+#[derive(Debug)]
+struct ClientConfig {
     end_point: String,
-    pub end_point_realtime: Option<String>, //todo set this
-    pub header: HeaderMap,
+    end_point_realtime: Option<String>,
+    header: HeaderMap,
     chunk_size: usize,
     self_signed: bool,
+    tls: TlsConfig,
+    retry: RetryPolicy,
+    middleware: MiddlewareStack,
+    transport: Arc<dyn Transport>,
+    version_check: VersionCheck,
+    query_syntax: QuerySyntax,
+    /// Last server version probe, `None` before the first request.
+    server_version: tokio::sync::Mutex<Option<VersionProbe>>,
+}
+
+/// Wait after a failed server version probe, doubled on every failure.
+const VERSION_PROBE_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Longest wait between failed server version probes.
+const MAX_VERSION_PROBE_BACKOFF: Duration = Duration::from_secs(30 * 60);
+
+/// Outcome of a server version probe.
+#[derive(Debug, Clone, Copy)]
+enum VersionProbe {
+    Known(ServerVersion),
+    /// The probe failed; don't probe again before `retry_at`.
+    Failed {
+        retry_at: Instant,
+        backoff: Duration,
+    },
 }
 
 #[derive(Clone)]
@@ -34,6 +104,32 @@ pub struct ClientBuilder {
     pub header: HeaderMap,
     chunk_size: Option<usize>,
     self_signed: Option<bool>,
+    tls: TlsConfig,
+    retry: RetryPolicy,
+    middleware: MiddlewareStack,
+    transport: Option<Arc<dyn Transport>>,
+    version_check: VersionCheck,
+    query_syntax: QuerySyntax,
+    #[cfg(feature = "cassette")]
+    cassette: Option<(std::path::PathBuf, CassetteMode)>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    pool_idle_timeout: Option<Duration>,
+    http2_prior_knowledge: bool,
+}
+
+/// Certificate material used for both the REST and the realtime connections.
+#[derive(Debug, Clone, Default)]
+struct TlsConfig {
+    /// Extra trusted roots, each entry a PEM bundle of one or more certificates.
+    root_certificates: Vec<Vec<u8>>,
+    identity: Option<ClientIdentity>,
+}
+
+#[derive(Debug, Clone)]
+enum ClientIdentity {
+    Pkcs8Pem { cert: Vec<u8>, key: Vec<u8> },
+    Pkcs12Der { der: Vec<u8>, password: String },
 }
 
 impl Default for ClientBuilder {
@@ -44,6 +140,18 @@ impl Default for ClientBuilder {
             header: HeaderMap::new(),
             chunk_size: Some(5 * 1024 * 1024),
             self_signed: Some(false),
+            tls: TlsConfig::default(),
+            retry: RetryPolicy::none(),
+            middleware: MiddlewareStack::default(),
+            transport: None,
+            version_check: VersionCheck::Off,
+            query_syntax: QuerySyntax::Json,
+            #[cfg(feature = "cassette")]
+            cassette: None,
+            connect_timeout: None,
+            timeout: None,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            http2_prior_knowledge: false,
         }
     }
 }
@@ -54,10 +162,47 @@ impl ClientBuilder {
             ..Default::default()
         }
     }
+    /// Accept any server certificate, including self-signed and expired ones.
+    ///
+    /// Applies to both REST calls and realtime subscriptions. Use only on dev
+    /// mode with a self-signed SSL cert.
     pub fn set_self_signed(&mut self, status: bool) -> Result<&mut Self, Error> {
         self.self_signed = Some(status);
         Ok(self)
     }
+
+    /// Trust the certificates in `pem` in addition to the system roots.
+    /// `pem` may hold a bundle of several certificates.
+    pub fn add_root_certificate_pem(&mut self, pem: &[u8]) -> Result<&mut Self, Error> {
+        reqwest::Certificate::from_pem_bundle(pem)?;
+        self.tls.root_certificates.push(pem.to_vec());
+        Ok(self)
+    }
+
+    /// Present a client certificate (mTLS) from a PEM certificate chain and a
+    /// PKCS #8 PEM private key.
+    pub fn set_client_identity_pem(&mut self, cert: &[u8], key: &[u8]) -> Result<&mut Self, Error> {
+        reqwest::Identity::from_pkcs8_pem(cert, key)?;
+        self.tls.identity = Some(ClientIdentity::Pkcs8Pem {
+            cert: cert.to_vec(),
+            key: key.to_vec(),
+        });
+        Ok(self)
+    }
+
+    /// Present a client certificate (mTLS) from a DER-encoded PKCS #12 archive.
+    pub fn set_client_identity_pkcs12(
+        &mut self,
+        der: &[u8],
+        password: &str,
+    ) -> Result<&mut Self, Error> {
+        reqwest::Identity::from_pkcs12_der(der, password)?;
+        self.tls.identity = Some(ClientIdentity::Pkcs12Der {
+            der: der.to_vec(),
+            password: password.to_string(),
+        });
+        Ok(self)
+    }
     pub fn set_endpoint(&mut self, endpoint: &str) -> Result<&mut Self, Error> {
         self.end_point = Some(String::from(endpoint));
         if self.end_point_realtime.as_ref().is_none() {
@@ -100,112 +245,733 @@ impl ClientBuilder {
         Ok(self)
     }
 
+    /// Ask for responses shaped like API version `version`, e.g.
+    /// [`RESPONSE_FORMAT`](crate::version::RESPONSE_FORMAT), so a server
+    /// upgrade doesn't change the payloads the models deserialize.
+    pub fn set_response_format(&mut self, version: &str) -> Result<&mut Self, Error> {
+        self.header.insert(
+            "x-appwrite-response-format",
+            HeaderValue::from_str(version)?,
+        );
+        Ok(self)
+    }
+
+    /// Check the server version before the first request, see [`VersionCheck`].
+    pub fn set_version_check(&mut self, check: VersionCheck) -> Result<&mut Self, Error> {
+        self.version_check = check;
+        Ok(self)
+    }
+
+    /// Write queries in `syntax`; use [`QuerySyntax::Legacy`] to talk to
+    /// Appwrite 1.4 servers without changing call sites.
+    pub fn set_query_syntax(&mut self, syntax: QuerySyntax) -> Result<&mut Self, Error> {
+        self.query_syntax = syntax;
+        Ok(self)
+    }
+
+    /// Maximum time to wait while establishing a connection to the server.
+    pub fn set_connect_timeout(&mut self, timeout: Duration) -> Result<&mut Self, Error> {
+        self.connect_timeout = Some(timeout);
+        Ok(self)
+    }
+
+    /// Maximum time a whole request may take, from connecting until the
+    /// response body has been read.
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<&mut Self, Error> {
+        self.timeout = Some(timeout);
+        Ok(self)
+    }
+
+    /// How long an idle pooled connection is kept alive. `None` keeps idle
+    /// connections open indefinitely.
+    pub fn set_pool_idle_timeout(&mut self, timeout: Option<Duration>) -> Result<&mut Self, Error> {
+        self.pool_idle_timeout = timeout;
+        Ok(self)
+    }
+
+    /// Talk HTTP/2 to the server straight away instead of starting with HTTP/1.1.
+    /// Only enable this when the endpoint is known to support HTTP/2.
+    pub fn set_http2_prior_knowledge(&mut self, status: bool) -> Result<&mut Self, Error> {
+        self.http2_prior_knowledge = status;
+        Ok(self)
+    }
+
+    /// Retry transient failures (rate limits, 5xx, dropped connections) with
+    /// exponential backoff. Requests are not retried by default.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) -> Result<&mut Self, Error> {
+        self.retry = policy;
+        Ok(self)
+    }
+
+    /// Register a [`Middleware`] that sees every request and response. Hooks
+    /// run in registration order.
+    pub fn add_middleware(
+        &mut self,
+        middleware: impl Middleware + 'static,
+    ) -> Result<&mut Self, Error> {
+        self.middleware.0.push(Arc::new(middleware));
+        Ok(self)
+    }
+
+    /// Send requests through `transport` instead of the default
+    /// [`ReqwestTransport`]. Timeout, pooling and TLS options only apply to the
+    /// default transport (TLS options still apply to realtime).
+    pub fn set_transport(
+        &mut self,
+        transport: impl Transport + 'static,
+    ) -> Result<&mut Self, Error> {
+        self.transport = Some(Arc::new(transport));
+        Ok(self)
+    }
+
+    /// Record every exchange to the cassette at `path`, or answer requests from
+    /// it. See [`cassette`](crate::cassette).
+    #[cfg(feature = "cassette")]
+    pub fn set_cassette(
+        &mut self,
+        path: impl Into<std::path::PathBuf>,
+        mode: CassetteMode,
+    ) -> Result<&mut Self, Error> {
+        self.cassette = Some((path.into(), mode));
+        Ok(self)
+    }
+
     pub fn build(&self) -> Result<Client, Error> {
         let Some(endpoint) = self.end_point.as_ref() else {
-            return Err(Error::Unknown);
+            return Err(Error::Config(String::from(
+                "no endpoint set: call set_endpoint or set APPWRITE_ENDPOINT",
+            )));
+        };
+        let transport = match &self.transport {
+            Some(transport) => transport.clone(),
+            None => Arc::new(ReqwestTransport::new(self.build_http_client()?)),
+        };
+        #[cfg(feature = "cassette")]
+        let transport: Arc<dyn Transport> = match &self.cassette {
+            Some((path, CassetteMode::Record)) => {
+                Arc::new(RecordingTransport::new(transport, path))
+            }
+            Some((path, CassetteMode::Replay)) => {
+                Arc::new(ReplayTransport::new(Cassette::load(path)?))
+            }
+            None => transport,
         };
         Ok(Client {
-            end_point: endpoint.to_string(),
-            end_point_realtime: self.end_point_realtime.clone(),
-            header: self.header.clone(),
-            chunk_size: self.chunk_size.clone().unwrap_or_else(|| 5 * 1024 * 1024),
-            self_signed: self.self_signed.clone().unwrap_or_else(|| false),
+            config: Arc::new(ClientConfig {
+                end_point: endpoint.to_string(),
+                end_point_realtime: self.end_point_realtime.clone(),
+                header: self.header.clone(),
+                chunk_size: self.chunk_size.unwrap_or(5 * 1024 * 1024),
+                self_signed: self.self_signed.unwrap_or(false),
+                tls: self.tls.clone(),
+                retry: self.retry.clone(),
+                middleware: self.middleware.clone(),
+                transport,
+                version_check: self.version_check,
+                query_syntax: self.query_syntax,
+                server_version: tokio::sync::Mutex::new(None),
+            }),
+            overrides: Vec::new(),
+            cookies: None,
         })
     }
+
+    fn build_http_client(&self) -> Result<reqwest::Client, Error> {
+        let mut http = reqwest::Client::builder()
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .danger_accept_invalid_certs(self.self_signed.unwrap_or(false));
+        for pem in &self.tls.root_certificates {
+            for cert in reqwest::Certificate::from_pem_bundle(pem)? {
+                http = http.add_root_certificate(cert);
+            }
+        }
+        match &self.tls.identity {
+            Some(ClientIdentity::Pkcs8Pem { cert, key }) => {
+                http = http.identity(reqwest::Identity::from_pkcs8_pem(cert, key)?);
+            }
+            Some(ClientIdentity::Pkcs12Der { der, password }) => {
+                http = http.identity(reqwest::Identity::from_pkcs12_der(der, password)?);
+            }
+            None => {}
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if self.http2_prior_knowledge {
+            http = http.http2_prior_knowledge();
+        }
+        Ok(http.build()?)
+    }
 }
 
 impl Client {
+    /// TLS connector for the realtime WebSocket, configured like the REST client.
+    pub(crate) fn tls_connector(&self) -> Result<native_tls::TlsConnector, Error> {
+        let mut connector = native_tls::TlsConnector::builder();
+        connector.danger_accept_invalid_certs(self.config.self_signed);
+        for pem in &self.config.tls.root_certificates {
+            for cert in split_pem_bundle(pem) {
+                connector.add_root_certificate(native_tls::Certificate::from_pem(&cert)?);
+            }
+        }
+        match &self.config.tls.identity {
+            Some(ClientIdentity::Pkcs8Pem { cert, key }) => {
+                connector.identity(native_tls::Identity::from_pkcs8(cert, key)?);
+            }
+            Some(ClientIdentity::Pkcs12Der { der, password }) => {
+                connector.identity(native_tls::Identity::from_pkcs12(der, password)?);
+            }
+            None => {}
+        }
+        Ok(connector.build()?)
+    }
+
+    /// Base URL of the realtime endpoint, e.g. `wss://cloud.appwrite.io/v1`.
+    pub fn end_point_realtime(&self) -> Option<&str> {
+        self.config.end_point_realtime.as_deref()
+    }
+
+    /// The value this client sends for header `name`, scoped overrides included.
+    pub fn header(&self, name: &str) -> Option<&HeaderValue> {
+        match self
+            .overrides
+            .iter()
+            .rev()
+            .find(|(key, _)| key.as_str().eq_ignore_ascii_case(name))
+        {
+            Some((_, value)) => value.as_ref(),
+            None => self.config.header.get(name),
+        }
+    }
+
+    /// All headers this client sends, scoped overrides included.
+    pub fn headers(&self) -> HeaderMap {
+        let mut headers = self.config.header.clone();
+        for (key, value) in &self.overrides {
+            match value {
+                Some(value) => headers.insert(key, value.clone()),
+                None => headers.remove(key),
+            };
+        }
+        headers
+    }
+
+    /// A view of this client sending requests to `project`.
+    pub fn with_project(&self, project: &str) -> Result<Client, Error> {
+        self.with_headers(&[("x-appwrite-project", Some(project))])
+    }
+
+    /// A view of this client asking for responses in `locale`, e.g. `fr-FR`.
+    pub fn with_locale(&self, locale: &str) -> Result<Client, Error> {
+        self.with_headers(&[("x-appwrite-locale", Some(locale))])
+    }
+
+    /// A view of this client acting as the user owning the session `secret`,
+    /// e.g. the [`Session::secret`](crate::models::session::Session::secret)
+    /// returned by a session endpoint or the `a_session_<project>` cookie. The
+    /// API key is dropped, since Appwrite rejects requests carrying both a key
+    /// and a session.
+    pub fn with_session(&self, secret: &str) -> Result<Client, Error> {
+        self.with_headers(&[
+            ("x-appwrite-key", None),
+            ("x-appwrite-jwt", None),
+            ("x-appwrite-session", Some(secret)),
+        ])
+    }
+
+    /// A view of this client acting as the user the `jwt` was created for.
+    /// The API key is dropped.
+    pub fn with_jwt(&self, jwt: &str) -> Result<Client, Error> {
+        self.with_headers(&[
+            ("x-appwrite-key", None),
+            ("x-appwrite-session", None),
+            ("x-appwrite-jwt", Some(jwt)),
+        ])
+    }
+
+    /// A view of this client with its own, empty [`CookieJar`]: it keeps the
+    /// cookies the server sets (e.g. the session cookie of
+    /// [`Account::create_email_password_session`](crate::services::server::account::Account::create_email_password_session))
+    /// and sends them back. The API key is dropped. Clones of the view and
+    /// views derived from it share its jar; other views don't see it.
+    pub fn with_cookie_jar(&self) -> Client {
+        let mut client = self.clone();
+        let key = HeaderName::from_static("x-appwrite-key");
+        client.overrides.retain(|(name, _)| *name != key);
+        client.overrides.push((key, None));
+        client.cookies = Some(CookieJar::new());
+        client
+    }
+
+    /// The jar of a [`with_cookie_jar`](Self::with_cookie_jar) view.
+    pub fn cookie_jar(&self) -> Option<&CookieJar> {
+        self.cookies.as_ref()
+    }
+
+    /// A view of this client acting as user `user_id`. Requires an API key
+    /// with the `users.write` scope, or a session of a user allowed to
+    /// impersonate others.
+    pub fn impersonate_user(&self, user_id: &str) -> Result<Client, Error> {
+        self.with_headers(&[("x-appwrite-impersonate-user-id", Some(user_id))])
+    }
+
+    fn with_headers(&self, headers: &[(&'static str, Option<&str>)]) -> Result<Client, Error> {
+        let mut client = self.clone();
+        for (name, value) in headers {
+            let name = HeaderName::from_static(name);
+            let value = value.map(HeaderValue::from_str).transpose()?;
+            client.overrides.retain(|(key, _)| *key != name);
+            client.overrides.push((name, value));
+        }
+        Ok(client)
+    }
+
+    /// Version of the Appwrite server, from `/health/version` or, failing
+    /// that, the `x-appwrite-version` response header.
+    pub async fn server_version(&self) -> Result<ServerVersion, Error> {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse()?);
+        let res = self
+            .execute(
+                HttpMethod::GET,
+                "/health/version",
+                headers,
+                &json!({}),
+                None,
+                true,
+            )
+            .await?;
+        let version = res.json::<Value>().ok().and_then(|body| {
+            body.get("version")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        });
+        let version = version.or_else(|| {
+            res.headers()
+                .get("x-appwrite-version")
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        });
+        version
+            .ok_or_else(|| Error::Custom(String::from("server didn't report its version")))?
+            .parse()
+    }
+
+    /// Probe the server version and apply the client's [`VersionCheck`]. A
+    /// successful probe is kept; a failed one is retried with backoff, from
+    /// [`VERSION_PROBE_BACKOFF`] up to [`MAX_VERSION_PROBE_BACKOFF`].
+    async fn check_server_version(&self) -> Result<(), Error> {
+        if self.config.version_check == VersionCheck::Off {
+            return Ok(());
+        }
+        let syntax = self.config.query_syntax;
+        let mut probe = self.config.server_version.lock().await;
+        let version = match *probe {
+            Some(VersionProbe::Known(version)) => version,
+            Some(VersionProbe::Failed { retry_at, .. }) if Instant::now() < retry_at => {
+                return Ok(())
+            }
+            previous => match self.server_version().await {
+                Ok(version) => {
+                    if !version.is_supported_with(syntax) {
+                        trace_event!(
+                            warn,
+                            server_version = %version,
+                            "appwrite server version is not supported by this crate"
+                        );
+                    }
+                    *probe = Some(VersionProbe::Known(version));
+                    version
+                }
+                Err(_err) => {
+                    let backoff = match previous {
+                        Some(VersionProbe::Failed { backoff, .. }) => {
+                            (backoff * 2).min(MAX_VERSION_PROBE_BACKOFF)
+                        }
+                        _ => VERSION_PROBE_BACKOFF,
+                    };
+                    trace_event!(
+                        warn,
+                        error = %_err,
+                        retry_in = ?backoff,
+                        "unable to determine the appwrite server version"
+                    );
+                    *probe = Some(VersionProbe::Failed {
+                        retry_at: Instant::now() + backoff,
+                        backoff,
+                    });
+                    return Ok(());
+                }
+            },
+        };
+        if !version.is_supported_with(syntax) && self.config.version_check == VersionCheck::Error {
+            let (min, max) = (
+                ServerVersion::min_supported(syntax),
+                MAX_SUPPORTED_EXCLUSIVE,
+            );
+            return Err(Error::UnsupportedServerVersion {
+                version: version.to_string(),
+                supported: format!("{min} <= version < {max}"),
+            });
+        }
+        Ok(())
+    }
+
+    /// Send a request to `path`, relative to the endpoint, through the
+    /// middlewares and the [`Transport`], retrying as the [`RetryPolicy`]
+    /// allows. Error statuses are returned as [`Error`]s.
+    ///
+    /// The response is buffered: unlike in 0.2 it is not a
+    /// `reqwest::Response`, and [`HttpResponse::json`] is synchronous.
     pub async fn call<T: Serialize + ?Sized>(
         &self,
         method: HttpMethod,
         path: &str,
         headers: HeaderMap,
         params: &T,
-        form: Option<Form>,
-    ) -> Result<Response, Error> {
-        let res = reqwest::Client::new();
-        let res = match method {
-            HttpMethod::GET => {
-                let param = Self::_flatten_params_for_get(&json!(params))?;
-                res.get(format!("{}{}{}", self.end_point, path, param))
+        form: Option<MultipartForm>,
+    ) -> Result<HttpResponse, Error> {
+        self.check_server_version().await?;
+        let retryable = self.config.retry.allows_method(&method);
+        let mut params = json!(params);
+        if self.config.query_syntax == QuerySyntax::Legacy {
+            query::legacy_queries(&mut params)?;
+        }
+        self.execute(method, path, headers, &params, form, retryable)
+            .await
+    }
+
+    /// Upload one chunk of a chunked upload. Chunks are retried under the
+    /// client's retry policy even though they are `POST`s, since re-sending
+    /// the same `Content-Range` is safe, except for the first chunk of an
+    /// upload with ID `unique()`: a retry of it would create a second file.
+    async fn call_chunk<T: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        headers: HeaderMap,
+        params: &T,
+        form: MultipartForm,
+        retryable: bool,
+    ) -> Result<HttpResponse, Error> {
+        self.check_server_version().await?;
+        self.execute(
+            HttpMethod::POST,
+            path,
+            headers,
+            &json!(params),
+            Some(form),
+            retryable,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    async fn execute(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        headers: HeaderMap,
+        params: &Value,
+        form: Option<MultipartForm>,
+        retryable: bool,
+    ) -> Result<HttpResponse, Error> {
+        self.execute_attempts(method, path, headers, params, form, retryable)
+            .await
+    }
+
+    /// Runs the request inside an `appwrite.request` span carrying the method,
+    /// the API path template, the final status, the Appwrite error type and
+    /// the total duration (retries included).
+    #[cfg(feature = "tracing")]
+    async fn execute(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        headers: HeaderMap,
+        params: &Value,
+        form: Option<MultipartForm>,
+        retryable: bool,
+    ) -> Result<HttpResponse, Error> {
+        use tracing::{field::Empty, Instrument};
+
+        let span = tracing::info_span!(
+            "appwrite.request",
+            http.method = ?method,
+            path = crate::routes::path_template(path),
+            url = Empty,
+            status = Empty,
+            error_type = Empty,
+            duration_ms = Empty,
+        );
+        let started = Instant::now();
+        let res = self
+            .execute_attempts(method, path, headers, params, form, retryable)
+            .instrument(span.clone())
+            .await;
+        span.record("duration_ms", started.elapsed().as_millis() as u64);
+        match &res {
+            Ok(res) => {
+                span.record("status", res.status().as_u16());
             }
-            HttpMethod::POST => res
-                .post(format!("{}{}", self.end_point, path))
-                .json(&params),
-            HttpMethod::PUT => res.put(format!("{}{}", self.end_point, path)).json(&params),
-            HttpMethod::DELETE => res
-                .delete(format!("{}{}", self.end_point, path))
-                .json(&params),
-            HttpMethod::PATCH => res
-                .patch(format!("{}{}", self.end_point, path))
-                .json(&params),
+            Err(Error::AppWriteError {
+                code, error_type, ..
+            }) => {
+                span.record("status", code.unwrap_or_default());
+                span.record("error_type", error_type.as_deref().unwrap_or_default());
+            }
+            Err(Error::UnexpectedResponse { status, .. }) => {
+                span.record("status", status);
+            }
+            Err(_) => {}
+        }
+        res
+    }
+
+    async fn execute_attempts(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        headers: HeaderMap,
+        params: &Value,
+        form: Option<MultipartForm>,
+        retryable: bool,
+    ) -> Result<HttpResponse, Error> {
+        let max_attempts = match retryable {
+            true => self.config.retry.max_attempts.max(1),
+            false => 1,
         };
-        let res = res.headers(headers).headers(self.header.clone());
+        let mut attempt = 1;
+        loop {
+            let res = self
+                .send(method, path, headers.clone(), params, form.clone())
+                .await;
+            let retry_headers = match &res {
+                Ok(res) => Some(res.headers().clone()),
+                Err(_) => None,
+            };
+            let res = match res {
+                Ok(res) => Self::check_status(res),
+                Err(err) => Err(err),
+            };
+            let should_retry = attempt < max_attempts
+                && match &res {
+                    Ok(_) => false,
+                    Err(Error::Network(err)) => err.is_timeout() || err.is_connect(),
+                    Err(Error::AppWriteError {
+                        code, error_type, ..
+                    }) => self
+                        .config
+                        .retry
+                        .is_retryable(code.unwrap_or_default() as u16, error_type.as_deref()),
+                    Err(Error::UnexpectedResponse { status, .. }) => {
+                        self.config.retry.is_retryable(*status, None)
+                    }
+                    Err(_) => false,
+                };
+            if !should_retry {
+                return res;
+            }
+            let delay = self.config.retry.delay(attempt, retry_headers.as_ref());
+            trace_event!(
+                warn,
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "retrying appwrite request"
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
 
-        let res = if let Some(form) = form {
-            res.multipart(form)
-        } else {
-            res
+    async fn send(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        headers: HeaderMap,
+        params: &Value,
+        form: Option<MultipartForm>,
+    ) -> Result<HttpResponse, Error> {
+        let mut request = RequestParts {
+            method,
+            endpoint: self.config.end_point.clone(),
+            path: path.to_string(),
+            headers,
+            params: params.clone(),
+            form,
+        };
+        for (key, value) in &self.config.header {
+            request.headers.insert(key, value.clone());
+        }
+        for (key, value) in &self.overrides {
+            match value {
+                Some(value) => request.headers.insert(key, value.clone()),
+                None => request.headers.remove(key),
+            };
+        }
+        if let Some(cookies) = &self.cookies {
+            cookies.add_to(&mut request.headers);
+        }
+        let mut ran = 0;
+        let mut response = None;
+        for middleware in &self.config.middleware.0 {
+            middleware.on_request(&mut request);
+            ran += 1;
+            response = middleware.respond(&request);
+            if response.is_some() {
+                break;
+            }
+        }
+
+        let url = format!("{}{}", request.endpoint, request.path);
+        let (url, body) = match (request.method, request.form.clone()) {
+            (HttpMethod::GET, _) => {
+                let param = Self::_flatten_params_for_get(&request.params)?;
+                (format!("{}{}", url, param), RequestBody::Empty)
+            }
+            (_, Some(form)) => (
+                url,
+                RequestBody::Multipart(Self::_params_into_form(&request.params, form)),
+            ),
+            (_, None) => (url, RequestBody::Json(request.params.clone())),
         };
-        let res = res.send().await?;
-        // if res.status() >= StatusCode::BAD_REQUEST {
-        //     return Err(Error::AppWriteError(res.json::<AppWriteError>().await?));
-        // }
-        match res.status().as_u16() {
-            200..=299 => Ok(res),
-            400..=599 => {
-                let err = res.json::<AppWriteError>().await?;
-                Err(Error::AppWriteError {
-                    message: err.message,
-                    code: err.code,
-                    response: err.response,
-                    error_type: err.error_type,
-                })
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("url", crate::utils::redact_url(&url).as_str());
+
+        let started = Instant::now();
+        let res = match response {
+            Some(response) => Ok(response),
+            None => {
+                self.config
+                    .transport
+                    .send(HttpRequest {
+                        method: request.method,
+                        url,
+                        headers: request.headers.clone(),
+                        body,
+                    })
+                    .await
             }
+        };
+        if let (Some(cookies), Ok(res)) = (&self.cookies, &res) {
+            cookies.take_from(res.headers());
+        }
+        if !self.config.middleware.0.is_empty() {
+            let response = ResponseParts {
+                status: res.as_ref().ok().map(|r| r.status().as_u16()),
+                headers: res
+                    .as_ref()
+                    .map(|r| r.headers().clone())
+                    .unwrap_or_default(),
+                elapsed: started.elapsed(),
+            };
+            for middleware in &self.config.middleware.0[..ran] {
+                middleware.on_response(&request, &response);
+            }
+        }
+        res
+    }
+
+    fn check_status(res: HttpResponse) -> Result<HttpResponse, Error> {
+        match res.status().is_success() {
+            true => Ok(res),
+            false => Err(Self::response_error(&res)),
+        }
+    }
+
+    /// The error for a failed response: the Appwrite error in its JSON body,
+    /// or [`Error::UnexpectedResponse`] when there is none (e.g. an HTML page
+    /// from a reverse proxy, or an unexpected status code).
+    fn response_error(res: &HttpResponse) -> Error {
+        let status = res.status().as_u16();
+        match res.json::<AppWriteError>() {
+            Ok(err) if (400..=599).contains(&status) => Error::AppWriteError {
+                message: err.message,
+                code: err.code.or(Some(status as u64)),
+                response: err.response,
+                error_type: err.error_type,
+            },
             _ => {
-                //TODO: Error Message: Unexpected status code:{}, response.statuscode
-                Err(Error::Unknown)
+                let body = res.text();
+                let end = body
+                    .char_indices()
+                    .nth(BODY_EXCERPT_CHARS)
+                    .map_or(body.len(), |(i, _)| i);
+                Error::UnexpectedResponse {
+                    status,
+                    body: body[..end].trim().to_string(),
+                }
             }
         }
     }
 
+    /// Multipart requests can't carry a JSON body, so the request parameters
+    /// (e.g. `permissions`) are sent as form fields, arrays as `key[]`.
+    fn _params_into_form(params: &Value, form: MultipartForm) -> MultipartForm {
+        let Some(params) = params.as_object() else {
+            return form;
+        };
+        params.iter().fold(form, |form, (key, value)| match value {
+            Value::Array(values) => values.iter().fold(form, |form, value| {
+                form.text(format!("{key}[]"), Self::_form_value(value))
+            }),
+            value => form.text(key.as_str(), Self::_form_value(value)),
+        })
+    }
+
+    fn _form_value(value: &Value) -> String {
+        match value {
+            Value::String(value) => value.clone(),
+            value => value.to_string(),
+        }
+    }
+
+    /// Serialize `api_params` into a query string the way the official SDKs
+    /// do: arrays as `key[0]`, objects as `key[sub]`, every key and value
+    /// percent-encoded. Null values are skipped.
     fn _flatten_params_for_get(api_params: &Value) -> Result<String, Error> {
-        let mut params_chain = String::new();
-        let mut i = 0;
-
-        api_params
-            .as_object()
-            .ok_or(Error::Custom(
-                "Unable to convert value because it's not an object".to_string(),
-            ))?
+        let params = api_params.as_object().ok_or(Error::Custom(
+            "Unable to convert value because it's not an object".to_string(),
+        ))?;
+        let mut pairs = Vec::new();
+        for (key, value) in params {
+            Self::_flatten_value(key.clone(), value, &mut pairs);
+        }
+        let query = pairs
             .iter()
-            .for_each(|val| match val.0.contains("queries") {
-                true => {
-                    let v = val.1.as_array().unwrap();
-                    v.iter().for_each(|query| {
-                        let start_string = match i == 0 {
-                            true => "?",
-                            false => "&",
-                        };
-                        params_chain.push_str(
-                            format!("{}queries[{}]={}", start_string, i, query.as_str().unwrap())
-                                .as_str(),
-                        );
-                        i += 1;
-                    });
+            .map(|(key, value)| {
+                format!(
+                    "{}={}",
+                    utf8_percent_encode(key, QUERY_COMPONENT),
+                    utf8_percent_encode(value, QUERY_COMPONENT)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+        Ok(match query.is_empty() {
+            true => query,
+            false => format!("?{query}"),
+        })
+    }
+
+    fn _flatten_value(key: String, value: &Value, pairs: &mut Vec<(String, String)>) {
+        match value {
+            Value::Null => {}
+            Value::Array(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    Self::_flatten_value(format!("{key}[{i}]"), value, pairs);
                 }
-                false => {
-                    let start_character = match i != 0 {
-                        true => "&",
-                        false => "?",
-                    };
-                    params_chain.push_str(
-                        format!("{}{}={}", start_character, val.0, val.1.as_str().unwrap())
-                            .as_str(),
-                    )
+            }
+            Value::Object(map) => {
+                for (sub, value) in map {
+                    Self::_flatten_value(format!("{key}[{sub}]"), value, pairs);
                 }
-            });
-        Ok(params_chain)
+            }
+            Value::String(value) => pairs.push((key, value.clone())),
+            value => pairs.push((key, value.to_string())),
+        }
     }
 
     pub async fn chunk_upload_file<T: Serialize + ?Sized>(
@@ -229,13 +995,11 @@ impl Client {
         let uri = api_path;
 
         // File Size Check and Upload
-        if file_size <= self.chunk_size {
+        if file_size <= self.config.chunk_size {
             // Single-request upload
-            let part = Part::bytes(file).file_name(file_name);
-
-            let form = multipart::Form::new()
+            let form = MultipartForm::new()
                 .text("fileId", file_id.clone())
-                .part("file", part);
+                .file("file", file_name, file);
 
             let mut headers = HeaderMap::new();
             headers.insert(
@@ -250,8 +1014,7 @@ impl Client {
                     let file = self
                         .call(HttpMethod::POST, uri, headers, &params, Some(form))
                         .await?
-                        .json::<File>()
-                        .await?;
+                        .json::<File>()?;
 
                     // if let Some(ref on_progress) = on_progress {
                     //     on_progress(UploadProgress {
@@ -268,8 +1031,7 @@ impl Client {
                     let deployment = self
                         .call(HttpMethod::POST, uri, headers, &params, Some(form))
                         .await?
-                        .json::<Deployment>()
-                        .await?;
+                        .json::<Deployment>()?;
 
                     // if let Some(ref on_progress) = on_progress {
                     //     on_progress(UploadProgress {
@@ -306,37 +1068,36 @@ impl Client {
                     let res = self
                         .call(
                             HttpMethod::GET,
-                            format!("{}{}/{}", self.end_point, api_path, file_id).as_str(),
+                            format!("{api_path}/{file_id}").as_str(),
                             headers,
                             &params,
                             None,
                         )
                         .await?
-                        .json::<File>()
-                        .await?;
-                    offset = res.chunks_uploaded * self.chunk_size
+                        .json::<File>()?;
+                    offset = res.chunks_uploaded * self.config.chunk_size
                 }
                 false => {
                     let res = self
                         .call(
                             HttpMethod::GET,
-                            format!("{}{}/{}", self.end_point, api_path, file_id).as_str(),
+                            format!("{api_path}/{file_id}").as_str(),
                             headers,
                             &params,
                             None,
                         )
                         .await?
-                        .json::<Deployment>()
-                        .await?;
-                    offset = res.chunks_uploaded * self.chunk_size
+                        .json::<Deployment>()?;
+                    offset = res.chunks_uploaded * self.config.chunk_size
                 }
             }
         }
 
         while offset < file_size {
-            let end = std::cmp::min(offset + self.chunk_size, file_size);
+            let end = std::cmp::min(offset + self.config.chunk_size, file_size);
             let chunk = &file[offset..end];
             let content_range = format!("bytes {}-{}/{}", offset, end - 1, file_size);
+            trace_event!(debug, offset, end, file_size, "uploading chunk");
 
             let mut headers = HeaderMap::new();
             headers.insert(
@@ -349,10 +1110,10 @@ impl Client {
                 "Content-Range",
                 HeaderValue::from_str(content_range.as_str())?,
             );
-            let mut chunk_form = multipart::Form::new();
-            chunk_form = chunk_form.text("fileId", file_id.clone()).part(
+            let chunk_form = MultipartForm::new().text("fileId", file_id.clone()).file(
                 "file",
-                Part::bytes(chunk.to_vec()).file_name(file_name.clone()),
+                file_name.clone(),
+                chunk.to_vec(),
             );
             if !first_upload {
                 headers.insert(
@@ -373,21 +1134,16 @@ impl Client {
             // let params = serde_json::json!({
             //     "permissions": &[] as &[String]
             // });
+            let retryable = !first_upload || file_id != "unique()";
             let response = self
-                .call(HttpMethod::POST, uri, headers, &params, Some(chunk_form))
+                .call_chunk(uri, headers, &params, chunk_form, retryable)
                 .await?;
             if response.status() != StatusCode::CREATED {
-                let err = response.json::<AppWriteError>().await?;
-                return Err(Error::AppWriteError {
-                    message: err.message,
-                    code: err.code,
-                    response: err.response,
-                    error_type: err.error_type,
-                });
+                return Err(Self::response_error(&response));
             }
             match is_file {
                 true => {
-                    let file = response.json::<File>().await?;
+                    let file = response.json::<File>()?;
                     if first_upload {
                         x_appwrite_id = Some(file.clone().id);
                         first_upload = false;
@@ -396,9 +1152,9 @@ impl Client {
                     // if let Some(ref on_progress) = on_progress {
                     //     on_progress(UploadProgress {
                     //         id: file.clone().id,
-                    //         progress: std::cmp::min(offset, self.chunk_size) / self.chunk_size
+                    //         progress: std::cmp::min(offset, self.config.chunk_size) / self.config.chunk_size
                     //             * 100,
-                    //         size_uploaded: std::cmp::min(offset, self.chunk_size),
+                    //         size_uploaded: std::cmp::min(offset, self.config.chunk_size),
                     //         chunks_total: file.chunks_total,
                     //         chunks_uploaded: file.chunks_uploaded,
                     //     });
@@ -407,7 +1163,7 @@ impl Client {
                     res = Some(UploadType::File(file.clone()));
                 }
                 false => {
-                    let deployment = response.json::<Deployment>().await?;
+                    let deployment = response.json::<Deployment>()?;
                     if first_upload {
                         x_appwrite_id = Some(deployment.clone().id);
                         first_upload = false;
@@ -416,9 +1172,9 @@ impl Client {
                     // if let Some(ref on_progress) = on_progress {
                     //     on_progress(UploadProgress {
                     //         id: deployment.clone().id,
-                    //         progress: std::cmp::min(offset, self.chunk_size) / self.chunk_size
+                    //         progress: std::cmp::min(offset, self.config.chunk_size) / self.config.chunk_size
                     //             * 100,
-                    //         size_uploaded: std::cmp::min(offset, self.chunk_size),
+                    //         size_uploaded: std::cmp::min(offset, self.config.chunk_size),
                     //         chunks_total: deployment.chunks_total,
                     //         chunks_uploaded: deployment.chunks_uploaded,
                     //     });
@@ -428,7 +1184,7 @@ impl Client {
                 }
             }
 
-            offset += self.chunk_size;
+            offset += self.config.chunk_size;
         }
         Ok(res.ok_or(Error::Custom("No Upload Type".to_string()))?)
     }
@@ -453,13 +1209,11 @@ impl Client {
             let file_size = file.len();
             let uri = api_path.clone();
             // File Size Check and Upload
-            if file_size <= self.chunk_size {
+            if file_size <= self.config.chunk_size {
                 // Single-request upload
-                let part = Part::bytes(file).file_name(file_name);
-
-                let form = multipart::Form::new()
+                let form = MultipartForm::new()
                     .text("fileId", file_id.clone())
-                    .part("file", part);
+                    .file("file", file_name, file);
 
                 let mut headers = HeaderMap::new();
                 headers.insert(
@@ -474,8 +1228,7 @@ impl Client {
                         let file = self
                             .call(HttpMethod::POST, uri.as_str(), headers, &params, Some(form))
                             .await?
-                            .json::<File>()
-                            .await?;
+                            .json::<File>()?;
 
                         emitter
                             .emit((
@@ -496,8 +1249,7 @@ impl Client {
                         let deployment = self
                             .call(HttpMethod::POST, uri.as_str(), headers, &params, Some(form))
                             .await?
-                            .json::<Deployment>()
-                            .await?;
+                            .json::<Deployment>()?;
 
                         emitter
                             .emit((
@@ -537,37 +1289,36 @@ impl Client {
                         let res = self
                             .call(
                                 HttpMethod::GET,
-                                format!("{}{}/{}", self.end_point, api_path, file_id).as_str(),
+                                format!("{api_path}/{file_id}").as_str(),
                                 headers,
                                 &params,
                                 None,
                             )
                             .await?
-                            .json::<File>()
-                            .await?;
-                        offset = res.chunks_uploaded * self.chunk_size
+                            .json::<File>()?;
+                        offset = res.chunks_uploaded * self.config.chunk_size
                     }
                     false => {
                         let res = self
                             .call(
                                 HttpMethod::GET,
-                                format!("{}{}/{}", self.end_point, api_path, file_id).as_str(),
+                                format!("{api_path}/{file_id}").as_str(),
                                 headers,
                                 &params,
                                 None,
                             )
                             .await?
-                            .json::<Deployment>()
-                            .await?;
-                        offset = res.chunks_uploaded * self.chunk_size
+                            .json::<Deployment>()?;
+                        offset = res.chunks_uploaded * self.config.chunk_size
                     }
                 }
             }
 
             while offset < file_size {
-                let end = std::cmp::min(offset + self.chunk_size, file_size);
+                let end = std::cmp::min(offset + self.config.chunk_size, file_size);
                 let chunk = &file[offset..end];
                 let content_range = format!("bytes {}-{}/{}", offset, end - 1, file_size);
+                trace_event!(debug, offset, end, file_size, "uploading chunk");
 
                 let mut headers = HeaderMap::new();
                 headers.insert(
@@ -580,10 +1331,10 @@ impl Client {
                     "Content-Range",
                     HeaderValue::from_str(content_range.as_str())?,
                 );
-                let mut chunk_form = multipart::Form::new();
-                chunk_form = chunk_form.text("fileId", file_id.clone()).part(
+                let chunk_form = MultipartForm::new().text("fileId", file_id.clone()).file(
                     "file",
-                    Part::bytes(chunk.to_vec()).file_name(file_name.clone()),
+                    file_name.clone(),
+                    chunk.to_vec(),
                 );
                 if !first_upload {
                     headers.insert(
@@ -604,27 +1355,16 @@ impl Client {
                 // let params = serde_json::json!({
                 //     "permissions": &[] as &[String]
                 // });
+                let retryable = !first_upload || file_id != "unique()";
                 let response = self
-                    .call(
-                        HttpMethod::POST,
-                        uri.as_str(),
-                        headers,
-                        &params,
-                        Some(chunk_form),
-                    )
+                    .call_chunk(uri.as_str(), headers, &params, chunk_form, retryable)
                     .await?;
                 if response.status() != StatusCode::CREATED {
-                    let err = response.json::<AppWriteError>().await?;
-                    return Err(Error::AppWriteError {
-                        message: err.message,
-                        code: err.code,
-                        response: err.response,
-                        error_type: err.error_type,
-                    });
+                    return Err(Self::response_error(&response));
                 }
                 match is_file {
                     true => {
-                        let file = response.json::<File>().await?;
+                        let file = response.json::<File>()?;
                         if first_upload {
                             x_appwrite_id = Some(file.clone().id);
                             first_upload = false;
@@ -635,10 +1375,10 @@ impl Client {
                                 UploadType::File(file.clone()),
                                 UploadProgress {
                                     id: file.clone().id,
-                                    progress: std::cmp::min(offset, self.chunk_size)
-                                        / self.chunk_size
+                                    progress: std::cmp::min(offset, self.config.chunk_size)
+                                        / self.config.chunk_size
                                         * 100,
-                                    size_uploaded: std::cmp::min(offset, self.chunk_size),
+                                    size_uploaded: std::cmp::min(offset, self.config.chunk_size),
                                     chunks_total: file.chunks_total,
                                     chunks_uploaded: file.chunks_uploaded,
                                 },
@@ -646,7 +1386,7 @@ impl Client {
                             .await;
                     }
                     false => {
-                        let deployment = response.json::<Deployment>().await?;
+                        let deployment = response.json::<Deployment>()?;
                         if first_upload {
                             x_appwrite_id = Some(deployment.clone().id);
                             first_upload = false;
@@ -657,10 +1397,10 @@ impl Client {
                                 UploadType::Deployment(deployment.clone()),
                                 UploadProgress {
                                     id: deployment.clone().id,
-                                    progress: std::cmp::min(offset, self.chunk_size)
-                                        / self.chunk_size
+                                    progress: std::cmp::min(offset, self.config.chunk_size)
+                                        / self.config.chunk_size
                                         * 100,
-                                    size_uploaded: std::cmp::min(offset, self.chunk_size),
+                                    size_uploaded: std::cmp::min(offset, self.config.chunk_size),
                                     chunks_total: deployment.chunks_total,
                                     chunks_uploaded: deployment.chunks_uploaded,
                                 },
@@ -669,10 +1409,208 @@ impl Client {
                     }
                 }
 
-                offset += self.chunk_size;
+                offset += self.config.chunk_size;
             }
 
             Ok(())
         })
     }
 }
+
+/// Split a PEM bundle into its individual certificates, since
+/// `native_tls::Certificate::from_pem` only reads the first one.
+fn split_pem_bundle(pem: &[u8]) -> Vec<Vec<u8>> {
+    const END: &str = "-----END CERTIFICATE-----";
+    String::from_utf8_lossy(pem)
+        .split_inclusive(END)
+        .filter(|block| block.contains(END))
+        .map(|block| block.trim_start().as_bytes().to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::CannedTransport;
+
+    /// An Appwrite 1.4 server.
+    fn old_server() -> CannedTransport {
+        CannedTransport::new()
+            .json("/health/version", json!({"version": "1.4.13"}))
+            .json("/users", json!({"total": 0, "users": []}))
+    }
+
+    #[tokio::test]
+    async fn test_version_check() {
+        let mut builder = ClientBuilder::default();
+        builder.set_transport(old_server()).unwrap();
+        let client = builder.build().unwrap();
+        assert_eq!(
+            client.server_version().await.unwrap(),
+            ServerVersion::new(1, 4, 13)
+        );
+        assert!(
+            crate::services::server::users::Users::list(&client, None, None)
+                .await
+                .is_ok()
+        );
+
+        let client = builder
+            .set_version_check(VersionCheck::Error)
+            .unwrap()
+            .build()
+            .unwrap();
+        let err = crate::services::server::users::Users::list(&client, None, None)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::UnsupportedServerVersion { .. }));
+
+        let client = builder
+            .set_query_syntax(QuerySyntax::Legacy)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(
+            crate::services::server::users::Users::list(&client, None, None)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_version_probe_backs_off() {
+        let transport = CannedTransport::new()
+            .fail("/health/version", "connection refused")
+            .json("/health/version", json!({"version": "1.4.13"}))
+            .json("/users", json!({"total": 0, "users": []}));
+        let client = ClientBuilder::default()
+            .set_transport(transport.clone())
+            .unwrap()
+            .set_version_check(VersionCheck::Error)
+            .unwrap()
+            .build()
+            .unwrap();
+        let list = || crate::services::server::users::Users::list(&client, None, None);
+        assert!(list().await.is_ok());
+        assert!(list().await.is_ok());
+        assert_eq!(
+            transport.paths(),
+            ["/v1/health/version", "/v1/users", "/v1/users"]
+        );
+
+        let mut probe = client.config.server_version.lock().await;
+        let Some(VersionProbe::Failed { backoff, .. }) = *probe else {
+            panic!("expected a failed probe, got {probe:?}");
+        };
+        assert_eq!(backoff, VERSION_PROBE_BACKOFF);
+        *probe = Some(VersionProbe::Failed {
+            retry_at: Instant::now(),
+            backoff,
+        });
+        drop(probe);
+        let err = list().await.err().unwrap();
+        assert!(matches!(err, Error::UnsupportedServerVersion { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_chunk_upload_resumes_from_existing_file() {
+        let file = |chunks_uploaded| {
+            json!({
+                "$id": "report", "bucketId": "docs", "$createdAt": "", "$updatedAt": "",
+                "$permissions": [], "name": "report.txt", "signature": "",
+                "mimeType": "text/plain", "sizeOriginal": 10, "chunksTotal": 3,
+                "chunksUploaded": chunks_uploaded,
+            })
+        };
+        let transport = CannedTransport::new()
+            .json("/files/report", file(1))
+            .respond(
+                "/files",
+                HttpResponse::from_json(StatusCode::CREATED, &file(3)),
+            );
+        let mut builder = ClientBuilder {
+            chunk_size: Some(4),
+            ..Default::default()
+        };
+        let client = builder
+            .set_transport(transport.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!("{}.txt", Uuid::new_v4()));
+        fs::write(&path, b"0123456789").unwrap();
+        let res = client
+            .chunk_upload_file(
+                path.to_str().unwrap(),
+                "/storage/buckets/docs/files",
+                "report".to_string(),
+                &json!({"fileId": "report"}),
+                "report.txt".to_string(),
+                true,
+            )
+            .await;
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(res.unwrap(), UploadType::File(_)));
+        assert_eq!(
+            transport.paths(),
+            [
+                "/v1/storage/buckets/docs/files/report",
+                "/v1/storage/buckets/docs/files",
+                "/v1/storage/buckets/docs/files",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scoped_views_share_config() {
+        let client = ClientBuilder::default()
+            .set_project("5ff3")
+            .unwrap()
+            .set_key("cd86")
+            .unwrap()
+            .build()
+            .unwrap();
+        let view = client
+            .with_project("a1b2")
+            .unwrap()
+            .with_locale("fr-FR")
+            .unwrap()
+            .with_jwt("eyJh")
+            .unwrap();
+        assert!(Arc::ptr_eq(&client.config, &view.config));
+        assert_eq!(view.header("x-appwrite-project").unwrap(), "a1b2");
+        assert_eq!(view.header("x-appwrite-jwt").unwrap(), "eyJh");
+        assert!(view.header("x-appwrite-key").is_none());
+        assert!(!view.headers().contains_key("x-appwrite-key"));
+        assert_eq!(client.header("x-appwrite-project").unwrap(), "5ff3");
+        assert_eq!(
+            client.impersonate_user("6618").unwrap().headers()["x-appwrite-impersonate-user-id"],
+            "6618"
+        );
+    }
+
+    #[test]
+    fn test_flatten_params_for_get() {
+        let params = json!({
+            "queries": ["equal(\"name\", [\"a&b #1\"])", "limit(25)"],
+            "size": 400,
+            "margin": 0,
+            "download": true,
+            "fallback": null,
+            "filter": {"type": "image/png"},
+        });
+        assert_eq!(
+            Client::_flatten_params_for_get(&params).unwrap(),
+            "?download=true\
+             &filter%5Btype%5D=image%2Fpng\
+             &margin=0\
+             &queries%5B0%5D=equal%28%22name%22%2C%20%5B%22a%26b%20%231%22%5D%29\
+             &queries%5B1%5D=limit%2825%29\
+             &size=400"
+        );
+        assert_eq!(Client::_flatten_params_for_get(&json!({})).unwrap(), "");
+        assert!(Client::_flatten_params_for_get(&json!([])).is_err());
+    }
+}
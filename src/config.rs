@@ -0,0 +1,146 @@
+//! # Config
+//!
+//! Build a [`ClientBuilder`] from environment variables or from the Appwrite
+//! CLI's `appwrite.json` project file instead of hard-coding the endpoint and
+//! project.
+//!
+//! ```no_run
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//!
+//! fn main() -> Result<(), Error> {
+//!     let client = ClientBuilder::from_env()?.build()?;
+//!
+//!     let client = ClientBuilder::from_appwrite_json("appwrite.json")?
+//!         .set_key("cd868c7af8bdc893b4...93b7535db89")?
+//!         .build()?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::{fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::{client::ClientBuilder, error::Error};
+
+/// API endpoint, e.g. `https://cloud.appwrite.io/v1`. Defaults to Appwrite Cloud.
+pub const ENV_ENDPOINT: &str = "APPWRITE_ENDPOINT";
+/// Project ID. Required.
+pub const ENV_PROJECT_ID: &str = "APPWRITE_PROJECT_ID";
+/// API key.
+pub const ENV_API_KEY: &str = "APPWRITE_API_KEY";
+/// `true` / `1` to accept self-signed certificates.
+pub const ENV_SELF_SIGNED: &str = "APPWRITE_SELF_SIGNED";
+/// Default locale for responses, e.g. `en-US`.
+pub const ENV_LOCALE: &str = "APPWRITE_LOCALE";
+
+/// The parts of an Appwrite CLI `appwrite.json` the client needs.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppwriteJson {
+    pub project_id: String,
+    pub project_name: Option<String>,
+    pub endpoint: Option<String>,
+}
+
+impl AppwriteJson {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|err| Error::Config(format!("cannot read {}: {err}", path.display())))?;
+        serde_json::from_str(&content)
+            .map_err(|err| Error::Config(format!("invalid {}: {err}", path.display())))
+    }
+}
+
+impl ClientBuilder {
+    /// A builder configured from the `APPWRITE_*` environment variables, see
+    /// [`ENV_ENDPOINT`], [`ENV_PROJECT_ID`], [`ENV_API_KEY`],
+    /// [`ENV_SELF_SIGNED`] and [`ENV_LOCALE`].
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// A builder configured with the project ID and, when present, the
+    /// endpoint of an Appwrite CLI `appwrite.json` file.
+    pub fn from_appwrite_json(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let config = AppwriteJson::load(path)?;
+        let mut builder = Self::default();
+        if let Some(endpoint) = &config.endpoint {
+            builder.end_point_realtime = None;
+            builder.set_endpoint(endpoint)?;
+        }
+        builder.set_project(&config.project_id)?;
+        Ok(builder)
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, Error> {
+        let lookup = |name: &str| lookup(name).filter(|value| !value.trim().is_empty());
+        let mut builder = Self::default();
+        if let Some(endpoint) = lookup(ENV_ENDPOINT) {
+            builder.end_point_realtime = None;
+            builder.set_endpoint(&endpoint)?;
+        }
+        let project = lookup(ENV_PROJECT_ID)
+            .ok_or_else(|| Error::Config(format!("{ENV_PROJECT_ID} is not set")))?;
+        builder.set_project(&project)?;
+        if let Some(key) = lookup(ENV_API_KEY) {
+            builder.set_key(&key)?;
+        }
+        if let Some(self_signed) = lookup(ENV_SELF_SIGNED) {
+            let self_signed = match self_signed.trim().to_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => true,
+                "0" | "false" | "no" | "off" => false,
+                _ => {
+                    return Err(Error::Config(format!(
+                        "{ENV_SELF_SIGNED} must be true or false, got {self_signed:?}"
+                    )))
+                }
+            };
+            builder.set_self_signed(self_signed)?;
+        }
+        if let Some(locale) = lookup(ENV_LOCALE) {
+            builder.set_locale(&locale)?;
+        }
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_from_lookup() {
+        let vars = HashMap::from([
+            (ENV_ENDPOINT, "http://localhost/v1"),
+            (ENV_PROJECT_ID, "5ff3"),
+            (ENV_SELF_SIGNED, "true"),
+        ]);
+        let builder =
+            ClientBuilder::from_lookup(|name| vars.get(name).map(|v| v.to_string())).unwrap();
+        assert_eq!(builder.header["x-appwrite-project"], "5ff3");
+        assert_eq!(
+            builder.end_point_realtime.as_deref(),
+            Some("ws://localhost/v1")
+        );
+        assert!(builder.build().is_ok());
+
+        let err = ClientBuilder::from_lookup(|_| None).err().unwrap();
+        assert!(matches!(err, Error::Config(message) if message.contains(ENV_PROJECT_ID)));
+
+        let vars = HashMap::from([(ENV_PROJECT_ID, "5ff3"), (ENV_SELF_SIGNED, "maybe")]);
+        let err = ClientBuilder::from_lookup(|name| vars.get(name).map(|v| v.to_string()))
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::Config(_)));
+
+        assert!(matches!(
+            ClientBuilder::new().build(),
+            Err(Error::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,236 @@
+//! # Cookies
+//!
+//! Appwrite answers session endpoints with an `a_session_<project>` cookie
+//! (and mirrors it in the `x-fallback-cookies` header). A client view made
+//! with [`Client::with_cookie_jar`](crate::client::Client::with_cookie_jar) keeps those cookies in its own
+//! [`CookieJar`] and sends them back on every following request, which
+//! server-side-rendered apps need to forward the session to the browser. Make
+//! one view per browser session so users never see each other's cookies.
+//!
+//! ```no_run
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//! use unofficial_appwrite::services::server::account::Account;
+//!
+//! # async fn run() -> Result<(), Error> {
+//! let client = ClientBuilder::default().set_project("5ff3...")?.build()?;
+//!
+//! let visitor = client.with_cookie_jar();
+//! Account::create_email_password_session(&visitor, "ada@example.com", "password").await?;
+//! let secret = visitor.cookie_jar().and_then(|jar| jar.session("5ff3..."));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::header::{HeaderMap, HeaderValue, COOKIE, SET_COOKIE};
+use serde_json::Value;
+
+/// In-memory cookie store shared by all its clones, see
+/// [`Client::with_cookie_jar`](crate::client::Client::with_cookie_jar).
+///
+/// Cookies are kept by name only: domains, paths and expiry dates are
+/// ignored, since a jar talks to a single Appwrite endpoint. A cookie set to
+/// an empty value or with `Max-Age=0` is removed.
+#[derive(Debug, Clone, Default)]
+pub struct CookieJar {
+    cookies: Arc<Mutex<BTreeMap<String, String>>>,
+}
+
+impl CookieJar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of cookie `name`.
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.lock().get(name).cloned()
+    }
+
+    /// The session secret Appwrite set for `project`, i.e. the value of the
+    /// `a_session_<project>` cookie.
+    pub fn session(&self, project: &str) -> Option<String> {
+        self.get(&format!("a_session_{}", project.to_lowercase()))
+    }
+
+    pub fn insert(&self, name: impl Into<String>, value: impl Into<String>) {
+        self.lock().insert(name.into(), value.into());
+    }
+
+    pub fn remove(&self, name: &str) -> Option<String> {
+        self.lock().remove(name)
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// All cookies, sorted by name.
+    pub fn cookies(&self) -> Vec<(String, String)> {
+        self.lock()
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, String>> {
+        self.cookies.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn store(&self, name: &str, value: &str, expired: bool) {
+        match expired || value.is_empty() {
+            true => self.remove(name),
+            false => self.lock().insert(name.to_string(), value.to_string()),
+        };
+    }
+
+    /// Send the stored cookies with a request.
+    pub(crate) fn add_to(&self, headers: &mut HeaderMap) {
+        let cookies = self
+            .lock()
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        if let Ok(value) = HeaderValue::from_str(&cookies) {
+            if !cookies.is_empty() {
+                headers.insert(COOKIE, value);
+            }
+        }
+    }
+
+    /// Store the cookies a response sets.
+    pub(crate) fn take_from(&self, headers: &HeaderMap) {
+        for header in headers.get_all(SET_COOKIE) {
+            if let Ok(header) = header.to_str() {
+                self.set_cookie(header);
+            }
+        }
+        let fallback = headers
+            .get("x-fallback-cookies")
+            .and_then(|header| header.to_str().ok())
+            .and_then(|header| serde_json::from_str::<BTreeMap<String, Value>>(header).ok());
+        for (name, value) in fallback.unwrap_or_default() {
+            self.store(&name, value.as_str().unwrap_or_default(), false);
+        }
+    }
+
+    /// Apply one `Set-Cookie` header value.
+    fn set_cookie(&self, header: &str) {
+        let mut attributes = header.split(';').map(str::trim);
+        let Some((name, value)) = attributes.next().and_then(|pair| pair.split_once('=')) else {
+            return;
+        };
+        let expired = attributes.any(|attribute| {
+            attribute.split_once('=').is_some_and(|(key, value)| {
+                key.eq_ignore_ascii_case("max-age") && value.starts_with(['0', '-'])
+            })
+        });
+        self.store(name.trim(), value.trim().trim_matches('"'), expired);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        client::ClientBuilder,
+        services::server::account::Account,
+        transport::{CannedTransport, HttpResponse},
+    };
+
+    #[test]
+    fn test_cookie_jar_captures_and_sends_session() {
+        let jar = CookieJar::new();
+        let mut headers = HeaderMap::new();
+        headers.append(
+            SET_COOKIE,
+            "a_session_5ff3=s3cr3t; expires=Fri, 01 Jan 2100 00:00:00 GMT; path=/; httponly"
+                .parse()
+                .unwrap(),
+        );
+        headers.append(
+            SET_COOKIE,
+            "a_session_5ff3_legacy=; Max-Age=0; path=/".parse().unwrap(),
+        );
+        jar.insert("a_session_5ff3_legacy", "old");
+        jar.take_from(&headers);
+        assert_eq!(jar.session("5ff3").as_deref(), Some("s3cr3t"));
+        assert_eq!(jar.get("a_session_5ff3_legacy"), None);
+
+        let mut headers = HeaderMap::new();
+        jar.add_to(&mut headers);
+        assert_eq!(headers[COOKIE], "a_session_5ff3=s3cr3t");
+    }
+
+    /// Answers `DELETE /account/sessions/{id}` with a session cookie
+    /// holding `id`.
+    fn sessions(ids: &[&str]) -> CannedTransport {
+        ids.iter().fold(
+            CannedTransport::new().json("/account/prefs", json!({})),
+            |transport, id| {
+                let cookie = format!("a_session_5ff3={id}; path=/; httponly");
+                transport.respond(
+                    format!("/account/sessions/{id}"),
+                    HttpResponse::from_json(StatusCode::OK, &json!({}))
+                        .with_header(SET_COOKIE, cookie.parse().unwrap()),
+                )
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_views_dont_share_cookies() {
+        let transport = sessions(&["ada", "bob"]);
+        let client = ClientBuilder::default()
+            .set_project("5ff3")
+            .unwrap()
+            .set_key("cd868c7af8bdc893b4")
+            .unwrap()
+            .set_transport(transport.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        let ada = client.with_cookie_jar();
+        let bob = client.with_cookie_jar();
+
+        Account::delete_session(&ada, "ada").await.unwrap();
+        Account::delete_session(&bob, "bob").await.unwrap();
+        Account::get_preference(&ada).await.unwrap();
+        Account::get_preference(&bob).await.unwrap();
+        Account::get_preference(&client).await.unwrap();
+
+        let session = |view: &crate::client::Client| view.cookie_jar().unwrap().session("5ff3");
+        assert_eq!(session(&ada).as_deref(), Some("ada"));
+        assert_eq!(session(&bob).as_deref(), Some("bob"));
+        assert!(client.cookie_jar().is_none());
+        let requests = transport
+            .requests()
+            .into_iter()
+            .map(|request| {
+                let cookie = request.headers.get(COOKIE);
+                (
+                    cookie.map_or(String::new(), |value| value.to_str().unwrap().to_string()),
+                    request.headers.contains_key("x-appwrite-key"),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            requests,
+            [
+                (String::new(), false),
+                (String::new(), false),
+                (String::from("a_session_5ff3=ada"), false),
+                (String::from("a_session_5ff3=bob"), false),
+                (String::new(), true),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1249 @@
+//! Endpoint lists shared by [`crate::handles`] and `crate::blocking`.
+//!
+//! Each macro passes the signatures of one service to a mirror macro
+//! (`service_handle!` or `blocking_service!`) together with the name of the
+//! type to declare, so both mirrors are generated from the same list and
+//! can't drift apart. Types in the signatures resolve where the mirror is
+//! invoked.
+
+/// Endpoints of [`Account`](crate::services::server::account::Account).
+macro_rules! account {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::account::Account {
+                fn get() -> User;
+                fn create(user_id: &str, email: &str, password: &str, name: Option<&str>) -> User;
+                fn update_email(email: &str, password: &str) -> User;
+                fn list_identities(queries: Option<Vec<Query>>) -> IdentityList;
+                fn delete_identity(identity_id: &str) -> ();
+                fn create_jwt() -> JWT;
+                fn list_logs(queries: Option<Vec<Query>>) -> LogList;
+                fn update_mfa(mfa: bool) -> User;
+                fn create_mfa_authenticator(x_type: AuthenticationType) -> MfaType;
+                fn update_mfa_authenticator(x_type: AuthenticationType, otp: &str) -> User;
+                fn delete_mfa_authenticator(x_type: AuthenticationType, otp: &str) -> User;
+                fn create_mfa_challenge(factor: AuthenticationFactor) -> MfaChallenge;
+                fn update_mfa_challenge(challenge_id: &str, otp: &str) -> ();
+                fn list_mfa_factors() -> MfaFactors;
+                fn get_mfa_recovery_codes() -> MfaRecoveryCodes;
+                fn create_mfa_recovery_codes() -> MfaRecoveryCodes;
+                fn update_mfa_recovery_codes() -> MfaRecoveryCodes;
+                fn update_name(name: &str) -> User;
+                fn update_password(password: &str, old_password: &str) -> User;
+                fn update_phone(phone: &str, password: &str) -> User;
+                fn get_preference() -> Preferences;
+                fn update_preference(preference: Value) -> User;
+                fn create_recovery(email: &str, url: &str) -> Token;
+                fn update_recovery(
+                    user_id: &str,
+                    secret: &str,
+                    password: &str,
+                    password_again: &str,
+                ) -> Token;
+                fn list_sessions() -> SessionList;
+                fn delete_sessions() -> ();
+                fn create_anonymous_session() -> Session;
+                fn create_email_password_session(email: &str, password: &str) -> Session;
+                fn create_magic_url_session(user_id: &str, secret: &str) -> Session;
+                fn update_phone_session(user_id: &str, secret: &str) -> Session;
+                fn create_session(user_id: &str, secret: &str) -> Session;
+                fn get_session(session_id: &str) -> Session;
+                fn update_session(session_id: &str) -> Session;
+                fn delete_session(session_id: &str) -> ();
+                fn update_status() -> User;
+                fn create_email_token(user_id: &str, email: &str, phrase: Option<bool>) -> Token;
+                fn create_magic_url_token(
+                    user_id: &str,
+                    email: &str,
+                    url: Option<&str>,
+                    phrase: Option<bool>,
+                ) -> Token;
+                fn create_oauth2_token(
+                    provider: OAuthProvider,
+                    success: Option<&str>,
+                    failure: Option<&str>,
+                    scopes: Option<Vec<&str>>,
+                ) -> ();
+                fn create_phone_token(user_id: &str, phone: &str) -> Token;
+                fn create_verification(url: &str) -> Token;
+                fn update_verification(user_id: &str, secret: &str) -> Token;
+                fn create_phone_verification() -> Token;
+                fn update_phone_verification(user_id: &str, secret: &str) -> Token;
+                stream fn list_identities_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Identity;
+                stream fn list_logs_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Log;
+            }
+        }
+    };
+}
+pub(crate) use account;
+
+/// Endpoints of [`Avatars`](crate::services::server::avatar::Avatars).
+macro_rules! avatar {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::avatar::Avatars {
+                fn get_browser(
+                    code: &str,
+                    width: Option<u64>,
+                    height: Option<u64>,
+                    quality: Option<u64>,
+                ) -> Vec<u8>;
+                fn get_credit_card(
+                    code: &str,
+                    width: Option<u64>,
+                    height: Option<u64>,
+                    quality: Option<u64>,
+                ) -> Vec<u8>;
+                fn get_fav_icon(url: &str) -> Vec<u8>;
+                fn get_flag(
+                    code: Flag,
+                    width: Option<u64>,
+                    height: Option<u64>,
+                    quality: Option<u64>,
+                ) -> Vec<u8>;
+                fn get_image(url: &str, width: Option<u64>, height: Option<u64>) -> Vec<u8>;
+                fn get_initials(
+                    name: Option<&str>,
+                    width: Option<u64>,
+                    height: Option<u64>,
+                    background: Option<&str>,
+                ) -> Vec<u8>;
+                fn get_qr(
+                    text: &str,
+                    size: Option<u64>,
+                    margin: Option<u64>,
+                    download: Option<bool>,
+                ) -> Vec<u8>;
+            }
+        }
+    };
+}
+pub(crate) use avatar;
+
+/// Endpoints of [`Databases`](crate::services::server::databases::Databases).
+macro_rules! databases {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::databases::Databases {
+                fn list(search: Option<String>, queries: Option<Vec<Query>>) -> DatabaseList;
+                fn create(database_id: &str, name: &str, enabled: Option<bool>) -> Database;
+                fn get(database_id: &str) -> Database;
+                fn update(database_id: &str, name: &str, enabled: Option<bool>) -> Database;
+                fn delete(database_id: &str) -> ();
+                fn list_collections(
+                    database_id: &str,
+                    search: Option<String>,
+                    queries: Option<Vec<Query>>,
+                ) -> CollectionList;
+                fn create_collection(
+                    database_id: &str,
+                    collection_id: &str,
+                    name: &str,
+                    permissions: Option<Vec<String>>,
+                    document_security: Option<bool>,
+                    enabled: Option<bool>,
+                ) -> Collection;
+                fn get_collection(database_id: &str, collection_id: &str) -> Collection;
+                fn update_collection(
+                    database_id: &str,
+                    collection_id: &str,
+                    name: &str,
+                    permissions: Option<Vec<String>>,
+                    document_security: Option<bool>,
+                    enabled: Option<bool>,
+                ) -> Collection;
+                fn delete_collection(database_id: &str, collection_id: &str) -> ();
+                fn list_attributes(
+                    database_id: &str,
+                    collection_id: &str,
+                    queries: Option<Vec<Query>>,
+                ) -> AttributeList;
+                fn create_boolean_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<bool>,
+                    array: Option<bool>,
+                ) -> AttributeBoolean;
+                fn update_boolean_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<bool>,
+                ) -> AttributeBoolean;
+                fn create_date_time_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                    array: Option<bool>,
+                ) -> AttributeDateTime;
+                fn update_date_time_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                ) -> AttributeDateTime;
+                fn create_email_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                    array: Option<bool>,
+                ) -> AttributeEmail;
+                fn update_email_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                ) -> AttributeEmail;
+                fn create_enum_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    elements: Vec<&str>,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                    array: Option<bool>,
+                ) -> AttributeEnum;
+                fn update_enum_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    elements: Vec<&str>,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                ) -> AttributeEnum;
+                fn create_float_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    min: Option<f64>,
+                    max: Option<f64>,
+                    x_default: Option<f64>,
+                    array: Option<bool>,
+                ) -> AttributeFloat;
+                fn update_float_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    min: Option<f64>,
+                    max: Option<f64>,
+                    x_required: bool,
+                    x_default: Option<f64>,
+                ) -> AttributeFloat;
+                fn create_integer_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    min: Option<i64>,
+                    max: Option<i64>,
+                    x_default: Option<i64>,
+                    array: Option<bool>,
+                ) -> AttributeInteger;
+                fn update_integer_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    min: Option<i64>,
+                    max: Option<i64>,
+                    x_required: bool,
+                    x_default: Option<i64>,
+                ) -> AttributeInteger;
+                fn create_ip_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                    array: Option<bool>,
+                ) -> AttributeIp;
+                fn update_ip_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                ) -> AttributeIp;
+                fn create_relationship_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    related_collection_id: &str,
+                    relationship_type: RelationshipType,
+                    two_way: Option<bool>,
+                    key: Option<&str>,
+                    two_way_key: Option<&str>,
+                    on_delete: Option<RelationMutate>,
+                ) -> AttributeRelationship;
+                fn create_string_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    size: u64,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                    array: Option<bool>,
+                    encrypt: Option<bool>,
+                ) -> AttributeString;
+                fn update_string_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                ) -> AttributeString;
+                fn create_url_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                    array: Option<bool>,
+                ) -> AttributeUrl;
+                fn update_url_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    x_required: bool,
+                    x_default: Option<&str>,
+                ) -> AttributeUrl;
+                fn get_attribute(database_id: &str, collection_id: &str, key: &str) -> Value;
+                fn delete_attribute(database_id: &str, collection_id: &str, key: &str) -> ();
+                fn update_relationship_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    on_delete: Option<RelationMutate>,
+                ) -> AttributeRelationship;
+                fn list_documents(
+                    database_id: &str,
+                    collection_id: &str,
+                    queries: Option<Vec<Query>>,
+                ) -> DocumentList;
+                fn create_documents(
+                    database_id: &str,
+                    collection_id: &str,
+                    document_id: &str,
+                    data: Map<String, Value>,
+                    permissions: Option<Vec<String>>,
+                ) -> Document;
+                fn get_document(
+                    database_id: &str,
+                    collection_id: &str,
+                    document_id: &str,
+                    queries: Option<Vec<Query>>,
+                ) -> Document;
+                fn get_document_as<T: DeserializeOwned>(
+                    database_id: &str,
+                    collection_id: &str,
+                    document_id: &str,
+                    queries: Option<Vec<Query>>,
+                ) -> TypedDocument<T>;
+                fn list_documents_as<T: DeserializeOwned>(
+                    database_id: &str,
+                    collection_id: &str,
+                    queries: Option<Vec<Query>>,
+                ) -> TypedDocumentList<T>;
+                fn create_document_as<T: Serialize + DeserializeOwned>(
+                    database_id: &str,
+                    collection_id: &str,
+                    document_id: &str,
+                    data: &T,
+                    permissions: Option<Vec<String>>,
+                ) -> TypedDocument<T>;
+                fn update_document_as<T: DeserializeOwned>(
+                    database_id: &str,
+                    collection_id: &str,
+                    document_id: &str,
+                    data: &impl Serialize,
+                    permissions: Option<Vec<String>>,
+                ) -> TypedDocument<T>;
+                fn update_document(
+                    database_id: &str,
+                    collection_id: &str,
+                    document_id: &str,
+                    data: Option<Map<String, Value>>,
+                    permissions: Option<Vec<String>>,
+                ) -> Document;
+                fn delete_document(database_id: &str, collection_id: &str, document_id: &str) -> ();
+                fn list_indexes(
+                    database_id: &str,
+                    collection_id: &str,
+                    queries: Option<Vec<Query>>,
+                ) -> IndexList;
+                fn create_index(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    index_type: IndexType,
+                    attributes: Vec<&str>,
+                    orders: Option<Vec<&str>>,
+                ) -> Index;
+                fn get_index(database_id: &str, collection_id: &str, key: &str) -> Index;
+                fn delete_index(database_id: &str, collection_id: &str, key: &str) -> ();
+                fn wait_for_attribute(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    options: Option<WaitOptions>,
+                ) -> Value;
+                fn wait_for_index(
+                    database_id: &str,
+                    collection_id: &str,
+                    key: &str,
+                    options: Option<WaitOptions>,
+                ) -> Index;
+                stream fn list_stream<'a>(
+                    search: Option<String>,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Database;
+                stream fn list_collections_stream<'a>(
+                    database_id: &'a str,
+                    search: Option<String>,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Collection;
+                stream fn list_documents_stream<'a>(
+                    database_id: &'a str,
+                    collection_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Document;
+                stream fn list_documents_as_stream<'a, T: DeserializeOwned>(
+                    database_id: &'a str,
+                    collection_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> TypedDocument<T>;
+                stream fn list_attributes_stream<'a>(
+                    database_id: &'a str,
+                    collection_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Value;
+                stream fn list_indexes_stream<'a>(
+                    database_id: &'a str,
+                    collection_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Index;
+            }
+        }
+    };
+}
+pub(crate) use databases;
+
+/// Endpoints of [`Functions`](crate::services::server::functions::Functions).
+macro_rules! functions {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::functions::Functions {
+                fn list(queries: Option<Vec<Query>>, search: Option<String>) -> FunctionList;
+                fn create(
+                    function_id: &str,
+                    name: &str,
+                    runtime: Runtime,
+                    execute: Option<Vec<&str>>,
+                    events: Option<Vec<&str>>,
+                    schedule: Option<&str>,
+                    timeout: Option<u64>,
+                    enabled: Option<bool>,
+                    logging: Option<bool>,
+                    entry_point: Option<&str>,
+                    commands: Option<&str>,
+                    installation_id: Option<&str>,
+                    provider_repository_id: Option<&str>,
+                    provider_branch: Option<&str>,
+                    provider_silent_mode: Option<bool>,
+                    provider_root_directory: Option<&str>,
+                    template_repository: Option<&str>,
+                    template_owner: Option<&str>,
+                    template_root_directory: Option<&str>,
+                    template_branch: Option<&str>,
+                ) -> Func;
+                fn list_run_times() -> RuntimeList;
+                fn get(function_id: &str) -> Func;
+                fn update(
+                    function_id: &str,
+                    name: &str,
+                    runtime: Option<Runtime>,
+                    execute: Option<Vec<&str>>,
+                    events: Option<Vec<&str>>,
+                    schedule: Option<&str>,
+                    timeout: Option<u64>,
+                    enabled: Option<bool>,
+                    logging: Option<bool>,
+                    entry_point: Option<&str>,
+                    commands: Option<&str>,
+                    installation_id: Option<&str>,
+                    provider_repository_id: Option<&str>,
+                    provider_branch: Option<&str>,
+                    provider_silent_mode: Option<bool>,
+                    provider_root_directory: Option<&str>,
+                ) -> Func;
+                fn delete(function_id: &str) -> ();
+                fn list_deployments(
+                    function_id: &str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                ) -> DeploymentList;
+                fn create_deployments(
+                    function_id: &str,
+                    // code: InputFile,
+                    file_path: &str,
+                    file_name: String,
+                    activate: bool,
+                    entrypoint: Option<&str>,
+                    commands: Option<&str>,
+                ) -> Deployment;
+                fn get_deployments(function_id: &str, deployment_id: &str) -> Deployment;
+                fn update_deployments(function_id: &str, deployment_id: &str) -> Func;
+                fn delete_deployments(function_id: &str, deployment_id: &str) -> ();
+                fn create_build(function_id: &str, deployment_id: &str, build_id: &str) -> Value;
+                fn download_deployment(function_id: &str, deployment_id: &str) -> Vec<u8>;
+                fn list_executions(
+                    function_id: &str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                ) -> ExecutionList;
+                fn create_executions(
+                    function_id: &str,
+                    body: Option<&str>,
+                    x_async: Option<bool>,
+                    path: Option<&str>,
+                    method: Option<ExecutionMethod>,
+                    headers: Option<Map<String, Value>>,
+                    //should be Map,
+                ) -> Execution;
+                fn get_executions(function_id: &str, execution_id: &str) -> Execution;
+                fn list_variables(function_id: &str) -> VariableList;
+                fn create_variables(function_id: &str, key: &str, value: &str) -> Variable;
+                fn get_variables(function_id: &str, variable_id: &str) -> Variable;
+                fn update_variables(
+                    function_id: &str,
+                    variable_id: &str,
+                    key: &str,
+                    value: Option<&str>,
+                ) -> Variable;
+                fn delete_variables(function_id: &str, variable_id: &str) -> ();
+                stream fn list_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Func;
+                stream fn list_deployments_stream<'a>(
+                    function_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Deployment;
+                stream fn list_executions_stream<'a>(
+                    function_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Execution;
+            }
+        }
+    };
+}
+pub(crate) use functions;
+
+/// Endpoints of [`Graphql`](crate::services::server::graphql::Graphql).
+macro_rules! graphql {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::graphql::Graphql {
+                fn query(query: Map<String, Value>) -> Value;
+                fn mutation(query: Map<String, Value>) -> Value;
+            }
+        }
+    };
+}
+pub(crate) use graphql;
+
+/// Endpoints of [`Health`](crate::services::server::health::Health).
+macro_rules! health {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::health::Health {
+                fn get() -> HealthStatus;
+                fn get_antivirus() -> HealthAntivirus;
+                fn get_cache() -> HealthStatus;
+                fn get_certificate(domain: Option<&str>) -> HealthCertificate;
+                fn get_db() -> HealthStatus;
+                fn get_pub_sub() -> HealthStatus;
+                fn get_queue() -> HealthStatus;
+                fn get_queue_builds(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_certificates(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_databases(name: Option<&str>, threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_deletes(threshold: Option<usize>) -> HealthQueue;
+                fn get_failed_jobs(name: Name, threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_functions(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_logs(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_mails(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_messaging(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_migrations(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_usage(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_usage_dump(threshold: Option<usize>) -> HealthQueue;
+                fn get_queue_webhooks(threshold: Option<usize>) -> HealthQueue;
+                fn get_storage() -> HealthStatus;
+                fn get_storage_local() -> HealthStatus;
+                fn get_time() -> HealthTime;
+            }
+        }
+    };
+}
+pub(crate) use health;
+
+/// Endpoints of [`Locale`](crate::services::server::locale::Locale).
+macro_rules! locale {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::locale::Locale {
+                fn get() -> MLocale;
+                fn list_codes() -> LocaleCode;
+                fn list_continents() -> ContinentList;
+                fn list_countries() -> CountryList;
+                fn list_countries_eu() -> CountryList;
+                fn list_countries_phones() -> PhoneList;
+                fn list_currencies() -> CurrencyList;
+                fn list_languages() -> LanguageList;
+            }
+        }
+    };
+}
+pub(crate) use locale;
+
+/// Endpoints of [`Messaging`](crate::services::server::messaging::Messaging).
+macro_rules! messaging {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::messaging::Messaging {
+                fn list_messages(queries: Option<Vec<Query>>, search: Option<String>) -> MessageList;
+                fn create_email(
+                    message_id: &str,
+                    subject: &str,
+                    content: &str,
+                    topics: Option<Vec<&str>>,
+                    users: Option<Vec<&str>>,
+                    targets: Option<Vec<&str>>,
+                    cc: Option<Vec<&str>>,
+                    bcc: Option<Vec<&str>>,
+                    attachments: Option<Vec<&str>>,
+                    draft: Option<bool>,
+                    html: Option<bool>,
+                    scheduled_at: Option<bool>,
+                ) -> Message;
+                fn update_email(
+                    message_id: &str,
+                    topics: Option<Vec<&str>>,
+                    users: Option<Vec<&str>>,
+                    targets: Option<Vec<&str>>,
+                    subject: Option<&str>,
+                    content: Option<&str>,
+                    draft: Option<bool>,
+                    html: Option<bool>,
+                    cc: Option<Vec<&str>>,
+                    bcc: Option<Vec<&str>>,
+                    scheduled_at: Option<&str>,
+                ) -> Message;
+                fn create_push(
+                    message_id: &str,
+                    title: &str,
+                    body: &str,
+                    topics: Option<Vec<&str>>,
+                    users: Option<Vec<&str>>,
+                    targets: Option<Vec<&str>>,
+                    data: Option<Map<String, Value>>,
+                    action: Option<&str>,
+                    image: Option<&str>,
+                    icon: Option<&str>,
+                    sound: Option<&str>,
+                    color: Option<&str>,
+                    tag: Option<&str>,
+                    badge: Option<&str>,
+                    draft: Option<bool>,
+                    scheduled_at: Option<&str>,
+                ) -> Message;
+                fn update_push(
+                    message_id: &str,
+                    topics: Option<Vec<&str>>,
+                    users: Option<Vec<&str>>,
+                    targets: Option<Vec<&str>>,
+                    title: Option<&str>,
+                    body: Option<&str>,
+                    data: Option<Map<String, Value>>,
+                    action: Option<&str>,
+                    image: Option<&str>,
+                    icon: Option<&str>,
+                    sound: Option<&str>,
+                    color: Option<&str>,
+                    tag: Option<&str>,
+                    badge: Option<i32>,
+                    draft: Option<bool>,
+                    scheduled_at: Option<&str>,
+                ) -> Message;
+                fn create_sms(
+                    message_id: &str,
+                    content: &str,
+                    topics: Option<Vec<&str>>,
+                    users: Option<Vec<&str>>,
+                    targets: Option<Vec<&str>>,
+                    draft: Option<bool>,
+                    scheduled_at: Option<&str>,
+                ) -> Message;
+                fn update_sms(
+                    message_id: &str,
+                    topics: Option<Vec<&str>>,
+                    users: Option<Vec<&str>>,
+                    targets: Option<Vec<&str>>,
+                    content: Option<&str>,
+                    draft: Option<bool>,
+                    scheduled_at: Option<&str>,
+                ) -> Message;
+                fn get_message(message_id: &str) -> Message;
+                fn delete_message(message_id: &str) -> ();
+                fn list_message_logs(message_id: &str, queries: Option<Vec<Query>>) -> LogList;
+                fn list_targets(message_id: &str, queries: Option<Vec<Query>>) -> TargetList;
+                fn list_providers(queries: Option<Vec<Query>>, search: Option<Vec<&str>>) -> ProviderList;
+                fn create_apns_provider(
+                    provider_id: &str,
+                    name: &str,
+                    auth_key: Option<&str>,
+                    auth_key_id: Option<&str>,
+                    team_id: Option<&str>,
+                    bundle_id: Option<&str>,
+                    sandbox: Option<bool>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_apns_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    enabled: Option<bool>,
+                    auth_key: Option<&str>,
+                    auth_key_id: Option<&str>,
+                    team_id: Option<&str>,
+                    bundle_id: Option<&str>,
+                    sandbox: Option<bool>,
+                ) -> Provider;
+                fn create_fcm_provider(
+                    provider_id: &str,
+                    name: &str,
+                    service_account_json: Option<Map<String, Value>>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_fcm_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    enabled: Option<bool>,
+                    service_account_json: Option<Map<String, Value>>,
+                ) -> Provider;
+                fn create_mail_gun_provider(
+                    provider_id: &str,
+                    name: &str,
+                    api_key: Option<&str>,
+                    domain: Option<&str>,
+                    is_eu_region: Option<bool>,
+                    from_name: Option<&str>,
+                    from_email: Option<&str>,
+                    reply_to_name: Option<&str>,
+                    reply_to_email: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_mail_gun_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    api_key: Option<&str>,
+                    domain: Option<&str>,
+                    is_eu_region: Option<bool>,
+                    enabled: Option<bool>,
+                    from_name: Option<&str>,
+                    from_email: Option<&str>,
+                    reply_to_name: Option<&str>,
+                    reply_to_email: Option<&str>,
+                ) -> Provider;
+                fn create_msg_91_provider(
+                    provider_id: &str,
+                    name: &str,
+                    template_id: Option<&str>,
+                    sender_id: Option<&str>,
+                    auth_key: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_msg_91_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    enabled: Option<bool>,
+                    template_id: Option<&str>,
+                    sender_id: Option<&str>,
+                    auth_key: Option<&str>,
+                ) -> Provider;
+                fn create_send_grid_provider(
+                    provider_id: &str,
+                    name: &str,
+                    api_key: Option<&str>,
+                    from_name: Option<&str>,
+                    from_email: Option<&str>,
+                    reply_to_name: Option<&str>,
+                    reply_to_email: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_send_grid_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    enabled: Option<bool>,
+                    api_key: Option<&str>,
+                    from_name: Option<&str>,
+                    from_email: Option<&str>,
+                    reply_to_name: Option<&str>,
+                    reply_to_email: Option<&str>,
+                ) -> Provider;
+                fn create_smtp_provider(
+                    provider_id: &str,
+                    name: &str,
+                    host: &str,
+                    port: Option<i32>,
+                    username: Option<&str>,
+                    password: Option<&str>,
+                    encryption: Option<SmtpEncryption>,
+                    auto_tls: Option<bool>,
+                    mailer: Option<&str>,
+                    from_name: Option<&str>,
+                    from_email: Option<&str>,
+                    reply_to_name: Option<&str>,
+                    reply_to_email: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_smtp_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    host: Option<&str>,
+                    port: Option<i32>,
+                    username: Option<&str>,
+                    password: Option<&str>,
+                    encryption: Option<SmtpEncryption>,
+                    auto_tls: Option<bool>,
+                    mailer: Option<&str>,
+                    from_name: Option<&str>,
+                    from_email: Option<&str>,
+                    reply_to_name: Option<&str>,
+                    reply_to_email: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn create_telesign_provider(
+                    provider_id: &str,
+                    name: &str,
+                    from: Option<&str>,
+                    customer_id: Option<&str>,
+                    api_key: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_telesign_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    enabled: Option<bool>,
+                    customer_id: Option<&str>,
+                    api_key: Option<&str>,
+                    from: Option<&str>,
+                ) -> Provider;
+                fn create_text_magic_provider(
+                    provider_id: &str,
+                    name: &str,
+                    from: Option<&str>,
+                    username: Option<&str>,
+                    api_key: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_text_magic_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    enabled: Option<bool>,
+                    username: Option<&str>,
+                    api_key: Option<&str>,
+                    from: Option<&str>,
+                ) -> Provider;
+                fn create_twilio_provider(
+                    provider_id: &str,
+                    name: &str,
+                    from: Option<&str>,
+                    account_id: Option<&str>,
+                    auth_token: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_twilio_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    enabled: Option<bool>,
+                    accounts_id: Option<&str>,
+                    auth_token: Option<&str>,
+                    from: Option<&str>,
+                ) -> Provider;
+                fn create_vonage_provider(
+                    provider_id: &str,
+                    name: &str,
+                    from: Option<&str>,
+                    api_key: Option<&str>,
+                    api_secret: Option<&str>,
+                    enabled: Option<bool>,
+                ) -> Provider;
+                fn update_vonage_provider(
+                    provider_id: &str,
+                    name: Option<&str>,
+                    enabled: Option<bool>,
+                    api_key: Option<&str>,
+                    api_secret: Option<&str>,
+                    from: Option<&str>,
+                ) -> Provider;
+                fn get_provider(provider_id: &str) -> Provider;
+                fn delete_provider(provider_id: &str) -> ();
+                fn list_provider_logs(provider_id: &str, queries: Option<Vec<Query>>) -> LogList;
+                fn list_subscriber_logs(subscriber_id: &str, queries: Option<Vec<Query>>) -> LogList;
+                fn list_topics(queries: Option<Vec<Query>>, search: Option<String>) -> TopicList;
+                fn create_topics(topic_id: &str, name: &str, subscribe: Option<Vec<&str>>) -> Topic;
+                fn get_topic(topic_id: &str) -> Topic;
+                fn update_topic(topic_id: &str, name: Option<&str>, subscribe: Option<Vec<&str>>) -> Topic;
+                fn delete_topic(topic_id: &str) -> ();
+                fn list_topic_logs(topic_id: &str, queries: Option<Vec<Query>>) -> LogList;
+                fn list_subscribers(
+                    topic_id: &str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                ) -> SubscriberList;
+                fn create_subscriber(topic_id: &str, subscriber_id: &str, target_id: &str) -> Subscriber;
+                fn get_subscriber(topic_id: &str, subscriber_id: &str) -> Subscriber;
+                fn delete_subscriber(topic_id: &str, subscriber_id: &str) -> ();
+                stream fn list_messages_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Message;
+                stream fn list_targets_stream<'a>(
+                    message_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Target;
+                stream fn list_providers_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    search: Option<Vec<&'a str>>,
+                    page_size: Option<usize>,
+                ) -> Provider;
+                stream fn list_topics_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Topic;
+                stream fn list_subscribers_stream<'a>(
+                    topic_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Subscriber;
+                stream fn list_message_logs_stream<'a>(
+                    message_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Log;
+                stream fn list_provider_logs_stream<'a>(
+                    provider_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Log;
+                stream fn list_subscriber_logs_stream<'a>(
+                    subscriber_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Log;
+                stream fn list_topic_logs_stream<'a>(
+                    topic_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Log;
+            }
+        }
+    };
+}
+pub(crate) use messaging;
+
+/// Endpoints of [`Storage`](crate::services::server::storage::Storage).
+macro_rules! storage {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::storage::Storage {
+                fn list_buckets(queries: Option<Vec<Query>>, search: Option<String>) -> BucketList;
+                fn create_bucket(
+                    bucket_id: &str,
+                    name: &str,
+                    permissions: Option<Vec<String>>,
+                    file_security: Option<bool>,
+                    enabled: Option<bool>,
+                    maximum_file_size: Option<usize>,
+                    allowed_file_extensions: Option<Vec<&str>>,
+                    compression: Option<Compression>,
+                    encryption: Option<bool>,
+                    antivirus: Option<bool>,
+                ) -> Bucket;
+                fn get_bucket(bucket_id: &str) -> Bucket;
+                fn update_bucket(
+                    bucket_id: &str,
+                    name: &str,
+                    permissions: Option<Vec<String>>,
+                    file_security: Option<bool>,
+                    enabled: Option<bool>,
+                    maximum_file_size: Option<usize>,
+                    allowed_file_extensions: Option<Vec<&str>>,
+                    compression: Option<Compression>,
+                    encryption: Option<bool>,
+                    antivirus: Option<bool>,
+                ) -> Bucket;
+                fn delete_bucket(bucket_id: &str) -> ();
+                fn list_files(
+                    bucket_id: &str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                ) -> FileList;
+                fn create_files(
+                    bucket_id: &str,
+                    file_id: &str,
+                    file_path: &str,
+                    file_name: String,
+                    permissions: Option<Vec<String>>,
+                ) -> File;
+                fn get_file(bucket_id: &str, file_id: &str) -> File;
+                fn update_file(
+                    bucket_id: &str,
+                    file_id: &str,
+                    name: Option<&str>,
+                    permissions: Option<Vec<String>>,
+                ) -> File;
+                fn delete_file(bucket_id: &str, file_id: &str) -> ();
+                fn get_file_download(bucket_id: &str, file_id: &str) -> Vec<u8>;
+                fn get_file_preview(
+                    bucket_id: &str,
+                    file_id: &str,
+                    width: Option<usize>,
+                    height: Option<usize>,
+                    gravity: Option<ImageGravity>,
+                    quality: Option<usize>,
+                    border_width: Option<usize>,
+                    border_color: Option<&str>,
+                    border_radius: Option<usize>,
+                    opacity: Option<f32>,
+                    rotation: Option<usize>,
+                    background: Option<&str>,
+                    output: Option<ImageFormat>,
+                ) -> Vec<u8>;
+                fn get_file_view(bucket_id: &str, file_id: &str) -> Vec<u8>;
+                stream fn list_buckets_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Bucket;
+                stream fn list_files_stream<'a>(
+                    bucket_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> File;
+            }
+        }
+    };
+}
+pub(crate) use storage;
+
+/// Endpoints of [`Teams`](crate::services::server::teams::Teams).
+macro_rules! teams {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::teams::Teams {
+                fn list(queries: Option<Vec<Query>>, search: Option<String>) -> TeamList;
+                fn create(team_id: &str, name: &str, roles: Option<Vec<&str>>) -> Team;
+                fn get(team_id: &str) -> Team;
+                fn update_name(team_id: &str, name: &str) -> Team;
+                fn delete(team_id: &str) -> ();
+                fn list_memberships(
+                    team_id: &str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                ) -> MembershipList;
+                fn create_memberships(
+                    team_id: &str,
+                    roles: Vec<&str>,
+                    email: Option<&str>,
+                    user_id: Option<&str>,
+                    phone: Option<&str>,
+                    url: Option<&str>,
+                    name: Option<&str>,
+                ) -> Membership;
+                fn get_memberships(team_id: &str, membership_id: &str) -> Membership;
+                fn update_memberships(team_id: &str, membership_id: &str, roles: Vec<&str>) -> Membership;
+                fn delete_memberships(team_id: &str, membership_id: &str) -> ();
+                fn update_membership_status(
+                    team_id: &str,
+                    membership_id: &str,
+                    user_id: &str,
+                    secret: &str,
+                ) -> Membership;
+                fn get_prefs(team_id: &str) -> Preferences;
+                fn update_prefs(team_id: &str, prefs: Map<String, Value>) -> Preferences;
+                stream fn list_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Team;
+                stream fn list_memberships_stream<'a>(
+                    team_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Membership;
+            }
+        }
+    };
+}
+pub(crate) use teams;
+
+/// Endpoints of [`Users`](crate::services::server::users::Users).
+macro_rules! users {
+    ($mirror:ident!($name:ident)) => {
+        $mirror! {
+            $name => crate::services::server::users::Users {
+                fn list(queries: Option<Vec<Query>>, search: Option<String>) -> UserList;
+                fn create(
+                    user_id: &str,
+                    email: Option<&str>,
+                    phone: Option<&str>,
+                    password: Option<&str>,
+                    name: Option<&str>,
+                ) -> User;
+                fn create_argon2_user(
+                    user_id: &str,
+                    email: &str,
+                    password: &str,
+                    name: Option<&str>,
+                ) -> User;
+                fn create_bcrypt_user(
+                    user_id: &str,
+                    email: &str,
+                    password: &str,
+                    name: Option<&str>,
+                ) -> User;
+                fn list_identities(queries: Option<Vec<Query>>, search: Option<String>) -> IdentityList;
+                fn delete_identity(identity_id: &str) -> ();
+                fn create_md5_user(user_id: &str, email: &str, password: &str, name: Option<&str>) -> User;
+                fn create_phpass_user(
+                    user_id: &str,
+                    email: &str,
+                    password: &str,
+                    name: Option<&str>,
+                ) -> User;
+                fn create_scrypt_user(
+                    user_id: &str,
+                    email: &str,
+                    password: &str,
+                    password_salt: &str,
+                    password_cpu: usize,
+                    password_memory: usize,
+                    password_parallel: usize,
+                    password_length: usize,
+                    name: Option<&str>,
+                ) -> User;
+                fn create_scrypt_modified_user(
+                    user_id: &str,
+                    email: &str,
+                    password: &str,
+                    password_salt: &str,
+                    password_salt_separator: &str,
+                    password_signer_key: &str,
+                    name: Option<&str>,
+                ) -> User;
+                fn create_sha_user(
+                    user_id: &str,
+                    email: &str,
+                    password: &str,
+                    password_version: PasswordHash,
+                    name: Option<&str>,
+                ) -> User;
+                fn get(user_id: &str) -> User;
+                fn delete(user_id: &str) -> ();
+                fn update_email(user_id: &str, email: &str) -> User;
+                fn update_labels(user_id: &str, labels: Vec<&str>) -> User;
+                fn list_logs(user_id: &str, queries: Option<Vec<Query>>) -> LogList;
+                fn list_membership(user_id: &str) -> MembershipList;
+                fn update_mfa(user_id: &str, mfa: bool) -> User;
+                fn delete_mfa_authenticator(user_id: &str, auth_type: AuthenticationType) -> User;
+                fn list_mfa_factors(user_id: &str) -> MfaFactors;
+                fn get_mfa_recovery_codes(user_id: &str) -> MfaRecoveryCodes;
+                fn update_mfa_recovery_codes(user_id: &str) -> MfaRecoveryCodes;
+                fn create_mfa_recovery_codes(user_id: &str) -> MfaRecoveryCodes;
+                fn update_name(user_id: &str, name: &str) -> User;
+                fn update_password(user_id: &str, password: &str) -> User;
+                fn update_phone(user_id: &str, number: &str) -> User;
+                fn get_prefs(user_id: &str) -> Preferences;
+                fn update_prefs(user_id: &str, prefs: Map<String, Value>) -> Preferences;
+                fn list_sessions(user_id: &str) -> SessionList;
+                fn create_sessions(user_id: &str) -> Session;
+                fn delete_sessions(user_id: &str) -> ();
+                fn delete_session(user_id: &str, session_id: &str) -> ();
+                fn update_status(user_id: &str, status: bool) -> User;
+                fn list_targets(user_id: &str, queries: Option<Vec<Query>>) -> TargetList;
+                fn create_target(
+                    user_id: &str,
+                    target_id: &str,
+                    provider_type: MessagingProviderType,
+                    identifier: &str,
+                    provider_id: Option<&str>,
+                    name: Option<&str>,
+                ) -> Target;
+                fn get_target(user_id: &str, target_id: &str) -> Target;
+                fn update_target(
+                    user_id: &str,
+                    target_id: &str,
+                    identifier: Option<&str>,
+                    provider_id: Option<&str>,
+                    name: Option<&str>,
+                ) -> Target;
+                fn delete_target(user_id: &str, target_id: &str) -> ();
+                fn create_token(user_id: &str, length: Option<usize>, expire: Option<usize>) -> Token;
+                fn update_email_verification(user_id: &str, email_verification: bool) -> User;
+                fn update_phone_verification(user_id: &str, phone_verification: bool) -> User;
+                stream fn list_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> User;
+                stream fn list_identities_stream<'a>(
+                    queries: Option<Vec<Query>>,
+                    search: Option<String>,
+                    page_size: Option<usize>,
+                ) -> Identity;
+                stream fn list_targets_stream<'a>(
+                    user_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Target;
+                stream fn list_logs_stream<'a>(
+                    user_id: &'a str,
+                    queries: Option<Vec<Query>>,
+                    page_size: Option<usize>,
+                ) -> Log;
+            }
+        }
+    };
+}
+pub(crate) use users;
@@ -1,4 +1,5 @@
 /// HTTP methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HttpMethod {
     GET,
     POST,
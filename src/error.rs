@@ -16,6 +16,13 @@ pub enum Error {
         error_type: Option<String>,
     },
 
+    #[error("json error: {0:?}")]
+    Json(#[from] serde_json::Error),
+    #[error("tls error: {0:?}")]
+    Tls(#[from] native_tls::Error),
+    #[error("websocket error: {0:?}")]
+    WebSocket(Box<tokio_tungstenite::tungstenite::Error>),
+
     #[error("invalid header name: {0:#?}")]
     HeaderName(#[from] InvalidHeaderName),
     #[error("invalid header value: {0:#?}")]
@@ -24,6 +31,38 @@ pub enum Error {
     #[error("The file path `{0}` does not exist")]
     FilePathNotExist(String),
 
+    #[error("unexpected response: status {status}, body: {body:?}")]
+    UnexpectedResponse {
+        status: u16,
+        /// The start of the response body.
+        body: String,
+    },
+
+    #[error("Appwrite server version {version} is not supported, expected {supported}")]
+    UnsupportedServerVersion { version: String, supported: String },
+
+    #[error("invalid parameter `{param}`: {reason}")]
+    InvalidParameter { param: &'static str, reason: String },
+
+    #[error("configuration error: {0}")]
+    Config(String),
+
+    #[error("{resource} `{key}` is {status}: {message}")]
+    ProvisioningFailed {
+        resource: &'static str,
+        key: String,
+        status: String,
+        message: String,
+    },
+
+    #[error("{resource} `{key}` is still {status} after {timeout:?}")]
+    ProvisioningTimeout {
+        resource: &'static str,
+        key: String,
+        status: String,
+        timeout: std::time::Duration,
+    },
+
     #[error("Unknown error: probably a None Type")]
     Unknown,
 
@@ -32,6 +71,277 @@ pub enum Error {
 
     #[error("Custom error: {0}")]
     Custom(String),
+
+    /// A cassette couldn't be read, written or replayed.
+    #[cfg(feature = "cassette")]
+    #[error("cassette error: {0}")]
+    Cassette(String),
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Error::WebSocket(Box::new(err))
+    }
+}
+
+impl Error {
+    /// The typed Appwrite error type, for [`Error::AppWriteError`]s that carry one.
+    pub fn kind(&self) -> Option<AppwriteErrorKind> {
+        match self {
+            Error::AppWriteError {
+                error_type: Some(error_type),
+                ..
+            } => Some(AppwriteErrorKind::from(error_type.as_str())),
+            _ => None,
+        }
+    }
+
+    /// HTTP status of the failed response, if a response was received.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::AppWriteError { code, .. } => code.map(|code| code as u16),
+            Error::UnexpectedResponse { status, .. } => Some(*status),
+            Error::Network(err) => err.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// The resource doesn't exist (404, `*_not_found`).
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+            || self
+                .kind()
+                .is_some_and(|kind| kind.as_str().ends_with("_not_found"))
+    }
+
+    /// The resource already exists or was changed concurrently (409).
+    pub fn is_conflict(&self) -> bool {
+        self.status() == Some(409)
+            || self.kind().is_some_and(|kind| {
+                kind.as_str().ends_with("_already_exists")
+                    || kind == AppwriteErrorKind::DocumentUpdateConflict
+            })
+    }
+
+    /// Missing or invalid credentials, or a key/session lacking the scope (401).
+    pub fn is_unauthorized(&self) -> bool {
+        self.status() == Some(401)
+            || matches!(
+                self.kind(),
+                Some(
+                    AppwriteErrorKind::GeneralUnauthorizedScope
+                        | AppwriteErrorKind::UserUnauthorized
+                        | AppwriteErrorKind::UserJwtInvalid
+                        | AppwriteErrorKind::UserInvalidCredentials
+                )
+            )
+    }
+
+    /// Too many requests (429, `general_rate_limit_exceeded`).
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(429)
+            || self.kind() == Some(AppwriteErrorKind::GeneralRateLimitExceeded)
+    }
+
+    /// A transient failure that may succeed if sent again: rate limits,
+    /// server errors, connection failures and timeouts.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Network(err) if err.is_timeout() || err.is_connect() => true,
+            _ => {
+                self.is_rate_limited()
+                    || matches!(self.status(), Some(500 | 502 | 503 | 504))
+                    || self.kind() == Some(AppwriteErrorKind::GeneralServerError)
+            }
+        }
+    }
+}
+
+/// Declare [`AppwriteErrorKind`] with the Appwrite `type` string of every variant.
+macro_rules! appwrite_error_kinds {
+    ($( $variant:ident => $name:literal, )*) => {
+        /// Appwrite error `type`, see
+        /// [Error Types](https://appwrite.io/docs/advanced/platform/response-codes#errorTypes).
+        ///
+        /// Types this version doesn't know about are kept in [`Unknown`](Self::Unknown).
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum AppwriteErrorKind {
+            $( #[doc = concat!("`", $name, "`")] $variant, )*
+            Unknown(String),
+        }
+
+        impl AppwriteErrorKind {
+            /// The `type` string sent by Appwrite.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( Self::$variant => $name, )*
+                    Self::Unknown(name) => name,
+                }
+            }
+        }
+
+        impl From<&str> for AppwriteErrorKind {
+            fn from(name: &str) -> Self {
+                match name {
+                    $( $name => Self::$variant, )*
+                    name => Self::Unknown(name.to_string()),
+                }
+            }
+        }
+    };
+}
+
+appwrite_error_kinds! {
+    GeneralMock => "general_mock",
+    GeneralArgumentInvalid => "general_argument_invalid",
+    GeneralQueryLimitExceeded => "general_query_limit_exceeded",
+    GeneralQueryInvalid => "general_query_invalid",
+    GeneralCursorNotFound => "general_cursor_not_found",
+    GeneralServerError => "general_server_error",
+    GeneralProtocolUnsupported => "general_protocol_unsupported",
+    GeneralCodesDisabled => "general_codes_disabled",
+    GeneralUsageDisabled => "general_usage_disabled",
+    GeneralNotImplemented => "general_not_implemented",
+    GeneralInvalidEmail => "general_invalid_email",
+    GeneralInvalidPhone => "general_invalid_phone",
+    GeneralRouteNotFound => "general_route_not_found",
+    GeneralUnknown => "general_unknown",
+    GeneralUnauthorizedScope => "general_unauthorized_scope",
+    GeneralRateLimitExceeded => "general_rate_limit_exceeded",
+    GeneralSmtpDisabled => "general_smtp_disabled",
+    GeneralPhoneDisabled => "general_phone_disabled",
+    GeneralAccessForbidden => "general_access_forbidden",
+    GeneralUnknownOrigin => "general_unknown_origin",
+    GeneralServiceDisabled => "general_service_disabled",
+    GeneralBadRequest => "general_bad_request",
+    UserCountExceeded => "user_count_exceeded",
+    UserJwtInvalid => "user_jwt_invalid",
+    UserAlreadyExists => "user_already_exists",
+    UserBlocked => "user_blocked",
+    UserInvalidToken => "user_invalid_token",
+    UserPasswordResetRequired => "user_password_reset_required",
+    UserEmailNotWhitelisted => "user_email_not_whitelisted",
+    UserInvalidCode => "user_invalid_code",
+    UserIpNotWhitelisted => "user_ip_not_whitelisted",
+    UserInvalidCredentials => "user_invalid_credentials",
+    UserAnonymousConsoleProhibited => "user_anonymous_console_prohibited",
+    UserSessionAlreadyExists => "user_session_already_exists",
+    UserNotFound => "user_not_found",
+    UserPasswordRecentlyUsed => "user_password_recently_used",
+    UserPasswordPersonalData => "user_password_personal_data",
+    UserEmailAlreadyExists => "user_email_already_exists",
+    UserPasswordMismatch => "user_password_mismatch",
+    UserSessionNotFound => "user_session_not_found",
+    UserIdentityNotFound => "user_identity_not_found",
+    UserUnauthorized => "user_unauthorized",
+    UserAuthMethodUnsupported => "user_auth_method_unsupported",
+    UserPhoneAlreadyExists => "user_phone_already_exists",
+    UserPhoneNotFound => "user_phone_not_found",
+    UserMissingId => "user_missing_id",
+    UserMoreFactorsRequired => "user_more_factors_required",
+    UserInvalidChallenge => "user_invalid_challenge",
+    UserAuthenticatorNotFound => "user_authenticator_not_found",
+    UserAuthenticatorAlreadyVerified => "user_authenticator_already_verified",
+    UserRecoveryCodesAlreadyExists => "user_recovery_codes_already_exists",
+    UserRecoveryCodesNotFound => "user_recovery_codes_not_found",
+    UserChallengeRequired => "user_challenge_required",
+    UserOauth2BadRequest => "user_oauth2_bad_request",
+    UserOauth2Unauthorized => "user_oauth2_unauthorized",
+    UserOauth2ProviderError => "user_oauth2_provider_error",
+    UserEmailNotFound => "user_email_not_found",
+    UserTargetNotFound => "user_target_not_found",
+    UserTargetAlreadyExists => "user_target_already_exists",
+    UserApiKeyAndSessionSet => "user_api_key_and_session_set",
+    TeamNotFound => "team_not_found",
+    TeamInviteAlreadyExists => "team_invite_already_exists",
+    TeamInviteNotFound => "team_invite_not_found",
+    TeamInvalidSecret => "team_invalid_secret",
+    TeamMembershipMismatch => "team_membership_mismatch",
+    TeamInviteMismatch => "team_invite_mismatch",
+    TeamAlreadyExists => "team_already_exists",
+    MembershipNotFound => "membership_not_found",
+    MembershipAlreadyConfirmed => "membership_already_confirmed",
+    AvatarSetNotFound => "avatar_set_not_found",
+    AvatarNotFound => "avatar_not_found",
+    AvatarImageNotFound => "avatar_image_not_found",
+    AvatarRemoteUrlFailed => "avatar_remote_url_failed",
+    AvatarIconNotFound => "avatar_icon_not_found",
+    StorageFileAlreadyExists => "storage_file_already_exists",
+    StorageFileNotFound => "storage_file_not_found",
+    StorageDeviceNotFound => "storage_device_not_found",
+    StorageFileEmpty => "storage_file_empty",
+    StorageFileTypeUnsupported => "storage_file_type_unsupported",
+    StorageInvalidFileSize => "storage_invalid_file_size",
+    StorageInvalidFile => "storage_invalid_file",
+    StorageBucketAlreadyExists => "storage_bucket_already_exists",
+    StorageBucketNotFound => "storage_bucket_not_found",
+    StorageInvalidContentRange => "storage_invalid_content_range",
+    StorageInvalidRange => "storage_invalid_range",
+    StorageInvalidAppwriteId => "storage_invalid_appwrite_id",
+    StorageFileNotPublic => "storage_file_not_public",
+    FunctionNotFound => "function_not_found",
+    FunctionRuntimeUnsupported => "function_runtime_unsupported",
+    FunctionEntrypointMissing => "function_entrypoint_missing",
+    BuildNotFound => "build_not_found",
+    BuildNotReady => "build_not_ready",
+    BuildInProgress => "build_in_progress",
+    DeploymentNotFound => "deployment_not_found",
+    ExecutionNotFound => "execution_not_found",
+    DatabaseNotFound => "database_not_found",
+    DatabaseAlreadyExists => "database_already_exists",
+    DatabaseTimeout => "database_timeout",
+    CollectionNotFound => "collection_not_found",
+    CollectionAlreadyExists => "collection_already_exists",
+    CollectionLimitExceeded => "collection_limit_exceeded",
+    DocumentNotFound => "document_not_found",
+    DocumentInvalidStructure => "document_invalid_structure",
+    DocumentMissingData => "document_missing_data",
+    DocumentMissingPayload => "document_missing_payload",
+    DocumentAlreadyExists => "document_already_exists",
+    DocumentUpdateConflict => "document_update_conflict",
+    DocumentDeleteRestricted => "document_delete_restricted",
+    AttributeNotFound => "attribute_not_found",
+    AttributeUnknown => "attribute_unknown",
+    AttributeNotAvailable => "attribute_not_available",
+    AttributeFormatUnsupported => "attribute_format_unsupported",
+    AttributeDefaultUnsupported => "attribute_default_unsupported",
+    AttributeAlreadyExists => "attribute_already_exists",
+    AttributeLimitExceeded => "attribute_limit_exceeded",
+    AttributeValueInvalid => "attribute_value_invalid",
+    AttributeTypeInvalid => "attribute_type_invalid",
+    RelationshipValueInvalid => "relationship_value_invalid",
+    IndexNotFound => "index_not_found",
+    IndexLimitExceeded => "index_limit_exceeded",
+    IndexAlreadyExists => "index_already_exists",
+    IndexInvalid => "index_invalid",
+    ProjectNotFound => "project_not_found",
+    ProjectUnknown => "project_unknown",
+    ProjectKeyExpired => "project_key_expired",
+    ProviderNotFound => "provider_not_found",
+    ProviderAlreadyExists => "provider_already_exists",
+    ProviderIncorrectType => "provider_incorrect_type",
+    ProviderMissingCredentials => "provider_missing_credentials",
+    TopicNotFound => "topic_not_found",
+    TopicAlreadyExists => "topic_already_exists",
+    SubscriberNotFound => "subscriber_not_found",
+    SubscriberAlreadyExists => "subscriber_already_exists",
+    TargetNotFound => "target_not_found",
+    MessageNotFound => "message_not_found",
+    MessageMissingTarget => "message_missing_target",
+    MessageAlreadySent => "message_already_sent",
+    MessageAlreadyProcessing => "message_already_processing",
+    MessageAlreadyFailed => "message_already_failed",
+    MessageAlreadyScheduled => "message_already_scheduled",
+    MessageTargetNotEmail => "message_target_not_email",
+    MessageTargetNotSms => "message_target_not_sms",
+    MessageTargetNotPush => "message_target_not_push",
+}
+
+impl std::fmt::Display for AppwriteErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -57,3 +367,43 @@ impl std::fmt::Display for AppWriteError {
 }
 
 impl std::error::Error for AppWriteError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn appwrite_error(code: u64, error_type: &str) -> Error {
+        Error::AppWriteError {
+            message: String::new(),
+            code: Some(code),
+            response: None,
+            error_type: Some(error_type.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_error_classification() {
+        let err = appwrite_error(404, "document_not_found");
+        assert_eq!(err.kind(), Some(AppwriteErrorKind::DocumentNotFound));
+        assert!(err.is_not_found() && !err.is_conflict() && !err.is_retryable());
+
+        let err = appwrite_error(409, "user_already_exists");
+        assert!(err.is_conflict());
+
+        let err = appwrite_error(400, "some_future_type");
+        assert_eq!(
+            err.kind(),
+            Some(AppwriteErrorKind::Unknown("some_future_type".to_string()))
+        );
+        assert_eq!(err.kind().unwrap().as_str(), "some_future_type");
+
+        let err = appwrite_error(429, "general_rate_limit_exceeded");
+        assert!(err.is_rate_limited() && err.is_retryable());
+
+        let err = Error::UnexpectedResponse {
+            status: 502,
+            body: String::from("<html>Bad Gateway</html>"),
+        };
+        assert!(err.is_retryable() && err.kind().is_none());
+    }
+}
@@ -0,0 +1,24 @@
+//! # Account
+//!
+//! Instance-style handle on [`crate::services::server::account`].
+
+use serde_json::Value;
+
+use crate::{
+    client::Client,
+    enums::{
+        authentication_factor::AuthenticationFactor, authentication_type::AuthenticationType,
+        o_auth_provider::OAuthProvider,
+    },
+    error::Error,
+    handles::service_handle,
+    models::{
+        identity::Identity, identity_list::IdentityList, jwt::JWT, log::Log, log_list::LogList,
+        mfa_challenge::MfaChallenge, mfa_factors::MfaFactors, mfa_recovery_codes::MfaRecoveryCodes,
+        mfa_type::MfaType, preferences::Preferences, session::Session, session_list::SessionList,
+        token::Token, user::User,
+    },
+    query::Query,
+};
+
+crate::endpoints::account!(service_handle!(AccountHandle));
@@ -0,0 +1,7 @@
+//! # Avatars
+//!
+//! Instance-style handle on [`crate::services::server::avatar`].
+
+use crate::{client::Client, enums::flag::Flag, error::Error, handles::service_handle};
+
+crate::endpoints::avatar!(service_handle!(AvatarsHandle));
@@ -0,0 +1,366 @@
+//! # Databases
+//!
+//! Instance-style handle on [`crate::services::server::databases`].
+
+use futures_util::Stream;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    client::Client,
+    enums::{
+        index_type::IndexType, relation_mutate::RelationMutate, relationship_type::RelationshipType,
+    },
+    error::Error,
+    handles::service_handle,
+    models::{
+        attribute_boolean::AttributeBoolean, attribute_datetime::AttributeDateTime,
+        attribute_email::AttributeEmail, attribute_enum::AttributeEnum,
+        attribute_float::AttributeFloat, attribute_integer::AttributeInteger,
+        attribute_ip::AttributeIp, attribute_list::AttributeList,
+        attribute_relationship::AttributeRelationship, attribute_string::AttributeString,
+        attribute_url::AttributeUrl, collection::Collection, collection_list::CollectionList,
+        database::Database, database_list::DatabaseList, document::Document,
+        document_list::DocumentList, index::Index, index_list::IndexList,
+        typed_document::TypedDocument, typed_document_list::TypedDocumentList,
+    },
+    query::Query,
+    services::server::databases::Databases,
+    wait::WaitOptions,
+};
+
+crate::endpoints::databases!(service_handle!(DatabasesHandle));
+
+impl DatabasesHandle {
+    /// A handle scoped to database `database_id`.
+    pub fn database(&self, database_id: impl Into<String>) -> DatabaseHandle {
+        DatabaseHandle {
+            client: self.client().clone(),
+            database_id: database_id.into(),
+        }
+    }
+}
+
+/// Handle on one database, see [`DatabasesHandle::database`].
+#[derive(Debug, Clone)]
+pub struct DatabaseHandle {
+    client: Client,
+    database_id: String,
+}
+
+impl DatabaseHandle {
+    pub fn id(&self) -> &str {
+        &self.database_id
+    }
+
+    /// A handle scoped to collection `collection_id` of this database.
+    pub fn collection(&self, collection_id: impl Into<String>) -> CollectionHandle {
+        CollectionHandle {
+            client: self.client.clone(),
+            database_id: self.database_id.clone(),
+            collection_id: collection_id.into(),
+        }
+    }
+
+    /// See [`Databases::get`].
+    pub async fn get(&self) -> Result<Database, Error> {
+        Databases::get(&self.client, &self.database_id).await
+    }
+
+    /// See [`Databases::update`].
+    pub async fn update(&self, name: &str, enabled: Option<bool>) -> Result<Database, Error> {
+        Databases::update(&self.client, &self.database_id, name, enabled).await
+    }
+
+    /// See [`Databases::delete`].
+    pub async fn delete(&self) -> Result<(), Error> {
+        Databases::delete(&self.client, &self.database_id).await
+    }
+
+    /// See [`Databases::list_collections`].
+    pub async fn list_collections(
+        &self,
+        search: Option<String>,
+        queries: Option<Vec<Query>>,
+    ) -> Result<CollectionList, Error> {
+        Databases::list_collections(&self.client, &self.database_id, search, queries).await
+    }
+
+    /// See [`Databases::create_collection`].
+    pub async fn create_collection(
+        &self,
+        collection_id: &str,
+        name: &str,
+        permissions: Option<Vec<String>>,
+        document_security: Option<bool>,
+        enabled: Option<bool>,
+    ) -> Result<Collection, Error> {
+        Databases::create_collection(
+            &self.client,
+            &self.database_id,
+            collection_id,
+            name,
+            permissions,
+            document_security,
+            enabled,
+        )
+        .await
+    }
+}
+
+/// Handle on the documents of one collection, see
+/// [`DatabaseHandle::collection`].
+#[derive(Debug, Clone)]
+pub struct CollectionHandle {
+    client: Client,
+    database_id: String,
+    collection_id: String,
+}
+
+impl CollectionHandle {
+    pub fn id(&self) -> &str {
+        &self.collection_id
+    }
+
+    pub fn database_id(&self) -> &str {
+        &self.database_id
+    }
+
+    /// The collection itself. See [`Databases::get_collection`].
+    pub async fn info(&self) -> Result<Collection, Error> {
+        Databases::get_collection(&self.client, &self.database_id, &self.collection_id).await
+    }
+
+    /// See [`Databases::list_attributes`].
+    pub async fn list_attributes(
+        &self,
+        queries: Option<Vec<Query>>,
+    ) -> Result<AttributeList, Error> {
+        Databases::list_attributes(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            queries,
+        )
+        .await
+    }
+
+    /// See [`Databases::list_indexes`].
+    pub async fn list_indexes(&self, queries: Option<Vec<Query>>) -> Result<IndexList, Error> {
+        Databases::list_indexes(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            queries,
+        )
+        .await
+    }
+
+    /// List documents. See [`Databases::list_documents`].
+    pub async fn list(&self, queries: Option<Vec<Query>>) -> Result<DocumentList, Error> {
+        Databases::list_documents(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            queries,
+        )
+        .await
+    }
+
+    /// Stream every document. See [`Databases::list_documents_stream`].
+    pub fn list_stream(
+        &self,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Document, Error>> + '_ {
+        Databases::list_documents_stream(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            queries,
+            page_size,
+        )
+    }
+
+    /// Stream every document as `T`. See
+    /// [`Databases::list_documents_as_stream`].
+    pub fn list_as_stream<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<TypedDocument<T>, Error>> + 'a {
+        Databases::list_documents_as_stream(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            queries,
+            page_size,
+        )
+    }
+
+    /// Get a document. See [`Databases::get_document`].
+    pub async fn get(
+        &self,
+        document_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<Document, Error> {
+        Databases::get_document(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            document_id,
+            queries,
+        )
+        .await
+    }
+
+    /// Get a document as `T`. See [`Databases::get_document_as`].
+    pub async fn get_as<T: DeserializeOwned>(
+        &self,
+        document_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<TypedDocument<T>, Error> {
+        Databases::get_document_as(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            document_id,
+            queries,
+        )
+        .await
+    }
+
+    /// List documents as `T`. See [`Databases::list_documents_as`].
+    pub async fn list_as<T: DeserializeOwned>(
+        &self,
+        queries: Option<Vec<Query>>,
+    ) -> Result<TypedDocumentList<T>, Error> {
+        Databases::list_documents_as(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            queries,
+        )
+        .await
+    }
+
+    /// Create a document from `T`. See [`Databases::create_document_as`].
+    pub async fn create_as<T: Serialize + DeserializeOwned>(
+        &self,
+        document_id: &str,
+        data: &T,
+        permissions: Option<Vec<String>>,
+    ) -> Result<TypedDocument<T>, Error> {
+        Databases::create_document_as(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            document_id,
+            data,
+            permissions,
+        )
+        .await
+    }
+
+    /// Update a document as `T`. See [`Databases::update_document_as`].
+    pub async fn update_as<T: DeserializeOwned>(
+        &self,
+        document_id: &str,
+        data: &impl Serialize,
+        permissions: Option<Vec<String>>,
+    ) -> Result<TypedDocument<T>, Error> {
+        Databases::update_document_as(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            document_id,
+            data,
+            permissions,
+        )
+        .await
+    }
+
+    /// Create a document. See [`Databases::create_documents`].
+    pub async fn create(
+        &self,
+        document_id: &str,
+        data: Map<String, Value>,
+        permissions: Option<Vec<String>>,
+    ) -> Result<Document, Error> {
+        Databases::create_documents(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            document_id,
+            data,
+            permissions,
+        )
+        .await
+    }
+
+    /// Update a document. See [`Databases::update_document`].
+    pub async fn update(
+        &self,
+        document_id: &str,
+        data: Option<Map<String, Value>>,
+        permissions: Option<Vec<String>>,
+    ) -> Result<Document, Error> {
+        Databases::update_document(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            document_id,
+            data,
+            permissions,
+        )
+        .await
+    }
+
+    /// Delete a document. See [`Databases::delete_document`].
+    pub async fn delete(&self, document_id: &str) -> Result<(), Error> {
+        Databases::delete_document(
+            &self.client,
+            &self.database_id,
+            &self.collection_id,
+            document_id,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+    use serde_json::json;
+
+    use crate::{client::ClientBuilder, transport::CannedTransport};
+
+    #[tokio::test]
+    async fn test_collection_handle_paths() {
+        let transport = CannedTransport::new().json(
+            "/databases/blog/collections/posts/documents",
+            json!({"total": 0, "documents": []}),
+        );
+        let client = ClientBuilder::default()
+            .set_transport(transport.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        let posts = client.databases().database("blog").collection("posts");
+        assert_eq!(posts.list(None).await.unwrap().total, 0);
+        assert_eq!(
+            transport.paths(),
+            ["/v1/databases/blog/collections/posts/documents"]
+        );
+
+        let documents = client
+            .databases()
+            .list_documents_stream("blog", "posts", None, None)
+            .count()
+            .await;
+        assert_eq!(documents, 0);
+        assert!(transport.requests()[1].url.starts_with(
+            "https://cloud.appwrite.io/v1/databases/blog/collections/posts/documents?"
+        ));
+    }
+}
@@ -0,0 +1,48 @@
+//! # Functions
+//!
+//! Instance-style handle on [`crate::services::server::functions`].
+
+use futures_util::Stream;
+use serde_json::{Map, Value};
+
+use crate::{
+    client::Client,
+    enums::{execution_method::ExecutionMethod, runtime::Runtime},
+    error::Error,
+    handles::service_handle,
+    models::UploadType,
+    models::{
+        deployment::Deployment, deployment_list::DeploymentList, execution::Execution,
+        execution_list::ExecutionList, function::Func, function_list::FunctionList,
+        runtime_list::RuntimeList, variable::Variable, variable_list::VariableList,
+    },
+    query::Query,
+    upload_progress::UploadProgress,
+};
+
+crate::endpoints::functions!(service_handle!(FunctionsHandle));
+
+impl FunctionsHandle {
+    /// See [`crate::services::server::functions::Functions::create_deployments_streamed`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_deployments_streamed<'a>(
+        &'a self,
+        function_id: &'a str,
+        file_path: &'a str,
+        file_name: String,
+        activate: bool,
+        entrypoint: Option<&'a str>,
+        commands: Option<&'a str>,
+    ) -> impl Stream<Item = Result<(UploadType, UploadProgress), Error>> + 'a {
+        crate::services::server::functions::Functions::create_deployments_streamed(
+            &self.client,
+            function_id,
+            file_path,
+            file_name,
+            activate,
+            entrypoint,
+            commands,
+        )
+        .await
+    }
+}
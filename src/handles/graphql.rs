@@ -0,0 +1,9 @@
+//! # Graphql
+//!
+//! Instance-style handle on [`crate::services::server::graphql`].
+
+use serde_json::{Map, Value};
+
+use crate::{client::Client, error::Error, handles::service_handle};
+
+crate::endpoints::graphql!(service_handle!(GraphqlHandle));
@@ -0,0 +1,16 @@
+//! # Health
+//!
+//! Instance-style handle on [`crate::services::server::health`].
+
+use crate::{
+    client::Client,
+    enums::name::Name,
+    error::Error,
+    handles::service_handle,
+    models::{
+        health_antivirus::HealthAntivirus, health_certificate::HealthCertificate,
+        health_queue::HealthQueue, health_status::HealthStatus, health_time::HealthTime,
+    },
+};
+
+crate::endpoints::health!(service_handle!(HealthHandle));
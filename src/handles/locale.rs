@@ -0,0 +1,16 @@
+//! # Locale
+//!
+//! Instance-style handle on [`crate::services::server::locale`].
+
+use crate::{
+    client::Client,
+    error::Error,
+    handles::service_handle,
+    models::{
+        continent_list::ContinentList, country_list::CountryList, currency_list::CurrencyList,
+        language_list::LanguageList, locale::Locale as MLocale, locale_code::LocaleCode,
+        phone_list::PhoneList,
+    },
+};
+
+crate::endpoints::locale!(service_handle!(LocaleHandle));
@@ -0,0 +1,21 @@
+//! # Messaging
+//!
+//! Instance-style handle on [`crate::services::server::messaging`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    client::Client,
+    enums::smtp_encryption::SmtpEncryption,
+    error::Error,
+    handles::service_handle,
+    models::{
+        log::Log, log_list::LogList, message::Message, message_list::MessageList,
+        provider::Provider, provider_list::ProviderList, subscriber::Subscriber,
+        subscriber_list::SubscriberList, target::Target, target_list::TargetList, topic::Topic,
+        topic_list::TopicList,
+    },
+    query::Query,
+};
+
+crate::endpoints::messaging!(service_handle!(MessagingHandle));
@@ -0,0 +1,142 @@
+//! # Handles
+//!
+//! Instance-style access to the services in [`crate::services::server`].
+//!
+//! A handle owns a cheap clone of a [`Client`] and exposes the same endpoints
+//! as methods, which makes it easy to pass around or put behind a trait for
+//! mocking. The static API stays available.
+//!
+//! ```no_run
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//!
+//! # async fn run() -> Result<(), Error> {
+//! let client = ClientBuilder::default()
+//!     .set_project("5ff3...")?
+//!     .set_key("cd868c7af8bdc893b4...93b7535db89")?
+//!     .build()?;
+//!
+//! let users = client.users().list(None, None).await?;
+//!
+//! let posts = client.databases().database("blog").collection("posts");
+//! let documents = posts.list(None).await?;
+//! let post = posts.get("6618ef06d269bf4110d4", None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::Client;
+
+pub mod account;
+pub mod avatar;
+pub mod databases;
+pub mod functions;
+pub mod graphql;
+pub mod health;
+pub mod locale;
+pub mod messaging;
+pub mod storage;
+pub mod teams;
+pub mod users;
+
+/// Declare a handle type whose methods forward to the static service
+/// functions of the same name.
+macro_rules! service_handle {
+    (
+        $handle:ident => $service:ty {
+            $(
+                fn $name:ident $( < $gen:ident : $bound:ident $( + $bounds:ident )* > )?
+                    ( $( $arg:ident : $ty:ty ),* $(,)? ) -> $ret:ty;
+            )*
+            $(
+                stream fn $stream:ident < $lt:lifetime $( , $sgen:ident : $sbound:ident $( + $sbounds:ident )* )? >
+                    ( $( $sarg:ident : $sty:ty ),* $(,)? ) -> $item:ty;
+            )*
+        }
+    ) => {
+        #[doc = concat!("Handle on [`", stringify!($service), "`].")]
+        #[derive(Debug, Clone)]
+        pub struct $handle {
+            client: Client,
+        }
+
+        impl $handle {
+            pub fn new(client: Client) -> Self {
+                Self { client }
+            }
+
+            /// The client this handle sends requests with.
+            pub fn client(&self) -> &Client {
+                &self.client
+            }
+
+            $(
+                #[allow(clippy::too_many_arguments)]
+                #[doc = concat!("See [`", stringify!($service), "::", stringify!($name), "`].")]
+                pub async fn $name $( < $gen: $bound $( + $bounds )* > )? (
+                    &self,
+                    $( $arg: $ty ),*
+                ) -> Result<$ret, Error> {
+                    <$service>::$name(&self.client, $( $arg ),*).await
+                }
+            )*
+
+            $(
+                #[doc = concat!("See [`", stringify!($service), "::", stringify!($stream), "`].")]
+                pub fn $stream<$lt $( , $sgen: $sbound $( + $sbounds )* + $lt )?>(
+                    &$lt self,
+                    $( $sarg: $sty ),*
+                ) -> impl futures_util::Stream<Item = Result<$item, Error>> + $lt {
+                    <$service>::$stream(&self.client, $( $sarg ),*)
+                }
+            )*
+        }
+    };
+}
+pub(crate) use service_handle;
+
+impl Client {
+    pub fn account(&self) -> account::AccountHandle {
+        account::AccountHandle::new(self.clone())
+    }
+
+    pub fn avatars(&self) -> avatar::AvatarsHandle {
+        avatar::AvatarsHandle::new(self.clone())
+    }
+
+    pub fn databases(&self) -> databases::DatabasesHandle {
+        databases::DatabasesHandle::new(self.clone())
+    }
+
+    pub fn functions(&self) -> functions::FunctionsHandle {
+        functions::FunctionsHandle::new(self.clone())
+    }
+
+    pub fn graphql(&self) -> graphql::GraphqlHandle {
+        graphql::GraphqlHandle::new(self.clone())
+    }
+
+    pub fn health(&self) -> health::HealthHandle {
+        health::HealthHandle::new(self.clone())
+    }
+
+    pub fn locale(&self) -> locale::LocaleHandle {
+        locale::LocaleHandle::new(self.clone())
+    }
+
+    pub fn messaging(&self) -> messaging::MessagingHandle {
+        messaging::MessagingHandle::new(self.clone())
+    }
+
+    pub fn storage(&self) -> storage::StorageHandle {
+        storage::StorageHandle::new(self.clone())
+    }
+
+    pub fn teams(&self) -> teams::TeamsHandle {
+        teams::TeamsHandle::new(self.clone())
+    }
+
+    pub fn users(&self) -> users::UsersHandle {
+        users::UsersHandle::new(self.clone())
+    }
+}
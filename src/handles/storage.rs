@@ -0,0 +1,40 @@
+//! # Storage
+//!
+//! Instance-style handle on [`crate::services::server::storage`].
+
+use futures_util::Stream;
+
+use crate::{
+    client::Client,
+    enums::{compression::Compression, image_format::ImageFormat, image_gravity::ImageGravity},
+    error::Error,
+    handles::service_handle,
+    models::UploadType,
+    models::{bucket::Bucket, bucket_list::BucketList, file::File, file_list::FileList},
+    query::Query,
+    upload_progress::UploadProgress,
+};
+
+crate::endpoints::storage!(service_handle!(StorageHandle));
+
+impl StorageHandle {
+    /// See [`crate::services::server::storage::Storage::create_files_streamed`].
+    pub async fn create_files_streamed<'a>(
+        &'a self,
+        bucket_id: &'a str,
+        file_id: &'a str,
+        file_path: &'a str,
+        file_name: String,
+        permissions: Option<Vec<String>>,
+    ) -> impl Stream<Item = Result<(UploadType, UploadProgress), Error>> + 'a {
+        crate::services::server::storage::Storage::create_files_streamed(
+            &self.client,
+            bucket_id,
+            file_id,
+            file_path,
+            file_name,
+            permissions,
+        )
+        .await
+    }
+}
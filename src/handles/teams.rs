@@ -0,0 +1,18 @@
+//! # Teams
+//!
+//! Instance-style handle on [`crate::services::server::teams`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    client::Client,
+    error::Error,
+    handles::service_handle,
+    models::{
+        membership::Membership, membership_list::MembershipList, preferences::Preferences,
+        team::Team, team_list::TeamList,
+    },
+    query::Query,
+};
+
+crate::endpoints::teams!(service_handle!(TeamsHandle));
@@ -139,7 +139,7 @@
 //!     let col_list = Databases::list_collections(
 //!         &client,
 //!         "6618eec286a4ef198076",
-//!         Some(Query::equal(r"$id", json!(vec!["6619010ddab914e6b01e"]))),
+//!         None,
 //!         Some(queries),
 //!     )
 //!     .await?;
@@ -279,16 +279,38 @@
 //!
 //! NOTE 🎶: for other examples. check out the official docs or sdk of official sdk as a guide to using this sdk.
 
+// Lets `#[derive(AppwriteCollection)]` output resolve inside this crate's tests.
+#[cfg(test)]
+extern crate self as unofficial_appwrite;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "cassette")]
+pub mod cassette;
 pub mod client;
+pub mod config;
+pub mod cookie;
+mod endpoints;
 pub mod enumm;
 pub mod enums;
 pub mod error;
+pub mod handles;
 pub mod id;
+pub mod middleware;
 pub mod models;
+pub mod pagination;
+pub mod params;
 pub mod permission;
 pub mod query;
 pub mod realtime;
+pub mod retry;
 pub mod role;
+pub mod schema;
+#[cfg(feature = "tracing")]
+mod routes;
 pub mod services;
+pub mod transport;
 pub mod upload_progress;
 pub mod utils;
+pub mod version;
+pub mod wait;
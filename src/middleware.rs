@@ -0,0 +1,192 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+use crate::{
+    enumm::HttpMethod,
+    transport::{HttpResponse, MultipartForm},
+};
+
+/// Hook into every request a [`Client`](crate::client::Client) sends.
+///
+/// Middlewares run in the order they were registered with
+/// [`ClientBuilder::add_middleware`](crate::client::ClientBuilder::add_middleware),
+/// once per attempt, for every service call and for every chunk of a chunked
+/// upload. A middleware can answer a request itself with
+/// [`respond`](Self::respond); the middlewares after it and the transport are
+/// then skipped.
+///
+/// ```
+/// use unofficial_appwrite::middleware::{Middleware, RequestParts, ResponseParts};
+///
+/// struct CorrelationId;
+///
+/// impl Middleware for CorrelationId {
+///     fn on_request(&self, request: &mut RequestParts) {
+///         request
+///             .headers
+///             .insert("x-correlation-id", "7f3c...".parse().unwrap());
+///     }
+///
+///     fn on_response(&self, request: &RequestParts, response: &ResponseParts) {
+///         println!("{:?} {} -> {:?} in {:?}", request.method, request.path, response.status, response.elapsed);
+///     }
+/// }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent. Any change made to `request` is
+    /// what goes on the wire.
+    fn on_request(&self, _request: &mut RequestParts) {}
+
+    /// Answer the request without sending it, e.g. from a cache. Called right
+    /// after this middleware's [`on_request`](Self::on_request).
+    fn respond(&self, _request: &RequestParts) -> Option<HttpResponse> {
+        None
+    }
+
+    /// Called once the response arrived, or the request failed, for every
+    /// middleware whose `on_request` ran.
+    fn on_response(&self, _request: &RequestParts, _response: &ResponseParts) {}
+}
+
+/// Outgoing request as seen by a [`Middleware`].
+#[derive(Debug, Clone)]
+pub struct RequestParts {
+    pub method: HttpMethod,
+    /// Base URL of the Appwrite API, e.g. `https://cloud.appwrite.io/v1`.
+    pub endpoint: String,
+    /// API path, e.g. `/databases/6618.../collections`.
+    pub path: String,
+    /// Request headers, including the client's default headers.
+    pub headers: HeaderMap,
+    /// Request parameters. Sent as the query string for `GET` requests, as
+    /// fields of `form` when there is one and as the JSON body otherwise.
+    pub params: Value,
+    /// The multipart form of a file upload.
+    pub form: Option<MultipartForm>,
+}
+
+/// Outcome of a request as seen by a [`Middleware`].
+#[derive(Debug, Clone)]
+pub struct ResponseParts {
+    /// HTTP status, `None` when no response was received.
+    pub status: Option<u16>,
+    /// Response headers, empty when no response was received.
+    pub headers: HeaderMap,
+    /// Time between sending the request and receiving the whole response
+    /// body, or until a middleware responded.
+    pub elapsed: Duration,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareStack(pub(crate) Vec<Arc<dyn Middleware>>);
+
+impl fmt::Debug for MiddlewareStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MiddlewareStack({} middlewares)", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        client::ClientBuilder, services::server::users::Users, transport::CannedTransport,
+    };
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    fn users(status: StatusCode) -> HttpResponse {
+        HttpResponse::from_json(status, &json!({"total": 0, "users": []}))
+    }
+
+    struct Tag {
+        name: &'static str,
+        log: Log,
+        respond: bool,
+    }
+
+    impl Middleware for Tag {
+        fn on_request(&self, request: &mut RequestParts) {
+            let tag = match request.headers.get("x-tag") {
+                Some(tag) => format!("{}{}", tag.to_str().unwrap(), self.name),
+                None => self.name.to_string(),
+            };
+            request.headers.insert("x-tag", tag.parse().unwrap());
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("request {}", self.name));
+        }
+
+        fn respond(&self, _request: &RequestParts) -> Option<HttpResponse> {
+            self.respond.then(|| users(StatusCode::ACCEPTED))
+        }
+
+        fn on_response(&self, _request: &RequestParts, response: &ResponseParts) {
+            self.log.lock().unwrap().push(format!(
+                "response {} {}",
+                self.name,
+                response.status.unwrap()
+            ));
+        }
+    }
+
+    fn client(log: &Log, transport: &CannedTransport, respond: [bool; 3]) -> crate::client::Client {
+        let mut builder = ClientBuilder::default();
+        builder.set_transport(transport.clone()).unwrap();
+        for (name, respond) in ["a", "b", "c"].into_iter().zip(respond) {
+            builder
+                .add_middleware(Tag {
+                    name,
+                    log: log.clone(),
+                    respond,
+                })
+                .unwrap();
+        }
+        builder.build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_middleware_order() {
+        let (log, transport) = (
+            Log::default(),
+            CannedTransport::new().respond("/users", users(StatusCode::OK)),
+        );
+        Users::list(&client(&log, &transport, [false; 3]), None, None)
+            .await
+            .unwrap();
+        assert_eq!(transport.requests()[0].headers["x-tag"], "abc");
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "request a",
+                "request b",
+                "request c",
+                "response a 200",
+                "response b 200",
+                "response c 200",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_middleware_short_circuit() {
+        let (log, transport) = (Log::default(), CannedTransport::new());
+        let users = Users::list(&client(&log, &transport, [false, true, false]), None, None)
+            .await
+            .unwrap();
+        assert_eq!(users.total, 0);
+        assert!(transport.requests().is_empty());
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["request a", "request b", "response a 202", "response b 202"]
+        );
+    }
+}
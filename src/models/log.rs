@@ -25,7 +25,6 @@ pub struct Log {
     pub ip: String,
 
     /// Log creation date in ISO 8601 format.
-    #[serde(rename = "teamName")]
     pub time: String,
 
     /// Operating system code name. View list of [available options](https://github.com/appwrite/appwrite/blob/master/docs/lists/os.json).
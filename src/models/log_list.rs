@@ -8,5 +8,5 @@ pub struct LogList {
     /// Total number of logs documents that matched your query.
     pub total: u64,
     /// List of logs.
-    pub sessions: Vec<Log>,
+    pub logs: Vec<Log>,
 }
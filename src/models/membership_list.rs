@@ -8,5 +8,5 @@ pub struct MembershipList {
     /// Total number of memberships documents that matched your query.
     pub total: u64,
     /// List of memberships.
-    pub sessions: Vec<Membership>,
+    pub memberships: Vec<Membership>,
 }
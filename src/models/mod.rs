@@ -84,6 +84,8 @@ pub mod team_list;
 pub mod token;
 pub mod topic;
 pub mod topic_list;
+pub mod typed_document;
+pub mod typed_document_list;
 pub mod user;
 pub mod user_list;
 pub mod variable;
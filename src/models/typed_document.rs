@@ -0,0 +1,108 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::document::Document;
+use crate::error::Error;
+
+/// Document whose user fields deserialize into `T`, next to the document's
+/// metadata.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct TypedDocument<T> {
+    /// Document ID.
+    #[serde(rename = "$id")]
+    pub id: String,
+
+    /// Collection ID.
+    #[serde(rename = "$collectionId")]
+    pub collection_id: String,
+
+    /// Database ID.
+    #[serde(rename = "$databaseId")]
+    pub database_id: String,
+
+    /// Document creation date in ISO 8601 format.
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+
+    /// Document update date in ISO 8601 format.
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+
+    /// Document permissions. [Learn more about permissions](https://appwrite.io/docs/permissions).
+    #[serde(rename = "$permissions")]
+    pub permissions: Vec<String>,
+
+    /// The user fields.
+    #[serde(flatten)]
+    pub data: T,
+}
+
+impl<T> TypedDocument<T> {
+    /// Drop the metadata and keep the user fields.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T> Deref for TypedDocument<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data
+    }
+}
+
+impl<T> DerefMut for TypedDocument<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+}
+
+impl Document {
+    /// Deserialize the user fields into `T`.
+    pub fn into_typed<T: DeserializeOwned>(self) -> Result<TypedDocument<T>, Error> {
+        Ok(TypedDocument {
+            data: serde_json::from_value(self.data.into())?,
+            id: self.id,
+            collection_id: self.collection_id,
+            database_id: self.database_id,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            permissions: self.permissions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Post {
+        title: String,
+        views: u32,
+    }
+
+    #[test]
+    fn test_typed_document() {
+        let fields = json!({
+            "$id": "6618ef06d269bf4110d4",
+            "$collectionId": "posts",
+            "$databaseId": "blog",
+            "$createdAt": "2024-04-12T08:00:00.000+00:00",
+            "$updatedAt": "2024-04-12T08:00:00.000+00:00",
+            "$permissions": ["read(\"any\")"],
+            "title": "Hello",
+            "views": 3,
+        });
+        let post: TypedDocument<Post> = serde_json::from_value(fields.clone()).unwrap();
+        assert_eq!(post.id, "6618ef06d269bf4110d4");
+        assert_eq!(post.title, "Hello");
+
+        let document: Document = serde_json::from_value(fields).unwrap();
+        assert_eq!(document.into_typed::<Post>().unwrap(), post);
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::typed_document::TypedDocument;
+
+/// Documents List, with user fields deserialized into `T`
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct TypedDocumentList<T> {
+    /// Total number of document documents that matched your query.
+    pub total: u64,
+    /// List of document.
+    pub documents: Vec<TypedDocument<T>>,
+}
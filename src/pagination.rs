@@ -0,0 +1,375 @@
+//! # Pagination
+//!
+//! The `*_stream` variants of the list endpoints walk every page for you,
+//! following `cursorAfter` from the last item of each page. Attributes,
+//! indexes and logs have no ID to follow, so their pages are fetched by
+//! `offset` instead. Filters and ordering in the caller's queries are sent
+//! with every page; a `limit` caps the number of items streamed, an `offset`
+//! skips items before the first page and a `cursorAfter` is where streaming
+//! starts.
+//!
+//! ```no_run
+//! use futures_util::{pin_mut, StreamExt};
+//! use serde_json::json;
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//! use unofficial_appwrite::query::Query;
+//! use unofficial_appwrite::services::server::databases::Databases;
+//!
+//! # async fn run() -> Result<(), Error> {
+//! let client = ClientBuilder::default().set_project("5ff3...")?.build()?;
+//!
+//! let documents = Databases::list_documents_stream(
+//!     &client,
+//!     "blog",
+//!     "posts",
+//!     Some(vec![
+//!         Query::equal("status", json!("published")),
+//!         Query::order_desc("$createdAt"),
+//!     ]),
+//!     Some(500),
+//! );
+//! pin_mut!(documents);
+//! while let Some(document) = documents.next().await {
+//!     println!("{}", document?.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use async_fn_stream::try_fn_stream;
+use futures_util::Stream;
+use serde_json::Value;
+
+use crate::{
+    error::Error,
+    models::{
+        attribute_list::AttributeList, bucket::Bucket, bucket_list::BucketList,
+        collection::Collection, collection_list::CollectionList, database::Database,
+        database_list::DatabaseList, deployment::Deployment, deployment_list::DeploymentList,
+        document::Document, document_list::DocumentList, execution::Execution,
+        execution_list::ExecutionList, file::File, file_list::FileList, function::Func,
+        function_list::FunctionList, identity::Identity, identity_list::IdentityList, index::Index,
+        index_list::IndexList, log::Log, log_list::LogList, membership::Membership,
+        membership_list::MembershipList, message::Message, message_list::MessageList,
+        provider::Provider, provider_list::ProviderList, subscriber::Subscriber,
+        subscriber_list::SubscriberList, target::Target, target_list::TargetList, team::Team,
+        team_list::TeamList, topic::Topic, topic_list::TopicList, user::User, user_list::UserList,
+    },
+    query::Query,
+};
+
+/// Items fetched per request when no page size is given.
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// One page of a list endpoint.
+pub trait Page {
+    type Item;
+
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// The ID to continue after with `cursorAfter`, or `None` for items
+    /// without one, which are paged with `offset` instead.
+    fn cursor(item: &Self::Item) -> Option<&str>;
+}
+
+macro_rules! impl_page {
+    ($( $list:ty => $items:ident: $item:ty ),* $(,)?) => {
+        $(
+            impl Page for $list {
+                type Item = $item;
+
+                fn into_items(self) -> Vec<Self::Item> {
+                    self.$items
+                }
+
+                fn cursor(item: &Self::Item) -> Option<&str> {
+                    Some(&item.id)
+                }
+            }
+        )*
+    };
+}
+
+impl_page! {
+    BucketList => buckets: Bucket,
+    CollectionList => collections: Collection,
+    DatabaseList => databases: Database,
+    DeploymentList => deployments: Deployment,
+    DocumentList => documents: Document,
+    ExecutionList => executions: Execution,
+    FileList => files: File,
+    FunctionList => functions: Func,
+    IdentityList => identities: Identity,
+    MembershipList => memberships: Membership,
+    MessageList => messages: Message,
+    ProviderList => providers: Provider,
+    SubscriberList => subscribers: Subscriber,
+    TargetList => targets: Target,
+    TeamList => teams: Team,
+    TopicList => topics: Topic,
+    UserList => users: User,
+}
+
+/// Attributes, indexes and logs have no ID to page after.
+macro_rules! impl_offset_page {
+    ($( $list:ty => $items:ident: $item:ty ),* $(,)?) => {
+        $(
+            impl Page for $list {
+                type Item = $item;
+
+                fn into_items(self) -> Vec<Self::Item> {
+                    self.$items
+                }
+
+                fn cursor(_: &Self::Item) -> Option<&str> {
+                    None
+                }
+            }
+        )*
+    };
+}
+
+impl_offset_page! {
+    AttributeList => attributes: Value,
+    IndexList => indexes: Index,
+    LogList => logs: Log,
+}
+
+/// Stream the items of every page `fetch` returns for `queries`, `page_size`
+/// items (default [`DEFAULT_PAGE_SIZE`]) per request.
+pub(crate) fn paginate<'a, P, F, Fut>(
+    queries: Option<Vec<Query>>,
+    page_size: Option<usize>,
+    fetch: F,
+) -> impl Stream<Item = Result<P::Item, Error>> + 'a
+where
+    P: Page + 'a,
+    F: Fn(Vec<Query>) -> Fut + 'a,
+    Fut: Future<Output = Result<P, Error>> + 'a,
+{
+    try_fn_stream(move |emitter| async move {
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        if page_size == 0 {
+            return Err(Error::InvalidParameter {
+                param: "page_size",
+                reason: String::from("must be positive"),
+            });
+        }
+        let (mut remaining, mut offset, mut cursor) = (None, None, None);
+        let mut filters = Vec::new();
+        for query in queries.into_iter().flatten() {
+            match query {
+                Query::Limit(limit) => remaining = remaining.or(Some(limit)),
+                Query::Offset(skip) => offset = offset.or(Some(skip)),
+                Query::CursorAfter(id) => cursor = cursor.or(Some(id)),
+                Query::CursorBefore(_) => {
+                    return Err(Error::InvalidParameter {
+                        param: "queries",
+                        reason: String::from("streams page forward, use `cursorAfter`"),
+                    })
+                }
+                query => filters.push(query),
+            }
+        }
+        // Items already paged past, from `cursor` or the start.
+        let mut skipped = offset.unwrap_or_default();
+        loop {
+            let limit = remaining.map_or(page_size, |remaining: usize| remaining.min(page_size));
+            if limit == 0 {
+                return Ok(());
+            }
+            let mut queries = filters.clone();
+            queries.push(Query::limit(limit));
+            if let Some(offset) = offset.take() {
+                queries.push(Query::offset(offset));
+            }
+            if let Some(cursor) = &cursor {
+                queries.push(Query::cursor_after(cursor));
+            }
+            let items = fetch(queries).await?.into_items();
+            let count = items.len();
+            skipped += count;
+            match items.last().map(P::cursor) {
+                Some(Some(id)) => {
+                    cursor = Some(id.to_string());
+                    skipped = 0;
+                }
+                Some(None) => offset = Some(skipped),
+                None => {}
+            }
+            for item in items {
+                emitter.emit(item).await;
+            }
+            if let Some(remaining) = remaining.as_mut() {
+                *remaining -= count.min(*remaining);
+            }
+            if count < limit {
+                return Ok(());
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures_util::StreamExt;
+    use serde_json::json;
+
+    use super::*;
+    use crate::query::eval::evaluate;
+
+    #[tokio::test]
+    async fn test_paginate_follows_cursor() {
+        let documents = (1..=7)
+            .map(|n| {
+                serde_json::from_value::<Document>(json!({
+                    "$id": n.to_string(),
+                    "$collectionId": "posts",
+                    "$databaseId": "blog",
+                    "$createdAt": "",
+                    "$updatedAt": "",
+                    "$permissions": [],
+                    "even": n % 2 == 0,
+                }))
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let requests = Mutex::new(Vec::new());
+        let fetch = |queries: Vec<Query>| {
+            requests.lock().unwrap().push(queries.clone());
+            let page = evaluate(&queries, documents.clone()).map(DocumentList::from);
+            async move { page }
+        };
+
+        let ids = paginate(
+            Some(vec![Query::equal("even", json!(false)), Query::limit(3)]),
+            Some(2),
+            fetch,
+        )
+        .map(|document| document.unwrap().id)
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(ids, ["1", "3", "5"]);
+        assert_eq!(
+            requests.lock().unwrap().clone(),
+            [
+                vec![Query::equal("even", json!(false)), Query::limit(2)],
+                vec![
+                    Query::equal("even", json!(false)),
+                    Query::limit(1),
+                    Query::cursor_after("3"),
+                ],
+            ]
+        );
+
+        let ids = paginate(None, Some(3), |queries: Vec<Query>| {
+            let page = evaluate(&queries, documents.clone()).map(DocumentList::from);
+            async move { page }
+        })
+        .map(|document| document.unwrap().id)
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(ids.len(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_by_offset() {
+        let indexes = (1..=5)
+            .map(|n| Index {
+                key: format!("index_{n}"),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let requests = Mutex::new(Vec::new());
+        let fetch = |queries: Vec<Query>| {
+            requests.lock().unwrap().push(queries.clone());
+            let (mut limit, mut offset) = (0, 0);
+            for query in queries {
+                match query {
+                    Query::Limit(n) => limit = n,
+                    Query::Offset(n) => offset = n,
+                    _ => {}
+                }
+            }
+            let indexes = indexes.iter().skip(offset).take(limit).cloned().collect();
+            async move { Ok(IndexList { total: 5, indexes }) }
+        };
+
+        let keys = paginate(Some(vec![Query::offset(1)]), Some(2), fetch)
+            .map(|index: Result<Index, Error>| index.unwrap().key)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(keys, ["index_2", "index_3", "index_4", "index_5"]);
+        assert_eq!(
+            requests.lock().unwrap().clone(),
+            [
+                vec![Query::limit(2), Query::offset(1)],
+                vec![Query::limit(2), Query::offset(3)],
+                vec![Query::limit(2), Query::offset(5)],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_memberships_stream() {
+        use crate::{
+            client::ClientBuilder, services::server::teams::Teams, transport::CannedTransport,
+        };
+
+        /// A `GET /teams/{teamId}/memberships` response.
+        const RECORDED: &str = r#"{
+            "total": 2,
+            "memberships": [
+                {
+                    "$id": "5e5ea5c16897e",
+                    "$createdAt": "2020-10-15T06:38:00.000+00:00",
+                    "$updatedAt": "2020-10-15T06:38:00.000+00:00",
+                    "userId": "5e5ea5c16897e",
+                    "userName": "John Doe",
+                    "userEmail": "john@appwrite.io",
+                    "teamId": "5e5ea5c16897e",
+                    "teamName": "VIP",
+                    "invited": "2020-10-15T06:38:00.000+00:00",
+                    "joined": "2020-10-15T06:38:00.000+00:00",
+                    "confirm": false,
+                    "mfa": false,
+                    "roles": ["owner"]
+                },
+                {
+                    "$id": "5e5ea5c16897f",
+                    "$createdAt": "2020-10-16T06:38:00.000+00:00",
+                    "$updatedAt": "2020-10-16T06:38:00.000+00:00",
+                    "userId": "5e5ea5c16897f",
+                    "userName": "Jane Doe",
+                    "userEmail": "jane@appwrite.io",
+                    "teamId": "5e5ea5c16897e",
+                    "teamName": "VIP",
+                    "invited": "2020-10-16T06:38:00.000+00:00",
+                    "joined": "",
+                    "confirm": false,
+                    "mfa": false,
+                    "roles": []
+                }
+            ]
+        }"#;
+
+        let client = ClientBuilder::default()
+            .set_transport(CannedTransport::new().json(
+                "/teams/5e5ea5c16897e/memberships",
+                serde_json::from_str(RECORDED).unwrap(),
+            ))
+            .unwrap()
+            .build()
+            .unwrap();
+        let names = Teams::list_memberships_stream(&client, "5e5ea5c16897e", None, None, None)
+            .map(|membership| membership.unwrap().user_name)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(names, ["John Doe", "Jane Doe"]);
+    }
+}
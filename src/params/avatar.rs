@@ -0,0 +1,31 @@
+//! # Avatars
+//!
+//! Parameter builders for [`crate::services::server::avatar`].
+
+use crate::{
+    error::Error,
+    params::{builder, check_range},
+};
+
+#[cfg(doc)]
+use crate::services::server::avatar::Avatars;
+
+builder! {
+    /// Parameters of [`Avatars::get_initials`], see [`Avatars::get_initials_with`].
+    #[derive(Debug, Clone, Default)]
+    pub struct GetInitials {
+        name: Option<String>,
+        width: Option<u64>,
+        height: Option<u64>,
+        background: Option<String>,
+    }
+}
+
+impl GetInitials {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_range("width", self.width, 0..=2000)?;
+        check_range("height", self.height, 0..=2000)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+//! # Databases
+//!
+//! Parameter builders for [`crate::services::server::databases`].
+
+use crate::{
+    enums::{
+        index_type::IndexType, relation_mutate::RelationMutate, relationship_type::RelationshipType,
+    },
+    error::Error,
+    params::{builder, check_existing_id, check_not_empty},
+    wait::WaitOptions,
+};
+
+#[cfg(doc)]
+use crate::services::server::databases::Databases;
+
+builder! {
+    /// Parameters of [`Databases::create_boolean_attribute`], see [`Databases::create_boolean_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateBooleanAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        required: bool,
+        default: Option<bool>,
+        array: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateBooleanAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_date_time_attribute`], see [`Databases::create_date_time_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateDateTimeAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        required: bool,
+        default: Option<String>,
+        array: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateDateTimeAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_email_attribute`], see [`Databases::create_email_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateEmailAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        required: bool,
+        default: Option<String>,
+        array: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateEmailAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_enum_attribute`], see [`Databases::create_enum_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateEnumAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        elements: Vec<String>,
+        required: bool,
+        default: Option<String>,
+        array: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateEnumAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        if self.elements.is_empty() {
+            return Err(Error::InvalidParameter {
+                param: "elements",
+                reason: String::from("must not be empty"),
+            });
+        }
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_float_attribute`], see [`Databases::create_float_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateFloatAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        required: bool,
+        min: Option<f64>,
+        max: Option<f64>,
+        default: Option<f64>,
+        array: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateFloatAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_integer_attribute`], see [`Databases::create_integer_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateIntegerAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        required: bool,
+        min: Option<i64>,
+        max: Option<i64>,
+        default: Option<i64>,
+        array: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateIntegerAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_ip_attribute`], see [`Databases::create_ip_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateIpAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        required: bool,
+        default: Option<String>,
+        array: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateIpAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_relationship_attribute`], see [`Databases::create_relationship_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateRelationshipAttribute {
+        database_id: String,
+        collection_id: String,
+        related_collection_id: String,
+        relationship_type: RelationshipType,
+        two_way: Option<bool>,
+        key: Option<String>,
+        two_way_key: Option<String>,
+        on_delete: Option<RelationMutate>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateRelationshipAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_existing_id("related_collection_id", &self.related_collection_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_string_attribute`], see [`Databases::create_string_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateStringAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        size: u64,
+        required: bool,
+        default: Option<String>,
+        array: Option<bool>,
+        encrypt: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateStringAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        if self.size == 0 {
+            return Err(Error::InvalidParameter {
+                param: "size",
+                reason: String::from("must be positive"),
+            });
+        }
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_url_attribute`], see [`Databases::create_url_attribute_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateUrlAttribute {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        required: bool,
+        default: Option<String>,
+        array: Option<bool>,
+        /// Return once the attribute is `available`, polling with these options,
+        /// see [`Databases::wait_for_attribute`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateUrlAttribute {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Databases::create_index`], see [`Databases::create_index_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateIndex {
+        database_id: String,
+        collection_id: String,
+        key: String,
+        index_type: IndexType,
+        attributes: Vec<String>,
+        orders: Option<Vec<String>>,
+        /// Return once the index is `available`, polling with these options, see
+        /// [`Databases::wait_for_index`].
+        wait: Option<WaitOptions>,
+    }
+}
+
+impl CreateIndex {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("database_id", &self.database_id)?;
+        check_existing_id("collection_id", &self.collection_id)?;
+        check_not_empty("key", &self.key)?;
+        if self.attributes.is_empty() {
+            return Err(Error::InvalidParameter {
+                param: "attributes",
+                reason: String::from("must not be empty"),
+            });
+        }
+        if let Some(orders) = &self.orders {
+            if let Some(order) = orders
+                .iter()
+                .find(|order| *order != "ASC" && *order != "DESC")
+            {
+                return Err(Error::InvalidParameter {
+                    param: "orders",
+                    reason: format!("`{order}` is neither `ASC` nor `DESC`"),
+                });
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+//! # Functions
+//!
+//! Parameter builders for [`crate::services::server::functions`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    enums::{execution_method::ExecutionMethod, runtime::Runtime},
+    error::Error,
+    params::{builder, check_existing_id, check_id, check_not_empty},
+};
+
+#[cfg(doc)]
+use crate::services::server::functions::Functions;
+
+builder! {
+    /// Parameters of [`Functions::create`], see [`Functions::create_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateFunction {
+        function_id: String,
+        name: String,
+        runtime: Runtime,
+        execute: Option<Vec<String>>,
+        events: Option<Vec<String>>,
+        schedule: Option<String>,
+        timeout: Option<u64>,
+        enabled: Option<bool>,
+        logging: Option<bool>,
+        entry_point: Option<String>,
+        commands: Option<String>,
+        installation_id: Option<String>,
+        provider_repository_id: Option<String>,
+        provider_branch: Option<String>,
+        provider_silent_mode: Option<bool>,
+        provider_root_directory: Option<String>,
+        template_repository: Option<String>,
+        template_owner: Option<String>,
+        template_root_directory: Option<String>,
+        template_branch: Option<String>,
+    }
+}
+
+impl CreateFunction {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("function_id", &self.function_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Functions::update`], see [`Functions::update_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateFunction {
+        function_id: String,
+        name: String,
+        runtime: Option<Runtime>,
+        execute: Option<Vec<String>>,
+        events: Option<Vec<String>>,
+        schedule: Option<String>,
+        timeout: Option<u64>,
+        enabled: Option<bool>,
+        logging: Option<bool>,
+        entry_point: Option<String>,
+        commands: Option<String>,
+        installation_id: Option<String>,
+        provider_repository_id: Option<String>,
+        provider_branch: Option<String>,
+        provider_silent_mode: Option<bool>,
+        provider_root_directory: Option<String>,
+    }
+}
+
+impl UpdateFunction {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("function_id", &self.function_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Functions::create_executions`], see [`Functions::create_executions_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateExecution {
+        function_id: String,
+        body: Option<String>,
+        x_async: Option<bool>,
+        path: Option<String>,
+        method: Option<ExecutionMethod>,
+        headers: Option<Map<String, Value>>,
+    }
+}
+
+impl CreateExecution {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("function_id", &self.function_id)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,598 @@
+//! # Messaging
+//!
+//! Parameter builders for [`crate::services::server::messaging`].
+
+use serde_json::{Map, Value};
+
+use crate::{
+    enums::smtp_encryption::SmtpEncryption,
+    error::Error,
+    params::{builder, check_existing_id, check_id, check_not_empty},
+};
+
+#[cfg(doc)]
+use crate::services::server::messaging::Messaging;
+
+builder! {
+    /// Parameters of [`Messaging::create_email`], see [`Messaging::create_email_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateEmail {
+        message_id: String,
+        subject: String,
+        content: String,
+        topics: Option<Vec<String>>,
+        users: Option<Vec<String>>,
+        targets: Option<Vec<String>>,
+        cc: Option<Vec<String>>,
+        bcc: Option<Vec<String>>,
+        attachments: Option<Vec<String>>,
+        draft: Option<bool>,
+        html: Option<bool>,
+        scheduled_at: Option<bool>,
+    }
+}
+
+impl CreateEmail {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("message_id", &self.message_id)?;
+        check_not_empty("subject", &self.subject)?;
+        check_not_empty("content", &self.content)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_email`], see [`Messaging::update_email_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateEmail {
+        message_id: String,
+        topics: Option<Vec<String>>,
+        users: Option<Vec<String>>,
+        targets: Option<Vec<String>>,
+        subject: Option<String>,
+        content: Option<String>,
+        draft: Option<bool>,
+        html: Option<bool>,
+        cc: Option<Vec<String>>,
+        bcc: Option<Vec<String>>,
+        scheduled_at: Option<String>,
+    }
+}
+
+impl UpdateEmail {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("message_id", &self.message_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_push`], see [`Messaging::create_push_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreatePush {
+        message_id: String,
+        title: String,
+        body: String,
+        topics: Option<Vec<String>>,
+        users: Option<Vec<String>>,
+        targets: Option<Vec<String>>,
+        data: Option<Map<String, Value>>,
+        action: Option<String>,
+        image: Option<String>,
+        icon: Option<String>,
+        sound: Option<String>,
+        color: Option<String>,
+        tag: Option<String>,
+        badge: Option<String>,
+        draft: Option<bool>,
+        scheduled_at: Option<String>,
+    }
+}
+
+impl CreatePush {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("message_id", &self.message_id)?;
+        check_not_empty("title", &self.title)?;
+        check_not_empty("body", &self.body)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_push`], see [`Messaging::update_push_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdatePush {
+        message_id: String,
+        topics: Option<Vec<String>>,
+        users: Option<Vec<String>>,
+        targets: Option<Vec<String>>,
+        title: Option<String>,
+        body: Option<String>,
+        data: Option<Map<String, Value>>,
+        action: Option<String>,
+        image: Option<String>,
+        icon: Option<String>,
+        sound: Option<String>,
+        color: Option<String>,
+        tag: Option<String>,
+        badge: Option<i32>,
+        draft: Option<bool>,
+        scheduled_at: Option<String>,
+    }
+}
+
+impl UpdatePush {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("message_id", &self.message_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_sms`], see [`Messaging::create_sms_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateSms {
+        message_id: String,
+        content: String,
+        topics: Option<Vec<String>>,
+        users: Option<Vec<String>>,
+        targets: Option<Vec<String>>,
+        draft: Option<bool>,
+        scheduled_at: Option<String>,
+    }
+}
+
+impl CreateSms {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("message_id", &self.message_id)?;
+        check_not_empty("content", &self.content)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_sms`], see [`Messaging::update_sms_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateSms {
+        message_id: String,
+        topics: Option<Vec<String>>,
+        users: Option<Vec<String>>,
+        targets: Option<Vec<String>>,
+        content: Option<String>,
+        draft: Option<bool>,
+        scheduled_at: Option<String>,
+    }
+}
+
+impl UpdateSms {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("message_id", &self.message_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_apns_provider`], see [`Messaging::create_apns_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateApnsProvider {
+        provider_id: String,
+        name: String,
+        auth_key: Option<String>,
+        auth_key_id: Option<String>,
+        team_id: Option<String>,
+        bundle_id: Option<String>,
+        sandbox: Option<bool>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateApnsProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_apns_provider`], see [`Messaging::update_apns_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateApnsProvider {
+        provider_id: String,
+        name: Option<String>,
+        enabled: Option<bool>,
+        auth_key: Option<String>,
+        auth_key_id: Option<String>,
+        team_id: Option<String>,
+        bundle_id: Option<String>,
+        sandbox: Option<bool>,
+    }
+}
+
+impl UpdateApnsProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_mail_gun_provider`], see [`Messaging::create_mail_gun_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateMailGunProvider {
+        provider_id: String,
+        name: String,
+        api_key: Option<String>,
+        domain: Option<String>,
+        is_eu_region: Option<bool>,
+        from_name: Option<String>,
+        from_email: Option<String>,
+        reply_to_name: Option<String>,
+        reply_to_email: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateMailGunProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_mail_gun_provider`], see [`Messaging::update_mail_gun_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateMailGunProvider {
+        provider_id: String,
+        name: Option<String>,
+        api_key: Option<String>,
+        domain: Option<String>,
+        is_eu_region: Option<bool>,
+        enabled: Option<bool>,
+        from_name: Option<String>,
+        from_email: Option<String>,
+        reply_to_name: Option<String>,
+        reply_to_email: Option<String>,
+    }
+}
+
+impl UpdateMailGunProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_msg_91_provider`], see [`Messaging::create_msg_91_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateMsg91Provider {
+        provider_id: String,
+        name: String,
+        template_id: Option<String>,
+        sender_id: Option<String>,
+        auth_key: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateMsg91Provider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_msg_91_provider`], see [`Messaging::update_msg_91_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateMsg91Provider {
+        provider_id: String,
+        name: Option<String>,
+        enabled: Option<bool>,
+        template_id: Option<String>,
+        sender_id: Option<String>,
+        auth_key: Option<String>,
+    }
+}
+
+impl UpdateMsg91Provider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_send_grid_provider`], see [`Messaging::create_send_grid_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateSendGridProvider {
+        provider_id: String,
+        name: String,
+        api_key: Option<String>,
+        from_name: Option<String>,
+        from_email: Option<String>,
+        reply_to_name: Option<String>,
+        reply_to_email: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateSendGridProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_send_grid_provider`], see [`Messaging::update_send_grid_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateSendGridProvider {
+        provider_id: String,
+        name: Option<String>,
+        enabled: Option<bool>,
+        api_key: Option<String>,
+        from_name: Option<String>,
+        from_email: Option<String>,
+        reply_to_name: Option<String>,
+        reply_to_email: Option<String>,
+    }
+}
+
+impl UpdateSendGridProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_smtp_provider`], see [`Messaging::create_smtp_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateSmtpProvider {
+        provider_id: String,
+        name: String,
+        host: String,
+        port: Option<i32>,
+        username: Option<String>,
+        password: Option<String>,
+        encryption: Option<SmtpEncryption>,
+        auto_tls: Option<bool>,
+        mailer: Option<String>,
+        from_name: Option<String>,
+        from_email: Option<String>,
+        reply_to_name: Option<String>,
+        reply_to_email: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateSmtpProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        check_not_empty("host", &self.host)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_smtp_provider`], see [`Messaging::update_smtp_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateSmtpProvider {
+        provider_id: String,
+        name: Option<String>,
+        host: Option<String>,
+        port: Option<i32>,
+        username: Option<String>,
+        password: Option<String>,
+        encryption: Option<SmtpEncryption>,
+        auto_tls: Option<bool>,
+        mailer: Option<String>,
+        from_name: Option<String>,
+        from_email: Option<String>,
+        reply_to_name: Option<String>,
+        reply_to_email: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl UpdateSmtpProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_telesign_provider`], see [`Messaging::create_telesign_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateTelesignProvider {
+        provider_id: String,
+        name: String,
+        from: Option<String>,
+        customer_id: Option<String>,
+        api_key: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateTelesignProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_telesign_provider`], see [`Messaging::update_telesign_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateTelesignProvider {
+        provider_id: String,
+        name: Option<String>,
+        enabled: Option<bool>,
+        customer_id: Option<String>,
+        api_key: Option<String>,
+        from: Option<String>,
+    }
+}
+
+impl UpdateTelesignProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_text_magic_provider`], see [`Messaging::create_text_magic_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateTextMagicProvider {
+        provider_id: String,
+        name: String,
+        from: Option<String>,
+        username: Option<String>,
+        api_key: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateTextMagicProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_text_magic_provider`], see [`Messaging::update_text_magic_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateTextMagicProvider {
+        provider_id: String,
+        name: Option<String>,
+        enabled: Option<bool>,
+        username: Option<String>,
+        api_key: Option<String>,
+        from: Option<String>,
+    }
+}
+
+impl UpdateTextMagicProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_twilio_provider`], see [`Messaging::create_twilio_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateTwilioProvider {
+        provider_id: String,
+        name: String,
+        from: Option<String>,
+        account_id: Option<String>,
+        auth_token: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateTwilioProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_twilio_provider`], see [`Messaging::update_twilio_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateTwilioProvider {
+        provider_id: String,
+        name: Option<String>,
+        enabled: Option<bool>,
+        accounts_id: Option<String>,
+        auth_token: Option<String>,
+        from: Option<String>,
+    }
+}
+
+impl UpdateTwilioProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::create_vonage_provider`], see [`Messaging::create_vonage_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateVonageProvider {
+        provider_id: String,
+        name: String,
+        from: Option<String>,
+        api_key: Option<String>,
+        api_secret: Option<String>,
+        enabled: Option<bool>,
+    }
+}
+
+impl CreateVonageProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("provider_id", &self.provider_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Messaging::update_vonage_provider`], see [`Messaging::update_vonage_provider_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateVonageProvider {
+        provider_id: String,
+        name: Option<String>,
+        enabled: Option<bool>,
+        api_key: Option<String>,
+        api_secret: Option<String>,
+        from: Option<String>,
+    }
+}
+
+impl UpdateVonageProvider {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("provider_id", &self.provider_id)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+//! # Params
+//!
+//! Builder-style parameter structs for the endpoints with long lists of
+//! optional arguments. Every builder is sent with the `*_with` variant of its
+//! service function, which validates it locally first.
+//!
+//! ```no_run
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::enums::compression::Compression;
+//! use unofficial_appwrite::error::Error;
+//! use unofficial_appwrite::params::storage::CreateBucket;
+//! use unofficial_appwrite::services::server::storage::Storage;
+//!
+//! # async fn run() -> Result<(), Error> {
+//! # let client = ClientBuilder::default().build()?;
+//! let bucket = Storage::create_bucket_with(
+//!     &client,
+//!     CreateBucket::new("avatars", "Avatars")
+//!         .maximum_file_size(5 * 1024 * 1024)
+//!         .allowed_file_extensions(["png", "jpg"])
+//!         .compression(Compression::Zstd),
+//! )
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Display, ops::RangeInclusive};
+
+use crate::error::Error;
+
+pub mod avatar;
+pub mod databases;
+pub mod functions;
+pub mod messaging;
+pub mod storage;
+pub mod teams;
+pub mod users;
+
+/// Longest custom ID Appwrite accepts.
+const MAX_ID_LEN: usize = 36;
+
+/// `value` must be `unique()` or a valid custom ID: at most 36 characters
+/// among `a-z`, `A-Z`, `0-9`, `.`, `-` and `_`, not starting with a special
+/// character.
+pub(crate) fn check_id(param: &'static str, value: &str) -> Result<(), Error> {
+    if value == "unique()" {
+        return Ok(());
+    }
+    let valid_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_');
+    let reason = if value.is_empty() {
+        String::from("must not be empty")
+    } else if value.len() > MAX_ID_LEN {
+        format!("must be at most {MAX_ID_LEN} characters")
+    } else if !value.chars().all(valid_char) {
+        String::from("may only contain a-z, A-Z, 0-9, period, hyphen and underscore")
+    } else if !value.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        String::from("must not start with a special character")
+    } else {
+        return Ok(());
+    };
+    Err(Error::InvalidParameter { param, reason })
+}
+
+/// `value` must be the ID of an existing resource: a valid custom ID, but not
+/// `unique()`.
+pub(crate) fn check_existing_id(param: &'static str, value: &str) -> Result<(), Error> {
+    if value == "unique()" {
+        return Err(Error::InvalidParameter {
+            param,
+            reason: String::from("must be an existing ID, not `unique()`"),
+        });
+    }
+    check_id(param, value)
+}
+
+pub(crate) fn check_not_empty(param: &'static str, value: &str) -> Result<(), Error> {
+    match value.trim().is_empty() {
+        true => Err(Error::InvalidParameter {
+            param,
+            reason: String::from("must not be empty"),
+        }),
+        false => Ok(()),
+    }
+}
+
+/// `value` must be set, for parameters the API requires but the builder
+/// takes through a setter.
+pub(crate) fn check_required<T>(param: &'static str, value: &Option<T>) -> Result<(), Error> {
+    match value {
+        Some(_) => Ok(()),
+        None => Err(Error::InvalidParameter {
+            param,
+            reason: String::from("must be set"),
+        }),
+    }
+}
+
+pub(crate) fn check_range<T: PartialOrd + Display>(
+    param: &'static str,
+    value: Option<T>,
+    range: RangeInclusive<T>,
+) -> Result<(), Error> {
+    match value {
+        Some(value) if !range.contains(&value) => Err(Error::InvalidParameter {
+            param,
+            reason: format!(
+                "must be between {} and {}, got {value}",
+                range.start(),
+                range.end()
+            ),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Declare a parameter builder: the struct with public fields, a `new`
+/// taking the fields that aren't `Option`s, in order, and a setter for each
+/// `Option` field. `String`s are taken as `impl Into<String>` and
+/// `Vec<String>`s as any iterator of them; attributes on an `Option` field
+/// go on its setter.
+///
+/// Every field needs a trailing comma.
+macro_rules! builder {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident { $($fields:tt)* }
+    ) => {
+        $crate::params::builder!(@fields [$(#[$attr])* $name] [] [] [] [] $($fields)*);
+    };
+
+    (@fields $head:tt [$($field:tt)*] [$($arg:tt)*] [$($init:tt)*] [$($setter:tt)*]
+        $(#[$meta:meta])* $f:ident: Option<Vec<String>>, $($rest:tt)*
+    ) => {
+        $crate::params::builder!(@fields $head
+            [$($field)* pub $f: Option<Vec<String>>,]
+            [$($arg)*]
+            [$($init)* $f: None,]
+            [$($setter)*
+                $(#[$meta])*
+                pub fn $f(mut self, $f: impl IntoIterator<Item = impl Into<String>>) -> Self {
+                    self.$f = Some($f.into_iter().map(Into::into).collect());
+                    self
+                }
+            ]
+            $($rest)*
+        );
+    };
+
+    (@fields $head:tt [$($field:tt)*] [$($arg:tt)*] [$($init:tt)*] [$($setter:tt)*]
+        $(#[$meta:meta])* $f:ident: Option<String>, $($rest:tt)*
+    ) => {
+        $crate::params::builder!(@fields $head
+            [$($field)* pub $f: Option<String>,]
+            [$($arg)*]
+            [$($init)* $f: None,]
+            [$($setter)*
+                $(#[$meta])*
+                pub fn $f(mut self, $f: impl Into<String>) -> Self {
+                    self.$f = Some($f.into());
+                    self
+                }
+            ]
+            $($rest)*
+        );
+    };
+
+    (@fields $head:tt [$($field:tt)*] [$($arg:tt)*] [$($init:tt)*] [$($setter:tt)*]
+        $(#[$meta:meta])* $f:ident: Option<$ty:ty>, $($rest:tt)*
+    ) => {
+        $crate::params::builder!(@fields $head
+            [$($field)* pub $f: Option<$ty>,]
+            [$($arg)*]
+            [$($init)* $f: None,]
+            [$($setter)*
+                $(#[$meta])*
+                pub fn $f(mut self, $f: $ty) -> Self {
+                    self.$f = Some($f);
+                    self
+                }
+            ]
+            $($rest)*
+        );
+    };
+
+    (@fields $head:tt [$($field:tt)*] [$($arg:tt)*] [$($init:tt)*] $setters:tt
+        $f:ident: Vec<String>, $($rest:tt)*
+    ) => {
+        $crate::params::builder!(@fields $head
+            [$($field)* pub $f: Vec<String>,]
+            [$($arg)* $f: impl IntoIterator<Item = impl Into<String>>,]
+            [$($init)* $f: $f.into_iter().map(Into::into).collect(),]
+            $setters
+            $($rest)*
+        );
+    };
+
+    (@fields $head:tt [$($field:tt)*] [$($arg:tt)*] [$($init:tt)*] $setters:tt
+        $f:ident: String, $($rest:tt)*
+    ) => {
+        $crate::params::builder!(@fields $head
+            [$($field)* pub $f: String,]
+            [$($arg)* $f: impl Into<String>,]
+            [$($init)* $f: $f.into(),]
+            $setters
+            $($rest)*
+        );
+    };
+
+    (@fields $head:tt [$($field:tt)*] [$($arg:tt)*] [$($init:tt)*] $setters:tt
+        $f:ident: $ty:ty, $($rest:tt)*
+    ) => {
+        $crate::params::builder!(@fields $head
+            [$($field)* pub $f: $ty,]
+            [$($arg)* $f: $ty,]
+            [$($init)* $f,]
+            $setters
+            $($rest)*
+        );
+    };
+
+    (@fields [$(#[$attr:meta])* $name:ident] [$($field:tt)*] [$($arg:tt)*] [$($init:tt)*] [$($setter:tt)*]) => {
+        $(#[$attr])*
+        pub struct $name {
+            $($field)*
+        }
+
+        impl $name {
+            pub fn new($($arg)*) -> Self {
+                Self { $($init)* }
+            }
+
+            $($setter)*
+        }
+    };
+}
+pub(crate) use builder;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::{
+        databases::{CreateEnumAttribute, CreateStringAttribute},
+        storage::{CreateBucket, GetFilePreview, UpdateBucket},
+        users::CreateScryptUser,
+    };
+
+    #[test]
+    fn test_validation() {
+        assert!(CreateBucket::new("avatars", "Avatars").validate().is_ok());
+        assert!(CreateBucket::new("unique()", "Avatars").validate().is_ok());
+        assert!(CreateBucket::new("_avatars", "Avatars").validate().is_err());
+        assert!(CreateBucket::new("a".repeat(37), "Avatars")
+            .validate()
+            .is_err());
+        assert!(matches!(
+            CreateBucket::new("avatars", " ").validate(),
+            Err(Error::InvalidParameter { param: "name", .. })
+        ));
+        assert!(matches!(
+            UpdateBucket::new("unique()", "Avatars").validate(),
+            Err(Error::InvalidParameter {
+                param: "bucket_id",
+                ..
+            })
+        ));
+        assert!(GetFilePreview::new("avatars", "unique()")
+            .validate()
+            .is_err());
+        assert!(GetFilePreview::new("avatars", "6618")
+            .width(400)
+            .opacity(0.5)
+            .validate()
+            .is_ok());
+        assert!(GetFilePreview::new("avatars", "6618")
+            .quality(101)
+            .validate()
+            .is_err());
+        assert!(
+            CreateStringAttribute::new("blog", "posts", "title", 0, true)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            CreateEnumAttribute::new("blog", "posts", "status", Vec::<String>::new(), true)
+                .validate()
+                .is_err()
+        );
+
+        let scrypt = CreateScryptUser::new("6618", "ada@example.com", "aGFzaA==", "c2FsdA==")
+            .password_cpu(16384)
+            .password_memory(8)
+            .password_parallel(1);
+        assert!(matches!(
+            scrypt.clone().validate(),
+            Err(Error::InvalidParameter {
+                param: "password_length",
+                ..
+            })
+        ));
+        assert!(scrypt.password_length(64).validate().is_ok());
+    }
+}
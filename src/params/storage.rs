@@ -0,0 +1,100 @@
+//! # Storage
+//!
+//! Parameter builders for [`crate::services::server::storage`].
+
+use crate::{
+    enums::{compression::Compression, image_format::ImageFormat, image_gravity::ImageGravity},
+    error::Error,
+    params::{builder, check_existing_id, check_id, check_not_empty, check_range},
+};
+
+#[cfg(doc)]
+use crate::services::server::storage::Storage;
+
+builder! {
+    /// Parameters of [`Storage::create_bucket`], see [`Storage::create_bucket_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateBucket {
+        bucket_id: String,
+        name: String,
+        permissions: Option<Vec<String>>,
+        file_security: Option<bool>,
+        enabled: Option<bool>,
+        maximum_file_size: Option<usize>,
+        allowed_file_extensions: Option<Vec<String>>,
+        compression: Option<Compression>,
+        encryption: Option<bool>,
+        antivirus: Option<bool>,
+    }
+}
+
+impl CreateBucket {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("bucket_id", &self.bucket_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Storage::update_bucket`], see [`Storage::update_bucket_with`].
+    #[derive(Debug, Clone)]
+    pub struct UpdateBucket {
+        bucket_id: String,
+        name: String,
+        permissions: Option<Vec<String>>,
+        file_security: Option<bool>,
+        enabled: Option<bool>,
+        maximum_file_size: Option<usize>,
+        allowed_file_extensions: Option<Vec<String>>,
+        compression: Option<Compression>,
+        encryption: Option<bool>,
+        antivirus: Option<bool>,
+    }
+}
+
+impl UpdateBucket {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("bucket_id", &self.bucket_id)?;
+        check_not_empty("name", &self.name)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Storage::get_file_preview`], see [`Storage::get_file_preview_with`].
+    #[derive(Debug, Clone)]
+    pub struct GetFilePreview {
+        bucket_id: String,
+        file_id: String,
+        width: Option<usize>,
+        height: Option<usize>,
+        gravity: Option<ImageGravity>,
+        quality: Option<usize>,
+        border_width: Option<usize>,
+        border_color: Option<String>,
+        border_radius: Option<usize>,
+        opacity: Option<f32>,
+        rotation: Option<usize>,
+        background: Option<String>,
+        output: Option<ImageFormat>,
+    }
+}
+
+impl GetFilePreview {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("bucket_id", &self.bucket_id)?;
+        check_existing_id("file_id", &self.file_id)?;
+        check_range("width", self.width, 0..=4000)?;
+        check_range("height", self.height, 0..=4000)?;
+        check_range("quality", self.quality, 0..=100)?;
+        check_range("border_width", self.border_width, 0..=100)?;
+        check_range("border_radius", self.border_radius, 0..=4000)?;
+        check_range("opacity", self.opacity, 0.0..=1.0)?;
+        check_range("rotation", self.rotation, 0..=360)?;
+        Ok(())
+    }
+}
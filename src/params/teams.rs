@@ -0,0 +1,33 @@
+//! # Teams
+//!
+//! Parameter builders for [`crate::services::server::teams`].
+
+use crate::{
+    error::Error,
+    params::{builder, check_existing_id},
+};
+
+#[cfg(doc)]
+use crate::services::server::teams::Teams;
+
+builder! {
+    /// Parameters of [`Teams::create_memberships`], see [`Teams::create_memberships_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateMembership {
+        team_id: String,
+        roles: Vec<String>,
+        email: Option<String>,
+        user_id: Option<String>,
+        phone: Option<String>,
+        url: Option<String>,
+        name: Option<String>,
+    }
+}
+
+impl CreateMembership {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_existing_id("team_id", &self.team_id)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+//! # Users
+//!
+//! Parameter builders for [`crate::services::server::users`].
+
+use crate::{
+    error::Error,
+    params::{builder, check_id, check_not_empty, check_required},
+};
+
+#[cfg(doc)]
+use crate::services::server::users::Users;
+
+builder! {
+    /// Parameters of [`Users::create`], see [`Users::create_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateUser {
+        user_id: String,
+        email: Option<String>,
+        phone: Option<String>,
+        password: Option<String>,
+        name: Option<String>,
+    }
+}
+
+impl CreateUser {
+    /// Check the parameters without sending anything.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("user_id", &self.user_id)?;
+        Ok(())
+    }
+}
+
+builder! {
+    /// Parameters of [`Users::create_scrypt_user`], see [`Users::create_scrypt_user_with`].
+    #[derive(Debug, Clone)]
+    pub struct CreateScryptUser {
+        user_id: String,
+        email: String,
+        password: String,
+        password_salt: String,
+        password_cpu: Option<usize>,
+        password_memory: Option<usize>,
+        password_parallel: Option<usize>,
+        password_length: Option<usize>,
+        name: Option<String>,
+    }
+}
+
+impl CreateScryptUser {
+    /// Check the parameters without sending anything. The scrypt cost
+    /// parameters are required.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_id("user_id", &self.user_id)?;
+        check_not_empty("email", &self.email)?;
+        check_not_empty("password", &self.password)?;
+        check_not_empty("password_salt", &self.password_salt)?;
+        check_required("password_cpu", &self.password_cpu)?;
+        check_required("password_memory", &self.password_memory)?;
+        check_required("password_parallel", &self.password_parallel)?;
+        check_required("password_length", &self.password_length)?;
+        Ok(())
+    }
+}
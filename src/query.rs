@@ -1,53 +1,170 @@
-use serde::{Deserialize, Serialize};
+//! # Query
+//!
+//! Queries filter, sort and paginate the results of list endpoints. A
+//! [`Query`] is a typed tree that can be inspected, combined and validated
+//! before it is sent; it serializes to the JSON string Appwrite expects.
+//!
+//! ```
+//! use serde_json::json;
+//! use unofficial_appwrite::query::Query;
+//!
+//! let query = Query::or(vec![
+//!     Query::less_than("size", json!(5)),
+//!     Query::greater_than("size", json!(10)),
+//! ]);
+//! assert!(query.validate().is_ok());
+//!
+//! let parsed: Query = query.to_string().parse().unwrap();
+//! assert_eq!(parsed, query);
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{json, Value};
 
-#[derive(Serialize, Deserialize, Debug)]
-struct QueryString {
-    method: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    attribute: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    values: Option<Value>, // Use serde_json::Value for dynamic values
-}
-impl QueryString {
-    fn new(method: &str, attribute: Option<String>, values: Option<Value>) -> Self {
-        Self {
-            method: method.to_string(),
-            attribute,
-            values,
-        }
-    }
+use crate::error::Error;
 
-    pub fn to_string(&self) -> String {
-        serde_json::to_string(self).unwrap()
-    }
+pub mod eval;
+
+/// How queries are written on the wire, see
+/// [`ClientBuilder::set_query_syntax`](crate::client::ClientBuilder::set_query_syntax).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuerySyntax {
+    /// JSON objects, as Appwrite 1.5 and later expect.
+    #[default]
+    Json,
+    /// The function-call syntax of Appwrite 1.4 and earlier, e.g.
+    /// `equal("title", ["Iron Man"])`. Queries the old servers don't know,
+    /// such as `and` / `or`, fail with [`Error::InvalidParameter`].
+    Legacy,
 }
 
+/// The JSON form of a query: `{"method":…,"attribute":…,"values":[…]}`.
 #[derive(Serialize, Deserialize, Debug)]
-struct QueryDynamic {
+struct QueryJson {
     method: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     attribute: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    values: Option<Vec<serde_json::Value>>, // Use serde_json::Value for dynamic values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    values: Option<Vec<Value>>,
 }
-impl QueryDynamic {
-    fn new(method: &str, attribute: Option<String>, values: Option<Vec<Value>>) -> Self {
+
+impl QueryJson {
+    fn new(method: &str, attribute: Option<&str>, values: Option<Vec<Value>>) -> Self {
         Self {
             method: method.to_string(),
-            attribute,
+            attribute: attribute.map(str::to_string),
             values,
         }
     }
+}
 
-    pub fn to_string(&self) -> String {
-        serde_json::to_string(self).unwrap()
-    }
+/// A single query for a list endpoint.
+///
+/// Build queries with the constructor functions ([`Query::equal`],
+/// [`Query::limit`], …). Query strings built elsewhere convert with
+/// [`From<String>`]; whatever doesn't parse is kept as [`Query::Raw`] and sent
+/// verbatim.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    Equal {
+        attribute: String,
+        values: Vec<Value>,
+    },
+    NotEqual {
+        attribute: String,
+        values: Vec<Value>,
+    },
+    LessThan {
+        attribute: String,
+        value: Value,
+    },
+    LessThanEqual {
+        attribute: String,
+        value: Value,
+    },
+    GreaterThan {
+        attribute: String,
+        value: Value,
+    },
+    GreaterThanEqual {
+        attribute: String,
+        value: Value,
+    },
+    Between {
+        attribute: String,
+        start: Value,
+        end: Value,
+    },
+    Search {
+        attribute: String,
+        value: String,
+    },
+    StartsWith {
+        attribute: String,
+        value: String,
+    },
+    EndsWith {
+        attribute: String,
+        value: String,
+    },
+    Contains {
+        attribute: String,
+        values: Vec<Value>,
+    },
+    IsNull {
+        attribute: String,
+    },
+    IsNotNull {
+        attribute: String,
+    },
+    NotContains {
+        attribute: String,
+        values: Vec<Value>,
+    },
+    NotSearch {
+        attribute: String,
+        value: String,
+    },
+    NotBetween {
+        attribute: String,
+        start: Value,
+        end: Value,
+    },
+    NotStartsWith {
+        attribute: String,
+        value: String,
+    },
+    NotEndsWith {
+        attribute: String,
+        value: String,
+    },
+    CreatedBefore(String),
+    CreatedAfter(String),
+    UpdatedBefore(String),
+    UpdatedAfter(String),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+    Select(Vec<String>),
+    OrderAsc(String),
+    OrderDesc(String),
+    OrderRandom,
+    CursorBefore(String),
+    CursorAfter(String),
+    Limit(usize),
+    Offset(usize),
+    /// A query string this crate doesn't model.
+    Raw(String),
 }
 
-/// Helper class to generate query strings.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Query;
+/// Spread a JSON array into a list of values; wrap anything else.
+fn into_values(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(values) => values,
+        value => vec![value],
+    }
+}
 
 impl Query {
     /// Filter resources where [attribute] is equal to [value].
@@ -56,266 +173,420 @@ impl Query {
     /// the query will return resources where [attribute] is equal
     /// to any of the values in the list.
     /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::equal("title", json!(vec!["bamboo", "ace"])),
+    /// Query::equal("title", json!(vec!["bamboo", "ace"])).to_string(),
     /// r#"{"method":"equal","attribute":"title","values":["bamboo","ace"]}"#,
     /// );
     /// ```
     /// or
     /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::equal("title", json!(vec!["Iron Man"])),
+    /// Query::equal("title", json!("Iron Man")).to_string(),
     /// r#"{"method":"equal","attribute":"title","values":["Iron Man"]}"#,
     /// );
     /// ```
-    pub fn equal(attribute: &str, value: Value) -> String {
-        QueryString::new("equal", Some(attribute.to_string()), Some(value)).to_string()
+    pub fn equal(attribute: &str, value: Value) -> Self {
+        Self::Equal {
+            attribute: attribute.to_string(),
+            values: into_values(value),
+        }
     }
 
     /// Filter resources where [attribute] is not equal to [value].
     /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::not_equal("title", json!(vec!["Iron Man"])),
+    /// Query::not_equal("title", json!(vec!["Iron Man"])).to_string(),
     /// r#"{"method":"notEqual","attribute":"title","values":["Iron Man"]}"#
     /// );
     /// ```
-    pub fn not_equal(attribute: &str, value: Value) -> String {
-        QueryString::new("notEqual", Some(attribute.to_string()), Some(value)).to_string()
+    pub fn not_equal(attribute: &str, value: Value) -> Self {
+        Self::NotEqual {
+            attribute: attribute.to_string(),
+            values: into_values(value),
+        }
     }
 
     /// Filter resources where [attribute] is less than [value].
     /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::less_than("score", json!(10)),
+    /// Query::less_than("score", json!(10)).to_string(),
     /// r#"{"method":"lessThan","attribute":"score","values":[10]}"#
     /// );
     /// ```
-    pub fn less_than(attribute: &str, value: Value) -> String {
-        QueryDynamic::new("lessThan", Some(attribute.to_string()), Some(vec![value])).to_string()
+    pub fn less_than(attribute: &str, value: Value) -> Self {
+        Self::LessThan {
+            attribute: attribute.to_string(),
+            value,
+        }
     }
 
     /// Filter resources where [attribute] is less than or equal to [value].
     /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::less_than_equal("score", json!(10)),
+    /// Query::less_than_equal("score", json!(10)).to_string(),
     /// r#"{"method":"lessThanEqual","attribute":"score","values":[10]}"#
     /// );
     /// ```
-    pub fn less_than_equal(attribute: &str, value: Value) -> String {
-        QueryDynamic::new(
-            "lessThanEqual",
-            Some(attribute.to_string()),
-            Some(vec![value]),
-        )
-        .to_string()
+    pub fn less_than_equal(attribute: &str, value: Value) -> Self {
+        Self::LessThanEqual {
+            attribute: attribute.to_string(),
+            value,
+        }
     }
 
     /// Filter resources where [attribute] is greater than [value].
     /// ```
-    ///  assert_eq!(
-    /// Query::greater_than("score", json!(10)),
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::greater_than("score", json!(10)).to_string(),
     /// r#"{"method":"greaterThan","attribute":"score","values":[10]}"#
     /// );
     /// ```
-    pub fn greater_than(attribute: &str, value: Value) -> String {
-        QueryDynamic::new(
-            "greaterThan",
-            Some(attribute.to_string()),
-            Some(vec![value]),
-        )
-        .to_string()
+    pub fn greater_than(attribute: &str, value: Value) -> Self {
+        Self::GreaterThan {
+            attribute: attribute.to_string(),
+            value,
+        }
     }
 
     /// Filter resources where [attribute] is greater than or equal to [value].
     /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::greater_than_equal("score", json!(10)),
+    /// Query::greater_than_equal("score", json!(10)).to_string(),
     /// r#"{"method":"greaterThanEqual","attribute":"score","values":[10]}"#
     /// );
     /// ```
-    pub fn greater_than_equal(attribute: &str, value: Value) -> String {
-        QueryDynamic::new(
-            "greaterThanEqual",
-            Some(attribute.to_string()),
-            Some(vec![value]),
-        )
-        .to_string()
+    pub fn greater_than_equal(attribute: &str, value: Value) -> Self {
+        Self::GreaterThanEqual {
+            attribute: attribute.to_string(),
+            value,
+        }
     }
 
     /// Filter resources where by searching [attribute] for [value].
     /// ```
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::search("text", "key words"),
+    /// Query::search("text", "key words").to_string(),
     /// r#"{"method":"search","attribute":"text","values":["key words"]}"#
     /// );
     /// ```
-    pub fn search(attribute: &str, value: &str) -> String {
-        QueryString::new(
-            "search",
-            Some(attribute.to_string()),
-            Some(json!(vec![value])),
-        )
-        .to_string()
+    pub fn search(attribute: &str, value: &str) -> Self {
+        Self::Search {
+            attribute: attribute.to_string(),
+            value: value.to_string(),
+        }
     }
 
     /// Filter resources where [attribute] is null.
     /// ```
-    ///  assert_eq!(
-    /// Query::is_null("name"),
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::is_null("name").to_string(),
     /// r#"{"method":"isNull","attribute":"name"}"#
     /// );
     /// ```
-    pub fn is_null(attribute: &str) -> String {
-        QueryString::new("isNull", Some(attribute.to_string()), None).to_string()
+    pub fn is_null(attribute: &str) -> Self {
+        Self::IsNull {
+            attribute: attribute.to_string(),
+        }
     }
 
     /// Filter resources where [attribute] is not null.
     /// ```
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::is_not_null("name"),
+    /// Query::is_not_null("name").to_string(),
     /// r#"{"method":"isNotNull","attribute":"name"}"#
     /// );
     /// ```
-    pub fn is_not_null(attribute: &str) -> String {
-        QueryString::new("isNotNull", Some(attribute.to_string()), None).to_string()
+    pub fn is_not_null(attribute: &str) -> Self {
+        Self::IsNotNull {
+            attribute: attribute.to_string(),
+        }
     }
 
     /// Filter resources where [attribute] is between [start] and [end] (inclusive).
     /// ```
-    ///  assert_eq!(
-    /// Query::between("price", json!(5), json!(10)),
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::between("price", json!(5), json!(10)).to_string(),
     /// r#"{"method":"between","attribute":"price","values":[5,10]}"#
     /// );
     /// ```
-    pub fn between(attribute: &str, start: Value, end: Value) -> String {
-        QueryDynamic::new(
-            "between",
-            Some(attribute.to_string()),
-            Some(vec![start, end]),
-        )
-        .to_string()
+    pub fn between(attribute: &str, start: Value, end: Value) -> Self {
+        Self::Between {
+            attribute: attribute.to_string(),
+            start,
+            end,
+        }
     }
 
     /// Filter resources where [attribute] starts with [value].
     /// ```
-    ///  assert_eq!(
-    /// Query::starts_with("name", "Once upon a time"),
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::starts_with("name", "Once upon a time").to_string(),
     /// r#"{"method":"startsWith","attribute":"name","values":["Once upon a time"]}"#
     /// );
     /// ```
-    pub fn starts_with(attribute: &str, value: &str) -> String {
-        QueryString::new(
-            "startsWith",
-            Some(attribute.to_string()),
-            Some(json!(vec![value])),
-        )
-        .to_string()
+    pub fn starts_with(attribute: &str, value: &str) -> Self {
+        Self::StartsWith {
+            attribute: attribute.to_string(),
+            value: value.to_string(),
+        }
     }
 
     /// Filter resources where [attribute] ends with [value].
     /// ```
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::ends_with("name", "happily ever after."),
+    /// Query::ends_with("name", "happily ever after.").to_string(),
     /// r#"{"method":"endsWith","attribute":"name","values":["happily ever after."]}"#
     /// );
     /// ```
-    pub fn ends_with(attribute: &str, value: &str) -> String {
-        QueryString::new(
-            "endsWith",
-            Some(attribute.to_string()),
-            Some(json!(vec![value])),
-        )
-        .to_string()
+    pub fn ends_with(attribute: &str, value: &str) -> Self {
+        Self::EndsWith {
+            attribute: attribute.to_string(),
+            value: value.to_string(),
+        }
     }
 
     /// Filter resources where [attribute] contains [value]
     /// [value] can be a single value or a list.
     /// ```
-    ///  assert_eq!(
-    /// Query::contains("ingredients", json!(vec!["apple", "banana"])),
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::contains("ingredients", json!(vec!["apple", "banana"])).to_string(),
     /// r#"{"method":"contains","attribute":"ingredients","values":["apple","banana"]}"#
     /// );
     /// ```
-    /// or
+    pub fn contains(attribute: &str, value: Value) -> Self {
+        Self::Contains {
+            attribute: attribute.to_string(),
+            values: into_values(value),
+        }
+    }
+
+    /// Filter resources where [attribute] does not contain [value].
+    /// [value] can be a single value or a list.
     /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::contains("ingredients", json!(vec!["apple", "banana"])),
-    /// r#"{"method":"contains","attribute":"ingredients","values":["apple","banana"]}"#
+    /// Query::not_contains("ingredients", json!(vec!["apple", "banana"])).to_string(),
+    /// r#"{"method":"notContains","attribute":"ingredients","values":["apple","banana"]}"#
+    /// );
+    /// ```
+    pub fn not_contains(attribute: &str, value: Value) -> Self {
+        Self::NotContains {
+            attribute: attribute.to_string(),
+            values: into_values(value),
+        }
+    }
+
+    /// Filter resources where searching [attribute] for [value] finds nothing.
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::not_search("text", "key words").to_string(),
+    /// r#"{"method":"notSearch","attribute":"text","values":["key words"]}"#
     /// );
     /// ```
-    pub fn contains(attribute: &str, value: Value) -> String {
-        QueryString::new("contains", Some(attribute.to_string()), Some(value)).to_string()
+    pub fn not_search(attribute: &str, value: &str) -> Self {
+        Self::NotSearch {
+            attribute: attribute.to_string(),
+            value: value.to_string(),
+        }
     }
 
+    /// Filter resources where [attribute] is not between [start] and [end] (inclusive).
+    /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::not_between("price", json!(5), json!(10)).to_string(),
+    /// r#"{"method":"notBetween","attribute":"price","values":[5,10]}"#
+    /// );
+    /// ```
+    pub fn not_between(attribute: &str, start: Value, end: Value) -> Self {
+        Self::NotBetween {
+            attribute: attribute.to_string(),
+            start,
+            end,
+        }
+    }
+
+    /// Filter resources where [attribute] does not start with [value].
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::not_starts_with("name", "Once upon a time").to_string(),
+    /// r#"{"method":"notStartsWith","attribute":"name","values":["Once upon a time"]}"#
+    /// );
+    /// ```
+    pub fn not_starts_with(attribute: &str, value: &str) -> Self {
+        Self::NotStartsWith {
+            attribute: attribute.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Filter resources where [attribute] does not end with [value].
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::not_ends_with("name", "happily ever after.").to_string(),
+    /// r#"{"method":"notEndsWith","attribute":"name","values":["happily ever after."]}"#
+    /// );
+    /// ```
+    pub fn not_ends_with(attribute: &str, value: &str) -> Self {
+        Self::NotEndsWith {
+            attribute: attribute.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Filter resources created before [value], an ISO 8601 date.
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::created_before("2024-01-01T00:00:00.000+00:00").to_string(),
+    /// r#"{"method":"createdBefore","values":["2024-01-01T00:00:00.000+00:00"]}"#
+    /// );
+    /// ```
+    pub fn created_before(value: &str) -> Self {
+        Self::CreatedBefore(value.to_string())
+    }
+
+    /// Filter resources created after [value], an ISO 8601 date.
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::created_after("2024-01-01T00:00:00.000+00:00").to_string(),
+    /// r#"{"method":"createdAfter","values":["2024-01-01T00:00:00.000+00:00"]}"#
+    /// );
+    /// ```
+    pub fn created_after(value: &str) -> Self {
+        Self::CreatedAfter(value.to_string())
+    }
+
+    /// Filter resources last updated before [value], an ISO 8601 date.
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::updated_before("2024-01-01T00:00:00.000+00:00").to_string(),
+    /// r#"{"method":"updatedBefore","values":["2024-01-01T00:00:00.000+00:00"]}"#
+    /// );
+    /// ```
+    pub fn updated_before(value: &str) -> Self {
+        Self::UpdatedBefore(value.to_string())
+    }
+
+    /// Filter resources last updated after [value], an ISO 8601 date.
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::updated_after("2024-01-01T00:00:00.000+00:00").to_string(),
+    /// r#"{"method":"updatedAfter","values":["2024-01-01T00:00:00.000+00:00"]}"#
+    /// );
+    /// ```
+    pub fn updated_after(value: &str) -> Self {
+        Self::UpdatedAfter(value.to_string())
+    }
+
+    /// Match resources matching any of [queries]. Raw query strings are
+    /// accepted as well.
     ///```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
     /// Query::or(vec![
     ///     Query::less_than("size", json!(5)),
     ///     Query::greater_than("size", json!(10))
-    /// ]),
+    /// ]).to_string(),
     /// r#"{"method":"or","values":[{"attribute":"size","method":"lessThan","values":[5]},{"attribute":"size","method":"greaterThan","values":[10]}]}"#
     /// );
     /// ```
-    pub fn or(queries: Vec<String>) -> String {
-        let parsed_queries: Vec<serde_json::Value> = queries
-            .iter()
-            .map(|q| serde_json::from_str(q).unwrap())
-            .collect();
-        QueryDynamic::new("or", None, Some(parsed_queries)).to_string()
+    pub fn or(queries: impl IntoIterator<Item = impl Into<Query>>) -> Self {
+        Self::Or(queries.into_iter().map(Into::into).collect())
     }
 
+    /// Match resources matching all of [queries]. Raw query strings are
+    /// accepted as well.
     ///```
-    ///  assert_eq!(
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
     /// Query::and(vec![
     ///     Query::less_than("size", json!(10)),
     ///     Query::greater_than("size", json!(5))
-    /// ]),
+    /// ]).to_string(),
     /// r#"{"method":"and","values":[{"attribute":"size","method":"lessThan","values":[10]},{"attribute":"size","method":"greaterThan","values":[5]}]}"#
     /// );
     /// ```
-    pub fn and(queries: Vec<String>) -> String {
-        let parsed_queries: Vec<serde_json::Value> = queries
-            .iter()
-            .map(|q| serde_json::from_str(q).unwrap())
-            .collect();
-        QueryDynamic::new("and", None, Some(parsed_queries)).to_string()
+    pub fn and(queries: impl IntoIterator<Item = impl Into<Query>>) -> Self {
+        Self::And(queries.into_iter().map(Into::into).collect())
     }
 
     /// Specify which attributes should be returned by the API call.
-    pub fn select(attributes: Vec<&str>) -> String {
-        QueryDynamic::new(
-            "select",
-            None,
-            Some(
-                attributes
-                    .iter()
-                    .map(|attr| serde_json::Value::String(attr.to_string()))
-                    .collect(),
-            ),
-        )
-        .to_string()
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::select(vec!["name", "age"]).to_string(),
+    /// r#"{"method":"select","values":["name","age"]}"#
+    /// );
+    /// ```
+    pub fn select(attributes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Select(attributes.into_iter().map(Into::into).collect())
     }
 
     /// Sort results by [attribute] ascending.
     /// ```
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::order_asc("attribute"),
+    /// Query::order_asc("attribute").to_string(),
     /// r#"{"method":"orderAsc","attribute":"attribute"}"#
     /// );
     /// ```
-    pub fn order_asc(attribute: &str) -> String {
-        QueryDynamic::new("orderAsc", Some(attribute.to_string()), None).to_string()
+    pub fn order_asc(attribute: &str) -> Self {
+        Self::OrderAsc(attribute.to_string())
     }
 
     /// Sort results by [attribute] descending.
     /// ```
-    ///   assert_eq!(
-    /// Query::order_desc("attribute"),
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::order_desc("attribute").to_string(),
     /// r#"{"method":"orderDesc","attribute":"attribute"}"#
     /// );
     /// ```
-    pub fn order_desc(attribute: &str) -> String {
-        QueryDynamic::new("orderDesc", Some(attribute.to_string()), None).to_string()
+    pub fn order_desc(attribute: &str) -> Self {
+        Self::OrderDesc(attribute.to_string())
+    }
+
+    /// Sort results randomly.
+    /// ```
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(Query::order_random().to_string(), r#"{"method":"orderRandom"}"#);
+    /// ```
+    pub fn order_random() -> Self {
+        Self::OrderRandom
     }
 
     /// Return results before [id].
@@ -323,13 +594,14 @@ impl Query {
     /// Refer to the [Cursor Based Pagination](https://appwrite.io/docs/pagination#cursor-pagination)
     /// docs for more information.
     /// ```
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::cursor_before("62a7...a600"),
+    /// Query::cursor_before("62a7...a600").to_string(),
     /// r#"{"method":"cursorBefore","values":["62a7...a600"]}"#
     /// );
     /// ```
-    pub fn cursor_before(id: &str) -> String {
-        QueryString::new("cursorBefore", None, Some(json!(vec![id]))).to_string()
+    pub fn cursor_before(id: &str) -> Self {
+        Self::CursorBefore(id.to_string())
     }
 
     /// Return results after [id].
@@ -337,21 +609,23 @@ impl Query {
     /// Refer to the [Cursor Based Pagination](https://appwrite.io/docs/pagination#cursor-pagination)
     /// docs for more information.
     /// ```
+    /// # use unofficial_appwrite::query::Query;
     /// assert_eq!(
-    /// Query::cursor_after("62a7...f620"),
+    /// Query::cursor_after("62a7...f620").to_string(),
     /// r#"{"method":"cursorAfter","values":["62a7...f620"]}"#
     /// );
     /// ```
-    pub fn cursor_after(id: &str) -> String {
-        QueryString::new("cursorAfter", None, Some(json!(vec![id]))).to_string()
+    pub fn cursor_after(id: &str) -> Self {
+        Self::CursorAfter(id.to_string())
     }
 
     /// Return only [limit] results.
     /// ```
-    /// assert_eq!(Query::limit(25), r#"{"method":"limit","values":[25]}"#);
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(Query::limit(25).to_string(), r#"{"method":"limit","values":[25]}"#);
     /// ```
-    pub fn limit(limit: usize) -> String {
-        QueryString::new("limit", None, Some(json!(vec![limit]))).to_string()
+    pub fn limit(limit: usize) -> Self {
+        Self::Limit(limit)
     }
 
     /// Return results from [offset].
@@ -359,10 +633,478 @@ impl Query {
     /// Refer to the [Offset Pagination](https://appwrite.io/docs/pagination#offset-pagination)
     /// docs for more information.
     /// ```
-    /// assert_eq!(Query::offset(0), r#"{"method":"offset","values":[0]}"#);
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(Query::offset(0).to_string(), r#"{"method":"offset","values":[0]}"#);
+    /// ```
+    pub fn offset(offset: usize) -> Self {
+        Self::Offset(offset)
+    }
+
+    /// The query in the Appwrite 1.4 syntax, see [`QuerySyntax::Legacy`].
+    /// ```
+    /// # use serde_json::json;
+    /// # use unofficial_appwrite::query::Query;
+    /// assert_eq!(
+    /// Query::equal("title", json!(["Iron Man"])).to_legacy_string().unwrap().to_string(),
+    /// r#"equal("title", ["Iron Man"])"#
+    /// );
     /// ```
-    pub fn offset(offset: usize) -> String {
-        QueryString::new("offset", None, Some(json!(vec![offset]))).to_string()
+    pub fn to_legacy_string(&self) -> Result<String, Error> {
+        let quote = |text: &str| json!(text).to_string();
+        let list = |values: &[Value]| {
+            let values = values.iter().map(Value::to_string).collect::<Vec<_>>();
+            format!("[{}]", values.join(","))
+        };
+        let method = self.method().unwrap_or_default();
+        Ok(match self {
+            Self::Equal { attribute, values }
+            | Self::NotEqual { attribute, values }
+            | Self::Contains { attribute, values } => {
+                format!("{method}({}, {})", quote(attribute), list(values))
+            }
+            Self::LessThan { attribute, value }
+            | Self::LessThanEqual { attribute, value }
+            | Self::GreaterThan { attribute, value }
+            | Self::GreaterThanEqual { attribute, value } => {
+                format!("{method}({}, [{value}])", quote(attribute))
+            }
+            Self::Search { attribute, value }
+            | Self::StartsWith { attribute, value }
+            | Self::EndsWith { attribute, value } => {
+                format!("{method}({}, [{}])", quote(attribute), quote(value))
+            }
+            Self::Between {
+                attribute,
+                start,
+                end,
+            } => format!("{method}({}, {start}, {end})", quote(attribute)),
+            Self::IsNull { attribute }
+            | Self::IsNotNull { attribute }
+            | Self::OrderAsc(attribute)
+            | Self::OrderDesc(attribute) => format!("{method}({})", quote(attribute)),
+            Self::Select(attributes) => {
+                let attributes = attributes.iter().map(|a| quote(a)).collect::<Vec<_>>();
+                format!("{method}([{}])", attributes.join(","))
+            }
+            Self::CursorBefore(id) | Self::CursorAfter(id) => format!("{method}({})", quote(id)),
+            Self::Limit(n) | Self::Offset(n) => format!("{method}({n})"),
+            Self::Raw(raw) => raw.clone(),
+            _ => {
+                return Err(Error::InvalidParameter {
+                    param: "queries",
+                    reason: format!("`{method}` isn't supported by the legacy query syntax"),
+                })
+            }
+        })
+    }
+
+    /// A query string sent as is, without parsing or validation.
+    pub fn raw(query: impl Into<String>) -> Self {
+        Self::Raw(query.into())
+    }
+
+    /// The query method, e.g. `equal` or `limit`. `None` for [`Query::Raw`].
+    pub fn method(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Equal { .. } => "equal",
+            Self::NotEqual { .. } => "notEqual",
+            Self::LessThan { .. } => "lessThan",
+            Self::LessThanEqual { .. } => "lessThanEqual",
+            Self::GreaterThan { .. } => "greaterThan",
+            Self::GreaterThanEqual { .. } => "greaterThanEqual",
+            Self::Between { .. } => "between",
+            Self::Search { .. } => "search",
+            Self::StartsWith { .. } => "startsWith",
+            Self::EndsWith { .. } => "endsWith",
+            Self::Contains { .. } => "contains",
+            Self::IsNull { .. } => "isNull",
+            Self::IsNotNull { .. } => "isNotNull",
+            Self::NotContains { .. } => "notContains",
+            Self::NotSearch { .. } => "notSearch",
+            Self::NotBetween { .. } => "notBetween",
+            Self::NotStartsWith { .. } => "notStartsWith",
+            Self::NotEndsWith { .. } => "notEndsWith",
+            Self::CreatedBefore(_) => "createdBefore",
+            Self::CreatedAfter(_) => "createdAfter",
+            Self::UpdatedBefore(_) => "updatedBefore",
+            Self::UpdatedAfter(_) => "updatedAfter",
+            Self::And(_) => "and",
+            Self::Or(_) => "or",
+            Self::Select(_) => "select",
+            Self::OrderAsc(_) => "orderAsc",
+            Self::OrderDesc(_) => "orderDesc",
+            Self::OrderRandom => "orderRandom",
+            Self::CursorBefore(_) => "cursorBefore",
+            Self::CursorAfter(_) => "cursorAfter",
+            Self::Limit(_) => "limit",
+            Self::Offset(_) => "offset",
+            Self::Raw(_) => return None,
+        })
+    }
+
+    /// The attribute a filter or order query applies to.
+    pub fn attribute(&self) -> Option<&str> {
+        match self {
+            Self::Equal { attribute, .. }
+            | Self::NotEqual { attribute, .. }
+            | Self::LessThan { attribute, .. }
+            | Self::LessThanEqual { attribute, .. }
+            | Self::GreaterThan { attribute, .. }
+            | Self::GreaterThanEqual { attribute, .. }
+            | Self::Between { attribute, .. }
+            | Self::Search { attribute, .. }
+            | Self::StartsWith { attribute, .. }
+            | Self::EndsWith { attribute, .. }
+            | Self::Contains { attribute, .. }
+            | Self::IsNull { attribute }
+            | Self::IsNotNull { attribute }
+            | Self::NotContains { attribute, .. }
+            | Self::NotSearch { attribute, .. }
+            | Self::NotBetween { attribute, .. }
+            | Self::NotStartsWith { attribute, .. }
+            | Self::NotEndsWith { attribute, .. }
+            | Self::OrderAsc(attribute)
+            | Self::OrderDesc(attribute) => Some(attribute),
+            _ => None,
+        }
+    }
+
+    /// Whether this query filters resources, i.e. may appear inside
+    /// [`Query::and`] / [`Query::or`].
+    pub fn is_filter(&self) -> bool {
+        !matches!(
+            self,
+            Self::Select(_)
+                | Self::OrderAsc(_)
+                | Self::OrderDesc(_)
+                | Self::OrderRandom
+                | Self::CursorBefore(_)
+                | Self::CursorAfter(_)
+                | Self::Limit(_)
+                | Self::Offset(_)
+        )
+    }
+
+    /// Check the query the way the server would, without sending it.
+    /// [`Query::Raw`] queries are not checked.
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |reason: String| {
+            Err(Error::InvalidParameter {
+                param: "queries",
+                reason,
+            })
+        };
+        let method = self.method().unwrap_or_default();
+        if self
+            .attribute()
+            .is_some_and(|attribute| attribute.is_empty())
+        {
+            return invalid(format!("`{method}` needs an attribute"));
+        }
+        match self {
+            Self::Equal { values, .. }
+            | Self::NotEqual { values, .. }
+            | Self::Contains { values, .. }
+            | Self::NotContains { values, .. }
+                if values.is_empty() =>
+            {
+                invalid(format!("`{method}` needs at least one value"))
+            }
+            Self::And(queries) | Self::Or(queries) => {
+                if queries.len() < 2 {
+                    return invalid(format!("`{method}` needs at least two queries"));
+                }
+                for query in queries {
+                    if !query.is_filter() {
+                        return invalid(format!(
+                            "`{}` can't be nested in `{method}`",
+                            query.method().unwrap_or_default()
+                        ));
+                    }
+                    query.validate()?;
+                }
+                Ok(())
+            }
+            Self::Select(attributes) if attributes.is_empty() => {
+                invalid(String::from("`select` needs at least one attribute"))
+            }
+            Self::Select(attributes) if attributes.iter().any(String::is_empty) => {
+                invalid(String::from("`select` attributes can't be empty"))
+            }
+            Self::CursorBefore(id) | Self::CursorAfter(id) if id.is_empty() => {
+                invalid(format!("`{method}` needs a document ID"))
+            }
+            Self::CreatedBefore(date)
+            | Self::CreatedAfter(date)
+            | Self::UpdatedBefore(date)
+            | Self::UpdatedAfter(date)
+                if date.is_empty() =>
+            {
+                invalid(format!("`{method}` needs a date"))
+            }
+            Self::Limit(0) => invalid(String::from("`limit` must be positive")),
+            _ => Ok(()),
+        }
+    }
+
+    fn to_json(&self) -> QueryJson {
+        let one = |value: &Value| Some(vec![value.clone()]);
+        let text = |value: &str| Some(vec![json!(value)]);
+        let attribute = self.attribute();
+        let values = match self {
+            Self::Equal { values, .. }
+            | Self::NotEqual { values, .. }
+            | Self::Contains { values, .. }
+            | Self::NotContains { values, .. } => Some(values.clone()),
+            Self::LessThan { value, .. }
+            | Self::LessThanEqual { value, .. }
+            | Self::GreaterThan { value, .. }
+            | Self::GreaterThanEqual { value, .. } => one(value),
+            Self::Between { start, end, .. } | Self::NotBetween { start, end, .. } => {
+                Some(vec![start.clone(), end.clone()])
+            }
+            Self::Search { value, .. }
+            | Self::StartsWith { value, .. }
+            | Self::EndsWith { value, .. }
+            | Self::NotSearch { value, .. }
+            | Self::NotStartsWith { value, .. }
+            | Self::NotEndsWith { value, .. } => text(value),
+            Self::CreatedBefore(date)
+            | Self::CreatedAfter(date)
+            | Self::UpdatedBefore(date)
+            | Self::UpdatedAfter(date) => text(date),
+            Self::And(queries) | Self::Or(queries) => {
+                Some(queries.iter().map(Query::to_value).collect())
+            }
+            Self::Select(attributes) => Some(attributes.iter().map(|a| json!(a)).collect()),
+            Self::CursorBefore(id) | Self::CursorAfter(id) => text(id),
+            Self::Limit(n) | Self::Offset(n) => Some(vec![json!(n)]),
+            Self::IsNull { .. }
+            | Self::IsNotNull { .. }
+            | Self::OrderAsc(_)
+            | Self::OrderDesc(_)
+            | Self::OrderRandom
+            | Self::Raw(_) => None,
+        };
+        QueryJson::new(self.method().unwrap_or_default(), attribute, values)
+    }
+
+    /// The query as a JSON value, as nested in `and` / `or`.
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Raw(raw) => serde_json::from_str(raw).unwrap_or_else(|_| json!(raw)),
+            query => serde_json::to_value(query.to_json()).unwrap_or_default(),
+        }
+    }
+
+    fn from_json(query: QueryJson) -> Result<Self, Error> {
+        let QueryJson {
+            method,
+            attribute,
+            values,
+        } = query;
+        let invalid = |reason: &str| Error::InvalidParameter {
+            param: "queries",
+            reason: format!("`{method}` {reason}"),
+        };
+        let attribute = || {
+            attribute
+                .clone()
+                .ok_or_else(|| invalid("needs an attribute"))
+        };
+        let values = values.unwrap_or_default();
+        let value = || match values.as_slice() {
+            [value] => Ok(value.clone()),
+            _ => Err(invalid("needs exactly one value")),
+        };
+        let text = || match value()? {
+            Value::String(value) => Ok(value),
+            _ => Err(invalid("needs a string value")),
+        };
+        let count = || match value()? {
+            Value::Number(n) => n
+                .as_u64()
+                .map(|n| n as usize)
+                .ok_or_else(|| invalid("needs a non-negative integer")),
+            _ => Err(invalid("needs an integer value")),
+        };
+        let queries = || {
+            values
+                .iter()
+                .map(|value| match value {
+                    Value::String(query) => query.parse(),
+                    value => Self::from_json(serde_json::from_value(value.clone())?),
+                })
+                .collect::<Result<Vec<_>, Error>>()
+        };
+        Ok(match method.as_str() {
+            "equal" => Self::Equal {
+                attribute: attribute()?,
+                values: values.clone(),
+            },
+            "notEqual" => Self::NotEqual {
+                attribute: attribute()?,
+                values: values.clone(),
+            },
+            "contains" => Self::Contains {
+                attribute: attribute()?,
+                values: values.clone(),
+            },
+            "lessThan" => Self::LessThan {
+                attribute: attribute()?,
+                value: value()?,
+            },
+            "lessThanEqual" => Self::LessThanEqual {
+                attribute: attribute()?,
+                value: value()?,
+            },
+            "greaterThan" => Self::GreaterThan {
+                attribute: attribute()?,
+                value: value()?,
+            },
+            "greaterThanEqual" => Self::GreaterThanEqual {
+                attribute: attribute()?,
+                value: value()?,
+            },
+            "between" => match values.as_slice() {
+                [start, end] => Self::Between {
+                    attribute: attribute()?,
+                    start: start.clone(),
+                    end: end.clone(),
+                },
+                _ => return Err(invalid("needs exactly two values")),
+            },
+            "search" => Self::Search {
+                attribute: attribute()?,
+                value: text()?,
+            },
+            "startsWith" => Self::StartsWith {
+                attribute: attribute()?,
+                value: text()?,
+            },
+            "endsWith" => Self::EndsWith {
+                attribute: attribute()?,
+                value: text()?,
+            },
+            "notContains" => Self::NotContains {
+                attribute: attribute()?,
+                values: values.clone(),
+            },
+            "notSearch" => Self::NotSearch {
+                attribute: attribute()?,
+                value: text()?,
+            },
+            "notBetween" => match values.as_slice() {
+                [start, end] => Self::NotBetween {
+                    attribute: attribute()?,
+                    start: start.clone(),
+                    end: end.clone(),
+                },
+                _ => return Err(invalid("needs exactly two values")),
+            },
+            "notStartsWith" => Self::NotStartsWith {
+                attribute: attribute()?,
+                value: text()?,
+            },
+            "notEndsWith" => Self::NotEndsWith {
+                attribute: attribute()?,
+                value: text()?,
+            },
+            "createdBefore" => Self::CreatedBefore(text()?),
+            "createdAfter" => Self::CreatedAfter(text()?),
+            "updatedBefore" => Self::UpdatedBefore(text()?),
+            "updatedAfter" => Self::UpdatedAfter(text()?),
+            "isNull" => Self::IsNull {
+                attribute: attribute()?,
+            },
+            "isNotNull" => Self::IsNotNull {
+                attribute: attribute()?,
+            },
+            "and" => Self::And(queries()?),
+            "or" => Self::Or(queries()?),
+            "select" => Self::Select(
+                values
+                    .iter()
+                    .map(|value| value.as_str().map(str::to_string))
+                    .collect::<Option<_>>()
+                    .ok_or_else(|| invalid("needs string attributes"))?,
+            ),
+            "orderAsc" => Self::OrderAsc(attribute()?),
+            "orderDesc" => Self::OrderDesc(attribute()?),
+            "orderRandom" => Self::OrderRandom,
+            "cursorBefore" => Self::CursorBefore(text()?),
+            "cursorAfter" => Self::CursorAfter(text()?),
+            "limit" => Self::Limit(count()?),
+            "offset" => Self::Offset(count()?),
+            _ => return Err(invalid("is not a known query method")),
+        })
+    }
+}
+
+/// Rewrite the `queries` of request `params` in the legacy syntax.
+/// [`Query::Raw`] strings are left alone.
+pub(crate) fn legacy_queries(params: &mut Value) -> Result<(), Error> {
+    let Some(Value::Array(queries)) = params.get_mut("queries") else {
+        return Ok(());
+    };
+    for query in queries {
+        if let Value::String(text) = query {
+            *text = Query::from(text.as_str()).to_legacy_string()?;
+        }
+    }
+    Ok(())
+}
+
+/// Validate the queries passed to a list endpoint.
+pub(crate) fn check_queries(queries: &Option<Vec<Query>>) -> Result<(), Error> {
+    queries.iter().flatten().try_for_each(Query::validate)
+}
+
+impl FromStr for Query {
+    type Err = Error;
+
+    /// Parse the JSON form of a query.
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        Self::from_json(serde_json::from_str(query)?)
+    }
+}
+
+impl From<String> for Query {
+    /// Parse a query string, keeping it as [`Query::Raw`] if it can't be.
+    fn from(query: String) -> Self {
+        query.parse().unwrap_or(Self::Raw(query))
+    }
+}
+
+impl From<&str> for Query {
+    fn from(query: &str) -> Self {
+        Self::from(query.to_string())
+    }
+}
+
+impl fmt::Display for Query {
+    /// The JSON form of the query; pretty-printed with `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Self::Raw(raw) = self {
+            return f.write_str(raw);
+        }
+        let json = match f.alternate() {
+            true => serde_json::to_string_pretty(&self.to_json()),
+            false => serde_json::to_string(&self.to_json()),
+        };
+        f.write_str(&json.map_err(|_| fmt::Error)?)
+    }
+}
+
+impl Serialize for Query {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Query {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
     }
 }
 
@@ -373,115 +1115,304 @@ mod tests {
     #[test]
     fn test_all_query_at_once() {
         assert_eq!(
-            Query::equal("title", json!(vec!["bamboo", "ace"])),
+            Query::equal("title", json!(vec!["bamboo", "ace"])).to_string(),
             r#"{"method":"equal","attribute":"title","values":["bamboo","ace"]}"#
         );
         assert_eq!(
             Query::and(vec![
                 Query::less_than("size", json!(10)),
                 Query::greater_than("size", json!(5))
-            ]),
+            ])
+            .to_string(),
             r#"{"method":"and","values":[{"attribute":"size","method":"lessThan","values":[10]},{"attribute":"size","method":"greaterThan","values":[5]}]}"#
         );
         assert_eq!(
             Query::or(vec![
                 Query::less_than("size", json!(5)),
                 Query::greater_than("size", json!(10))
-            ]),
+            ])
+            .to_string(),
             r#"{"method":"or","values":[{"attribute":"size","method":"lessThan","values":[5]},{"attribute":"size","method":"greaterThan","values":[10]}]}"#
         );
         assert_eq!(
-            Query::equal("title", json!(vec!["Iron Man"])),
+            Query::equal("title", json!(vec!["Iron Man"])).to_string(),
             r#"{"method":"equal","attribute":"title","values":["Iron Man"]}"#
         );
         assert_eq!(
-            Query::not_equal("title", json!(vec!["Iron Man"])),
+            Query::not_equal("title", json!(vec!["Iron Man"])).to_string(),
             r#"{"method":"notEqual","attribute":"title","values":["Iron Man"]}"#
         );
         assert_eq!(
-            Query::less_than("score", json!(10)),
+            Query::less_than("score", json!(10)).to_string(),
             r#"{"method":"lessThan","attribute":"score","values":[10]}"#
         );
         assert_eq!(
-            Query::less_than_equal("score", json!(10)),
+            Query::less_than_equal("score", json!(10)).to_string(),
             r#"{"method":"lessThanEqual","attribute":"score","values":[10]}"#
         );
         assert_eq!(
-            Query::greater_than("score", json!(10)),
+            Query::greater_than("score", json!(10)).to_string(),
             r#"{"method":"greaterThan","attribute":"score","values":[10]}"#
         );
         assert_eq!(
-            Query::greater_than_equal("score", json!(10)),
+            Query::greater_than_equal("score", json!(10)).to_string(),
             r#"{"method":"greaterThanEqual","attribute":"score","values":[10]}"#
         );
         assert_eq!(
-            Query::between("price", json!(5), json!(10)),
+            Query::between("price", json!(5), json!(10)).to_string(),
             r#"{"method":"between","attribute":"price","values":[5,10]}"#
         );
         assert_eq!(
-            Query::is_null("name"),
+            Query::is_null("name").to_string(),
             r#"{"method":"isNull","attribute":"name"}"#
         );
         assert_eq!(
-            Query::is_not_null("name"),
+            Query::is_not_null("name").to_string(),
             r#"{"method":"isNotNull","attribute":"name"}"#
         );
         assert_eq!(
-            Query::starts_with("name", "Once upon a time"),
+            Query::starts_with("name", "Once upon a time").to_string(),
             r#"{"method":"startsWith","attribute":"name","values":["Once upon a time"]}"#
         );
         assert_eq!(
-            Query::ends_with("name", "happily ever after."),
+            Query::ends_with("name", "happily ever after.").to_string(),
             r#"{"method":"endsWith","attribute":"name","values":["happily ever after."]}"#
         );
         assert_eq!(
-            Query::contains("ingredients", json!(vec!["apple", "banana"])),
+            Query::contains("ingredients", json!(vec!["apple", "banana"])).to_string(),
             r#"{"method":"contains","attribute":"ingredients","values":["apple","banana"]}"#
         );
         assert_eq!(
-            Query::contains("name", json!(vec!["Tom"])),
+            Query::contains("name", json!(vec!["Tom"])).to_string(),
             r#"{"method":"contains","attribute":"name","values":["Tom"]}"#
         );
         assert_eq!(
-            Query::search("text", "key words"),
+            Query::search("text", "key words").to_string(),
             r#"{"method":"search","attribute":"text","values":["key words"]}"#
         );
         assert_eq!(
-            Query::order_desc("attribute"),
+            Query::order_desc("attribute").to_string(),
             r#"{"method":"orderDesc","attribute":"attribute"}"#
         );
         assert_eq!(
-            Query::order_asc("attribute"),
+            Query::order_asc("attribute").to_string(),
             r#"{"method":"orderAsc","attribute":"attribute"}"#
         );
-        assert_eq!(Query::limit(25), r#"{"method":"limit","values":[25]}"#);
-        assert_eq!(Query::offset(0), r#"{"method":"offset","values":[0]}"#);
         assert_eq!(
-            Query::cursor_after("62a7...f620"),
+            Query::limit(25).to_string(),
+            r#"{"method":"limit","values":[25]}"#
+        );
+        assert_eq!(
+            Query::offset(0).to_string(),
+            r#"{"method":"offset","values":[0]}"#
+        );
+        assert_eq!(
+            Query::cursor_after("62a7...f620").to_string(),
             r#"{"method":"cursorAfter","values":["62a7...f620"]}"#
         );
         assert_eq!(
-            Query::cursor_before("62a7...a600"),
+            Query::cursor_before("62a7...a600").to_string(),
             r#"{"method":"cursorBefore","values":["62a7...a600"]}"#
         );
     }
 
-    // #[test]
-    // fn test_select_query() {
-    //     let query = Query::select(vec!["name", "age"]);
-    //     assert_eq!(
-    //         query.to_string(),
-    //         r#"{"method":"select","values":["name","age"]}"#
-    //     );
-    // }
-
-    // #[test]
-    // fn test_or_query() {
-    //     let queries = vec![
-    //         r#"{"method":"equal","attribute":"name","values":["John"]}"#.to_string(),
-    //         r#"{"method":"equal","attribute":"age","values":[30]}"#.to_string(),
-    //     ];
-    //     let query = Query::or_queries(queries);
-    //     assert!(query.to_string().starts_with(r#"{"method":"or","values":[{"method":"equal"..."#));
-    // }
+    #[test]
+    fn test_select_query() {
+        let query = Query::select(vec!["name", "age"]);
+        assert_eq!(
+            query.to_string(),
+            r#"{"method":"select","values":["name","age"]}"#
+        );
+    }
+
+    #[test]
+    fn test_newer_operators() {
+        assert_eq!(
+            Query::contains("tags", json!(["rust", "appwrite", "sdk"])).to_string(),
+            r#"{"method":"contains","attribute":"tags","values":["rust","appwrite","sdk"]}"#
+        );
+        assert_eq!(
+            Query::not_contains("tags", json!(["draft", "spam"])).to_string(),
+            r#"{"method":"notContains","attribute":"tags","values":["draft","spam"]}"#
+        );
+        assert_eq!(
+            Query::not_search("text", "key words").to_string(),
+            r#"{"method":"notSearch","attribute":"text","values":["key words"]}"#
+        );
+        assert_eq!(
+            Query::not_between("price", json!(5), json!(10)).to_string(),
+            r#"{"method":"notBetween","attribute":"price","values":[5,10]}"#
+        );
+        assert_eq!(
+            Query::not_starts_with("name", "Once upon a time").to_string(),
+            r#"{"method":"notStartsWith","attribute":"name","values":["Once upon a time"]}"#
+        );
+        assert_eq!(
+            Query::not_ends_with("name", "happily ever after.").to_string(),
+            r#"{"method":"notEndsWith","attribute":"name","values":["happily ever after."]}"#
+        );
+        assert_eq!(
+            Query::created_before("2024-01-01T00:00:00.000+00:00").to_string(),
+            r#"{"method":"createdBefore","values":["2024-01-01T00:00:00.000+00:00"]}"#
+        );
+        assert_eq!(
+            Query::created_after("2024-01-01T00:00:00.000+00:00").to_string(),
+            r#"{"method":"createdAfter","values":["2024-01-01T00:00:00.000+00:00"]}"#
+        );
+        assert_eq!(
+            Query::updated_before("2024-01-01T00:00:00.000+00:00").to_string(),
+            r#"{"method":"updatedBefore","values":["2024-01-01T00:00:00.000+00:00"]}"#
+        );
+        assert_eq!(
+            Query::updated_after("2024-01-01T00:00:00.000+00:00").to_string(),
+            r#"{"method":"updatedAfter","values":["2024-01-01T00:00:00.000+00:00"]}"#
+        );
+        assert_eq!(
+            Query::order_random().to_string(),
+            r#"{"method":"orderRandom"}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let queries = [
+            Query::and(vec![
+                Query::equal("name", json!("John")),
+                Query::or(vec![
+                    Query::between("age", json!(18), json!(30)),
+                    Query::is_null("age"),
+                ]),
+            ]),
+            Query::select(vec!["name"]),
+            Query::order_desc("$createdAt"),
+            Query::order_random(),
+            Query::and(vec![
+                Query::not_contains("tags", json!(["draft", "spam"])),
+                Query::not_between("price", json!(5), json!(10)),
+                Query::created_after("2024-01-01T00:00:00.000+00:00"),
+            ]),
+            Query::cursor_after("62a7...f620"),
+            Query::limit(25),
+        ];
+        for query in queries {
+            assert_eq!(query.to_string().parse::<Query>().unwrap(), query);
+        }
+
+        let or = Query::or(vec![
+            r#"{"method":"equal","attribute":"name","values":["John"]}"#.to_string(),
+            r#"{"method":"equal","attribute":"age","values":[30]}"#.to_string(),
+        ]);
+        assert_eq!(
+            or,
+            Query::Or(vec![
+                Query::equal("name", json!(["John"])),
+                Query::equal("age", json!([30])),
+            ])
+        );
+
+        assert_eq!(Query::from("limit(25)"), Query::raw("limit(25)"));
+        assert!("limit(25)".parse::<Query>().is_err());
+        assert!(r#"{"method":"nearby","values":[]}"#.parse::<Query>().is_err());
+        assert_eq!(
+            serde_json::to_value(vec![Query::limit(1)]).unwrap(),
+            json!([r#"{"method":"limit","values":[1]}"#])
+        );
+    }
+
+    #[test]
+    fn test_string_queries() {
+        let queries = Some(vec![
+            Query::from(r#"{"method":"equal","attribute":"name","values":["John"]}"#),
+            Query::from("limit(25)"),
+        ]);
+        assert_eq!(
+            queries,
+            Some(vec![
+                Query::equal("name", json!(["John"])),
+                Query::raw("limit(25)")
+            ])
+        );
+        assert!(check_queries(&queries).is_ok());
+
+        let queries = Some(vec![Query::from(r#"{"method":"limit","values":[0]}"#)]);
+        assert!(check_queries(&queries).is_err());
+    }
+
+    #[test]
+    fn test_legacy_syntax() {
+        let legacy = |query: Query| query.to_legacy_string().unwrap();
+        assert_eq!(
+            legacy(Query::equal("title", json!(["Iron Man", "Thor"]))).to_string(),
+            r#"equal("title", ["Iron Man","Thor"])"#
+        );
+        assert_eq!(
+            legacy(Query::less_than("score", json!(10))).to_string(),
+            r#"lessThan("score", [10])"#
+        );
+        assert_eq!(
+            legacy(Query::search("text", "say \"hi\"")).to_string(),
+            r#"search("text", ["say \"hi\""])"#
+        );
+        assert_eq!(
+            legacy(Query::between("price", json!(5), json!(10))).to_string(),
+            r#"between("price", 5, 10)"#
+        );
+        assert_eq!(
+            legacy(Query::is_null("name")).to_string(),
+            r#"isNull("name")"#
+        );
+        assert_eq!(
+            legacy(Query::order_desc("year")).to_string(),
+            r#"orderDesc("year")"#
+        );
+        assert_eq!(
+            legacy(Query::select(vec!["name", "age"])).to_string(),
+            r#"select(["name","age"])"#
+        );
+        assert_eq!(
+            legacy(Query::cursor_after("62a7...f620")).to_string(),
+            r#"cursorAfter("62a7...f620")"#
+        );
+        assert_eq!(legacy(Query::limit(25)).to_string(), "limit(25)");
+        assert!(Query::or(vec![Query::is_null("a"), Query::is_null("b")])
+            .to_legacy_string()
+            .is_err());
+
+        let mut params = json!({
+            "queries": [Query::limit(25), Query::raw("offset(5)")],
+            "search": "limit(1)",
+        });
+        legacy_queries(&mut params).unwrap();
+        assert_eq!(
+            params,
+            json!({"queries": ["limit(25)", "offset(5)"], "search": "limit(1)"})
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Query::limit(25).validate().is_ok());
+        assert!(Query::raw("limit(0)").validate().is_ok());
+        for query in [
+            Query::limit(0),
+            Query::select(Vec::<String>::new()),
+            Query::equal("", json!("x")),
+            Query::equal("name", json!([])),
+            Query::or(vec![Query::is_null("name")]),
+            Query::and(vec![Query::is_null("name"), Query::limit(5)]),
+            Query::and(vec![Query::is_null("name"), Query::limit(0)]),
+            Query::cursor_after(""),
+        ] {
+            assert!(
+                matches!(
+                    query.validate(),
+                    Err(Error::InvalidParameter {
+                        param: "queries",
+                        ..
+                    })
+                ),
+                "{query} should be invalid"
+            );
+        }
+    }
 }
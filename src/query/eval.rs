@@ -0,0 +1,527 @@
+//! # Evaluation
+//!
+//! Run [`Query`]s locally against JSON values or [`Document`]s, e.g. to
+//! filter realtime events, back test fakes or answer reads from a cache.
+//!
+//! Evaluation follows the server where it can:
+//!
+//! - comparisons against a missing or `null` attribute never match, not even
+//!   negated ones (SQL `NULL` semantics); use [`Query::is_null`] for those;
+//! - scalar filters on an array attribute match if any element matches;
+//! - `search` matches documents containing any of the words, the last one as
+//!   a prefix, or the exact phrase when quoted, case-insensitively;
+//! - without a `limit` query at most [`DEFAULT_LIMIT`] documents are returned;
+//! - of several `limit`, `offset` or cursor queries the first one is used.
+//!
+//! String comparisons are case-sensitive, unlike a server using a
+//! case-insensitive collation.
+//!
+//! ```
+//! use serde_json::json;
+//! use unofficial_appwrite::query::{eval::evaluate, Query};
+//!
+//! let documents = vec![
+//!     json!({"$id": "1", "title": "Iron Man", "year": 2008}),
+//!     json!({"$id": "2", "title": "Thor", "year": 2011}),
+//!     json!({"$id": "3", "title": "Iron Man 2", "year": 2010}),
+//! ];
+//! let result = evaluate(
+//!     &[
+//!         Query::starts_with("title", "Iron"),
+//!         Query::order_desc("year"),
+//!         Query::limit(1),
+//!     ],
+//!     documents,
+//! )
+//! .unwrap();
+//! assert_eq!(result.total, 2);
+//! assert_eq!(result.documents[0]["$id"], "3");
+//! ```
+
+use std::{borrow::Cow, cmp::Ordering};
+
+use serde_json::Value;
+
+use crate::{
+    error::Error,
+    models::{document::Document, document_list::DocumentList},
+    query::Query,
+};
+
+/// The server's page size when no `limit` query is given.
+pub const DEFAULT_LIMIT: usize = 25;
+
+/// Something queries can be evaluated against.
+pub trait QueryTarget {
+    /// The document as a JSON object.
+    fn fields(&self) -> Cow<'_, Value>;
+
+    /// Keep only `attributes` and the `$`-prefixed system attributes.
+    fn select(&mut self, attributes: &[String]);
+}
+
+/// Whether `key` is kept by a `select` of `attributes`.
+fn is_selected(key: &str, attributes: &[String]) -> bool {
+    key.starts_with('$')
+        || attributes
+            .iter()
+            .any(|attribute| attribute == "*" || attribute.split('.').next() == Some(key))
+}
+
+impl QueryTarget for Value {
+    fn fields(&self) -> Cow<'_, Value> {
+        Cow::Borrowed(self)
+    }
+
+    fn select(&mut self, attributes: &[String]) {
+        if let Value::Object(map) = self {
+            map.retain(|key, _| is_selected(key, attributes));
+        }
+    }
+}
+
+impl QueryTarget for Document {
+    fn fields(&self) -> Cow<'_, Value> {
+        Cow::Owned(serde_json::to_value(self).unwrap_or_default())
+    }
+
+    fn select(&mut self, attributes: &[String]) {
+        self.data.retain(|key, _| is_selected(key, attributes));
+    }
+}
+
+/// The page of documents a list of queries selects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation<T> {
+    /// Number of documents matching the filters, before paging.
+    pub total: usize,
+    pub documents: Vec<T>,
+}
+
+impl From<Evaluation<Document>> for DocumentList {
+    fn from(evaluation: Evaluation<Document>) -> Self {
+        Self {
+            total: evaluation.total as u64,
+            documents: evaluation.documents,
+        }
+    }
+}
+
+impl Query {
+    /// Whether `document` passes this query. Queries that don't filter
+    /// (ordering, paging, `select`) match everything; unparsable
+    /// [`Query::Raw`] queries match nothing.
+    pub fn matches(&self, document: &impl QueryTarget) -> bool {
+        self.test(&document.fields()).unwrap_or(false)
+    }
+
+    /// Three-valued test: `None` when the outcome depends on a missing or
+    /// `null` attribute.
+    fn test(&self, document: &Value) -> Option<bool> {
+        let field = |attribute: &str| lookup(document, attribute);
+        let any = |attribute: &str, predicate: &dyn Fn(&Value) -> bool| {
+            field(attribute).map(|value| match value {
+                Value::Array(items) => items.iter().any(predicate),
+                value => predicate(value),
+            })
+        };
+        let text = |attribute: &str, predicate: &dyn Fn(&str) -> bool| {
+            any(attribute, &|value| value.as_str().is_some_and(predicate))
+        };
+        let ordered = |attribute: &str, value: &Value, expected: &[Ordering]| {
+            any(attribute, &|field| {
+                compare(field, value).is_some_and(|ordering| expected.contains(&ordering))
+            })
+        };
+        let between = |attribute: &str, start: &Value, end: &Value| {
+            any(attribute, &|field| {
+                compare(field, start).is_some_and(Ordering::is_ge)
+                    && compare(field, end).is_some_and(Ordering::is_le)
+            })
+        };
+        let date = |attribute: &str, date: &str, expected: Ordering| {
+            text(attribute, &|field| field.cmp(date) == expected)
+        };
+        let not = |outcome: Option<bool>| outcome.map(|outcome| !outcome);
+        match self {
+            Self::Equal { attribute, values } => {
+                any(attribute, &|field| values.iter().any(|v| equal(field, v)))
+            }
+            Self::NotEqual { attribute, values } => not(any(attribute, &|field| {
+                values.iter().any(|v| equal(field, v))
+            })),
+            Self::LessThan { attribute, value } => ordered(attribute, value, &[Ordering::Less]),
+            Self::LessThanEqual { attribute, value } => {
+                ordered(attribute, value, &[Ordering::Less, Ordering::Equal])
+            }
+            Self::GreaterThan { attribute, value } => {
+                ordered(attribute, value, &[Ordering::Greater])
+            }
+            Self::GreaterThanEqual { attribute, value } => {
+                ordered(attribute, value, &[Ordering::Greater, Ordering::Equal])
+            }
+            Self::Between {
+                attribute,
+                start,
+                end,
+            } => between(attribute, start, end),
+            Self::NotBetween {
+                attribute,
+                start,
+                end,
+            } => not(between(attribute, start, end)),
+            Self::Search { attribute, value } => text(attribute, &|field| search(field, value)),
+            Self::NotSearch { attribute, value } => {
+                not(text(attribute, &|field| search(field, value)))
+            }
+            Self::StartsWith { attribute, value } => {
+                text(attribute, &|field| field.starts_with(value.as_str()))
+            }
+            Self::NotStartsWith { attribute, value } => {
+                not(text(attribute, &|field| field.starts_with(value.as_str())))
+            }
+            Self::EndsWith { attribute, value } => {
+                text(attribute, &|field| field.ends_with(value.as_str()))
+            }
+            Self::NotEndsWith { attribute, value } => {
+                not(text(attribute, &|field| field.ends_with(value.as_str())))
+            }
+            Self::Contains { attribute, values } => {
+                field(attribute).map(|field| contains(field, values))
+            }
+            Self::NotContains { attribute, values } => {
+                field(attribute).map(|field| !contains(field, values))
+            }
+            Self::IsNull { attribute } => Some(field(attribute).is_none()),
+            Self::IsNotNull { attribute } => Some(field(attribute).is_some()),
+            Self::CreatedBefore(value) => date("$createdAt", value, Ordering::Less),
+            Self::CreatedAfter(value) => date("$createdAt", value, Ordering::Greater),
+            Self::UpdatedBefore(value) => date("$updatedAt", value, Ordering::Less),
+            Self::UpdatedAfter(value) => date("$updatedAt", value, Ordering::Greater),
+            Self::And(queries) => all(queries.iter().map(|query| query.test(document))),
+            Self::Or(queries) => not(all(queries.iter().map(|query| not(query.test(document))))),
+            Self::Raw(raw) => raw.parse::<Query>().ok()?.test(document),
+            Self::Select(_)
+            | Self::OrderAsc(_)
+            | Self::OrderDesc(_)
+            | Self::OrderRandom
+            | Self::CursorBefore(_)
+            | Self::CursorAfter(_)
+            | Self::Limit(_)
+            | Self::Offset(_) => Some(true),
+        }
+    }
+}
+
+/// Three-valued AND.
+fn all(outcomes: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+    let mut unknown = false;
+    for outcome in outcomes {
+        match outcome {
+            Some(false) => return Some(false),
+            None => unknown = true,
+            Some(true) => {}
+        }
+    }
+    (!unknown).then_some(true)
+}
+
+/// The value of `attribute`, following `.`-separated paths into nested
+/// objects. `None` if missing or `null`.
+fn lookup<'a>(document: &'a Value, attribute: &str) -> Option<&'a Value> {
+    let value = match document.get(attribute) {
+        Some(value) => value,
+        None => attribute
+            .split('.')
+            .try_fold(document, |value, key| value.get(key))?,
+    };
+    (!value.is_null()).then_some(value)
+}
+
+fn equal(a: &Value, b: &Value) -> bool {
+    compare(a, b) == Some(Ordering::Equal) || a == b
+}
+
+/// Order two scalars of the same kind; `None` if they can't be compared.
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+/// Order attribute values for sorting: `null` first, incomparable values
+/// as equal.
+fn compare_for_order(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => compare(a, b).unwrap_or(Ordering::Equal),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+fn contains(field: &Value, values: &[Value]) -> bool {
+    match field {
+        Value::Array(items) => values
+            .iter()
+            .any(|value| items.iter().any(|item| equal(item, value))),
+        Value::String(field) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|value| field.contains(value)),
+        _ => false,
+    }
+}
+
+/// Full-text search in boolean mode: any word matches, the last one as a
+/// prefix; a quoted value matches the exact phrase.
+fn search(field: &str, value: &str) -> bool {
+    let field = field.to_lowercase();
+    let value = value.trim().to_lowercase();
+    if let Some(phrase) = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    {
+        return !phrase.is_empty() && field.contains(phrase);
+    }
+    let words = |text: &str| {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    let (words, terms) = (words(&field), words(&value));
+    terms.iter().enumerate().any(|(index, term)| {
+        words.iter().any(|word| match index + 1 == terms.len() {
+            true => word.starts_with(term.as_str()),
+            false => word == term,
+        })
+    })
+}
+
+/// Filter, order, page and project `documents` the way a list endpoint
+/// would. Fails like the server on invalid queries, on [`Query::Raw`]
+/// queries that can't be parsed and on cursors naming an unknown document.
+pub fn evaluate<T: QueryTarget>(
+    queries: &[Query],
+    documents: impl IntoIterator<Item = T>,
+) -> Result<Evaluation<T>, Error> {
+    let invalid = |reason: String| Error::InvalidParameter {
+        param: "queries",
+        reason,
+    };
+    let queries = queries
+        .iter()
+        .map(|query| match query {
+            Query::Raw(raw) => raw
+                .parse::<Query>()
+                .map_err(|_| invalid(format!("can't evaluate query {raw:?}"))),
+            query => Ok(query.clone()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    queries.iter().try_for_each(Query::validate)?;
+
+    let mut documents = documents
+        .into_iter()
+        .map(|document| {
+            let fields = document.fields().into_owned();
+            (document, fields)
+        })
+        .filter(|(_, fields)| all(queries.iter().map(|query| query.test(fields))).unwrap_or(false))
+        .collect::<Vec<_>>();
+    let total = documents.len();
+
+    if queries.contains(&Query::OrderRandom) {
+        fastrand::shuffle(&mut documents);
+    }
+    let orders = queries
+        .iter()
+        .filter_map(|query| match query {
+            Query::OrderAsc(attribute) => Some((attribute, false)),
+            Query::OrderDesc(attribute) => Some((attribute, true)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    documents.sort_by(|(_, a), (_, b)| {
+        orders
+            .iter()
+            .map(|(attribute, descending)| {
+                let ordering = compare_for_order(lookup(a, attribute), lookup(b, attribute));
+                match descending {
+                    true => ordering.reverse(),
+                    false => ordering,
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let cursor = queries.iter().find_map(|query| match query {
+        Query::CursorAfter(id) => Some((id, false)),
+        Query::CursorBefore(id) => Some((id, true)),
+        _ => None,
+    });
+    let limit = queries
+        .iter()
+        .find_map(|query| match query {
+            Query::Limit(limit) => Some(*limit),
+            _ => None,
+        })
+        .unwrap_or(DEFAULT_LIMIT);
+    let offset = queries
+        .iter()
+        .find_map(|query| match query {
+            Query::Offset(offset) => Some(*offset),
+            _ => None,
+        })
+        .unwrap_or_default();
+    let before = match cursor {
+        Some((id, before)) => {
+            let index = documents
+                .iter()
+                .position(|(_, fields)| fields.get("$id").and_then(Value::as_str) == Some(id))
+                .ok_or_else(|| invalid(format!("document {id:?} for the cursor not found")))?;
+            match before {
+                true => documents.truncate(index),
+                false => drop(documents.drain(..=index)),
+            }
+            before
+        }
+        None => false,
+    };
+    if before {
+        documents.reverse();
+    }
+    let mut documents = documents
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(|(document, _)| document)
+        .collect::<Vec<_>>();
+    if before {
+        documents.reverse();
+    }
+
+    if let Some(attributes) = queries.iter().find_map(|query| match query {
+        Query::Select(attributes) => Some(attributes),
+        _ => None,
+    }) {
+        documents
+            .iter_mut()
+            .for_each(|document| document.select(attributes));
+    }
+    Ok(Evaluation { total, documents })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn movies() -> Vec<Value> {
+        vec![
+            json!({"$id": "1", "title": "Iron Man", "year": 2008, "tags": ["marvel"], "$createdAt": "2024-01-01T00:00:00.000+00:00"}),
+            json!({"$id": "2", "title": "The Dark Knight", "year": 2008, "tags": ["dc"], "$createdAt": "2024-02-01T00:00:00.000+00:00"}),
+            json!({"$id": "3", "title": "Iron Man 2", "year": 2010, "tags": ["marvel", "sequel"], "$createdAt": "2024-03-01T00:00:00.000+00:00"}),
+            json!({"$id": "4", "title": "Thor", "year": null, "tags": [], "$createdAt": "2024-04-01T00:00:00.000+00:00"}),
+        ]
+    }
+
+    fn ids(queries: &[Query]) -> Vec<String> {
+        evaluate(queries, movies())
+            .unwrap()
+            .documents
+            .iter()
+            .map(|document| document["$id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(ids(&[Query::equal("year", json!([2008]))]), ["1", "2"]);
+        assert_eq!(ids(&[Query::not_equal("year", json!(2008))]), ["3"]);
+        assert_eq!(
+            ids(&[Query::between("year", json!(2009), json!(2010))]),
+            ["3"]
+        );
+        assert_eq!(ids(&[Query::search("title", "knight")]), ["2"]);
+        assert_eq!(ids(&[Query::search("title", "iron mA")]), ["1", "3"]);
+        assert_eq!(ids(&[Query::search("title", "\"man 2\"")]), ["3"]);
+        assert_eq!(ids(&[Query::starts_with("title", "Iron")]), ["1", "3"]);
+        assert_eq!(ids(&[Query::contains("tags", json!(["sequel"]))]), ["3"]);
+        assert_eq!(
+            ids(&[Query::not_contains("tags", json!("marvel"))]),
+            ["2", "4"]
+        );
+        assert_eq!(ids(&[Query::is_null("year")]), ["4"]);
+        assert_eq!(
+            ids(&[Query::created_after("2024-02-01T00:00:00.000+00:00")]),
+            ["3", "4"]
+        );
+        assert_eq!(
+            ids(&[Query::or(vec![
+                Query::equal("title", json!("Thor")),
+                Query::and(vec![
+                    Query::greater_than("year", json!(2008)),
+                    Query::ends_with("title", "2"),
+                ]),
+            ])]),
+            ["3", "4"]
+        );
+        assert!(!Query::raw("limit(25)").matches(&movies()[0]));
+    }
+
+    #[test]
+    fn test_order_paging_and_select() {
+        assert_eq!(
+            ids(&[Query::order_desc("year"), Query::order_asc("title")]),
+            ["3", "1", "2", "4"]
+        );
+        assert_eq!(ids(&[Query::order_asc("year")]), ["4", "1", "2", "3"]);
+        assert_eq!(ids(&[Query::limit(2), Query::offset(1)]), ["2", "3"]);
+        assert_eq!(ids(&[Query::cursor_after("2")]), ["3", "4"]);
+        assert_eq!(
+            ids(&[Query::cursor_before("4"), Query::limit(2)]),
+            ["2", "3"]
+        );
+        assert!(matches!(
+            evaluate(&[Query::cursor_after("9")], movies()),
+            Err(Error::InvalidParameter { .. })
+        ));
+        assert!(evaluate(&[Query::limit(0)], movies()).is_err());
+
+        let result = evaluate(&[Query::select(vec!["title"]), Query::limit(1)], movies()).unwrap();
+        assert_eq!(result.total, 4);
+        assert_eq!(
+            result.documents,
+            [
+                json!({"$id": "1", "title": "Iron Man", "$createdAt": "2024-01-01T00:00:00.000+00:00"})
+            ]
+        );
+    }
+
+    #[test]
+    fn test_documents() {
+        let documents = movies()
+            .into_iter()
+            .map(|mut movie| {
+                let fields = movie.as_object_mut().unwrap();
+                fields.insert(String::from("$collectionId"), json!("movies"));
+                fields.insert(String::from("$databaseId"), json!("cinema"));
+                fields.insert(
+                    String::from("$updatedAt"),
+                    json!("2024-05-01T00:00:00.000+00:00"),
+                );
+                fields.insert(String::from("$permissions"), json!([]));
+                serde_json::from_value::<Document>(movie).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let list: DocumentList = evaluate(&[Query::equal("tags", json!("marvel"))], documents)
+            .unwrap()
+            .into();
+        assert_eq!(list.total, 2);
+        assert_eq!(list.documents[1].id, "3");
+    }
+}
@@ -1,8 +1,8 @@
-use crate::{client::Client, error::Error};
+use crate::{client::Client, error::Error, utils::trace_event};
 use async_fn_stream::try_fn_stream;
 use futures_util::{Stream, StreamExt};
 use serde_json::Value;
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use tokio_tungstenite::{connect_async_tls_with_config, tungstenite::protocol::Message, Connector};
 
 pub struct RealTime;
 
@@ -18,8 +18,7 @@ impl RealTime {
             }
             let mut url_search_params = String::new();
             let project_id = client
-                .header
-                .get("x-appwrite-Project")
+                .header("x-appwrite-project")
                 .ok_or(Error::Custom(
                     "Failed to get `x-appwrite-Project`. try configuring it".to_string(),
                 ))?
@@ -33,13 +32,15 @@ impl RealTime {
             });
             let url = format!(
                 "{}{}",
-                client.end_point_realtime.clone().ok_or(Error::Custom(
+                client.end_point_realtime().ok_or(Error::Custom(
                     "Unable to clone real time endpoint from client".to_string(),
                 ))?,
                 url_search_params
             );
-            println!("{}", url);
-            let (ws_stream, _response) = connect_async(url).await.expect("Can't connect");
+            trace_event!(debug, url = %url, "connecting to appwrite realtime");
+            let connector = Connector::NativeTls(client.tls_connector()?);
+            let (ws_stream, _response) =
+                connect_async_tls_with_config(url, None, false, Some(connector)).await?;
 
             let (mut _write, mut read) = ws_stream.split();
 
@@ -47,18 +48,25 @@ impl RealTime {
                 let msg = match message {
                     Ok(Message::Text(s)) => s,
                     Ok(_) => continue, // Skip non-text messages
-                    Err(err) => {
-                        eprintln!("Failed to read the message: {}", err);
+                    Err(_err) => {
+                        trace_event!(warn, error = %_err, "failed to read realtime message");
                         break; // Break the loop on error
                     }
                 };
                 let parsed: Value = match serde_json::from_str(&msg) {
                     Ok(val) => val,
-                    Err(err) => {
-                        println!("Unable to parse JSON: {}", err);
+                    Err(_err) => {
+                        trace_event!(warn, error = %_err, "unable to parse realtime message");
                         break; // Break the loop on error
                     }
                 };
+                trace_event!(
+                    debug,
+                    message_type = ?parsed.get("type"),
+                    channels = ?parsed.pointer("/data/channels"),
+                    events = ?parsed.pointer("/data/events"),
+                    "realtime message"
+                );
                 emitter.emit(parsed).await
             }
             Ok(())
@@ -0,0 +1,153 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+use crate::enumm::HttpMethod;
+
+/// Retry policy for transient Appwrite failures.
+///
+/// A failed request is sent again when its status code is in
+/// [`retry_status_codes`](Self::retry_status_codes), when the Appwrite error
+/// `type` is in [`retry_error_types`](Self::retry_error_types), or when the
+/// connection failed or timed out. Only idempotent methods (`GET`, `PUT`,
+/// `DELETE`) are retried unless [`retry_non_idempotent`](Self::retry_non_idempotent)
+/// is set. Chunks of a chunked upload are retried even though they are
+/// `POST`s, since re-sending the same `Content-Range` to a known file ID is
+/// safe. The one exception is the first chunk of an upload with a `unique()`
+/// ID: the server picks the ID on that request, so a retry after a lost
+/// response would start a second file.
+///
+/// ```
+/// use std::time::Duration;
+/// use unofficial_appwrite::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     max_attempts: 5,
+///     max_delay: Duration::from_secs(10),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry. Doubled for every following retry.
+    pub base_delay: Duration,
+    /// Upper bound for a single delay, also applied to `Retry-After` and
+    /// `X-RateLimit-Reset` hints from the server.
+    pub max_delay: Duration,
+    /// Pick a random delay between zero and the computed backoff ("full jitter").
+    pub jitter: bool,
+    /// HTTP status codes that are worth another attempt.
+    pub retry_status_codes: Vec<u16>,
+    /// Appwrite error types that are worth another attempt.
+    ///
+    /// See [Error Types](https://appwrite.io/docs/response-codes#errorTypes).
+    pub retry_error_types: Vec<String>,
+    /// Also retry `POST` and `PATCH` requests.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_status_codes: vec![429, 500, 502, 503, 504],
+            retry_error_types: vec![
+                String::from("general_rate_limit_exceeded"),
+                String::from("general_server_error"),
+            ],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries. This is what a client uses unless
+    /// [`ClientBuilder::set_retry_policy`](crate::client::ClientBuilder::set_retry_policy)
+    /// is called.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn allows_method(&self, method: &HttpMethod) -> bool {
+        match method {
+            HttpMethod::GET | HttpMethod::PUT | HttpMethod::DELETE => true,
+            HttpMethod::POST | HttpMethod::PATCH => self.retry_non_idempotent,
+        }
+    }
+
+    pub(crate) fn is_retryable(&self, status: u16, error_type: Option<&str>) -> bool {
+        self.retry_status_codes.contains(&status)
+            || error_type.is_some_and(|t| self.retry_error_types.iter().any(|r| r == t))
+    }
+
+    /// Delay before retry number `retry` (starting at 1), preferring the
+    /// server's `Retry-After` / `X-RateLimit-Reset` hints when present.
+    pub(crate) fn delay(&self, retry: u32, headers: Option<&HeaderMap>) -> Duration {
+        if let Some(hint) = headers.and_then(Self::server_hint) {
+            return hint.min(self.max_delay);
+        }
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_delay);
+        if self.jitter {
+            backoff.mul_f64(fastrand::f64())
+        } else {
+            backoff
+        }
+    }
+
+    fn server_hint(headers: &HeaderMap) -> Option<Duration> {
+        if let Some(value) = headers.get(RETRY_AFTER).and_then(|v| v.to_str().ok()) {
+            if let Ok(seconds) = value.trim().parse::<u64>() {
+                return Some(Duration::from_secs(seconds));
+            }
+            if let Ok(at) = httpdate::parse_http_date(value) {
+                return Some(at.duration_since(SystemTime::now()).unwrap_or_default());
+            }
+        }
+        let reset = headers
+            .get("x-ratelimit-reset")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some(Duration::from_secs(reset.saturating_sub(now)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_backoff_and_server_hints() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            jitter: false,
+            ..Default::default()
+        };
+        assert_eq!(policy.delay(1, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(3, None), Duration::from_millis(400));
+        assert_eq!(policy.delay(10, None), Duration::from_secs(1));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "0".parse().unwrap());
+        assert_eq!(policy.delay(3, Some(&headers)), Duration::ZERO);
+        headers.insert(RETRY_AFTER, "120".parse().unwrap());
+        assert_eq!(policy.delay(1, Some(&headers)), Duration::from_secs(1));
+
+        assert!(policy.is_retryable(503, None));
+        assert!(policy.is_retryable(400, Some("general_rate_limit_exceeded")));
+        assert!(!policy.is_retryable(404, Some("document_not_found")));
+        assert!(!policy.allows_method(&HttpMethod::POST));
+    }
+}
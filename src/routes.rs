@@ -0,0 +1,249 @@
+//! API path templates of every endpoint in [`crate::services::server`], used
+//! to report a low-cardinality route (`/databases/{databaseId}/collections`)
+//! instead of the concrete path of a request.
+
+const ROUTES: &[&str] = &[
+    "/account",
+    "/account/email",
+    "/account/identities",
+    "/account/identities/{identityId}",
+    "/account/jwt",
+    "/account/logs",
+    "/account/mfa",
+    "/account/mfa/authenticators/{type}",
+    "/account/mfa/challenge",
+    "/account/mfa/factors",
+    "/account/mfa/recovery-codes",
+    "/account/name",
+    "/account/prefs",
+    "/account/recovery",
+    "/account/sessions",
+    "/account/sessions/anonymous",
+    "/account/sessions/email",
+    "/account/sessions/magic-url",
+    "/account/sessions/phone",
+    "/account/sessions/token",
+    "/account/sessions/{sessionId}",
+    "/account/status",
+    "/account/token/email",
+    "/account/token/magic-url",
+    "/account/token/oauth2/{provider}",
+    "/account/token/phone",
+    "/account/verification",
+    "/account/verification/phone",
+    "/avatars/browsers/{code}",
+    "/avatars/credit-cards/{code}",
+    "/avatars/favicon",
+    "/avatars/flags/{code}",
+    "/avatars/image",
+    "/avatars/initials",
+    "/avatars/qr",
+    "/databases",
+    "/databases/{databaseId}",
+    "/databases/{databaseId}/collections",
+    "/databases/{databaseId}/collections/{collectionId}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/boolean",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/boolean/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/datetime",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/datetime/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/email",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/email/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/enum",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/enum/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/float",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/float/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/integer",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/integer/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/ip",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/ip/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/relationship",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/string",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/string/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/url",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/url/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/{key}",
+    "/databases/{databaseId}/collections/{collectionId}/attributes/{key}/relationship",
+    "/databases/{databaseId}/collections/{collectionId}/documents",
+    "/databases/{databaseId}/collections/{collectionId}/documents/{documentId}",
+    "/databases/{databaseId}/collections/{collectionId}/indexes",
+    "/databases/{databaseId}/collections/{collectionId}/indexes/{key}",
+    "/functions",
+    "/functions/runtimes",
+    "/functions/{functionId}",
+    "/functions/{functionId}/deployments",
+    "/functions/{functionId}/deployments/{deploymentId}",
+    "/functions/{functionId}/deployments/{deploymentId}/builds/{buildId}",
+    "/functions/{functionId}/deployments/{deploymentId}/download",
+    "/functions/{functionId}/executions",
+    "/functions/{functionId}/executions/{executionId}",
+    "/functions/{functionId}/variables",
+    "/functions/{functionId}/variables/{variableId}",
+    "/graphql",
+    "/graphql/mutation",
+    "/health",
+    "/health/anti-virus",
+    "/health/cache",
+    "/health/certificate",
+    "/health/db",
+    "/health/pubsub",
+    "/health/queue",
+    "/health/queue/builds",
+    "/health/queue/certificates",
+    "/health/queue/databases",
+    "/health/queue/deletes",
+    "/health/queue/failed/{name}",
+    "/health/queue/functions",
+    "/health/queue/logs",
+    "/health/queue/mails",
+    "/health/queue/messaging",
+    "/health/queue/migrations",
+    "/health/queue/usage",
+    "/health/queue/usage-dump",
+    "/health/queue/webhooks",
+    "/health/storage",
+    "/health/storage/local",
+    "/health/time",
+    "/locale",
+    "/locale/codes",
+    "/locale/continents",
+    "/locale/countries",
+    "/locale/countries/eu",
+    "/locale/countries/phones",
+    "/locale/currencies",
+    "/locale/languages",
+    "/messaging/messages",
+    "/messaging/messages/email",
+    "/messaging/messages/email/{messageId}",
+    "/messaging/messages/push",
+    "/messaging/messages/push/{messageId}",
+    "/messaging/messages/sms",
+    "/messaging/messages/sms/{messageId}",
+    "/messaging/messages/{messageId}",
+    "/messaging/messages/{messageId}/logs",
+    "/messaging/messages/{messageId}/targets",
+    "/messaging/providers",
+    "/messaging/providers/apns",
+    "/messaging/providers/apns/{providerId}",
+    "/messaging/providers/fcm",
+    "/messaging/providers/fcm/{providerId}",
+    "/messaging/providers/mailgun",
+    "/messaging/providers/mailgun/{providerId}",
+    "/messaging/providers/msg91",
+    "/messaging/providers/msg91/{providerId}",
+    "/messaging/providers/sendgrid",
+    "/messaging/providers/sendgrid/{providerId}",
+    "/messaging/providers/smtp",
+    "/messaging/providers/smtp/{providerId}",
+    "/messaging/providers/telesign",
+    "/messaging/providers/telesign/{providerId}",
+    "/messaging/providers/textmagic",
+    "/messaging/providers/textmagic/{providerId}",
+    "/messaging/providers/twilio",
+    "/messaging/providers/twilio/{providerId}",
+    "/messaging/providers/vonage",
+    "/messaging/providers/vonage/{providerId}",
+    "/messaging/providers/{providerId}",
+    "/messaging/providers/{providerId}/logs",
+    "/messaging/subscribers/{subscriberId}/logs",
+    "/messaging/topics",
+    "/messaging/topics/{topicId}",
+    "/messaging/topics/{topicId}/logs",
+    "/messaging/topics/{topicId}/subscribers",
+    "/messaging/topics/{topicId}/subscribers/{subscriberId}",
+    "/storage/buckets",
+    "/storage/buckets/{bucketId}",
+    "/storage/buckets/{bucketId}/files",
+    "/storage/buckets/{bucketId}/files/{fileId}",
+    "/storage/buckets/{bucketId}/files/{fileId}/download",
+    "/storage/buckets/{bucketId}/files/{fileId}/preview",
+    "/storage/buckets/{bucketId}/files/{fileId}/view",
+    "/teams",
+    "/teams/{teamId}",
+    "/teams/{teamId}/memberships",
+    "/teams/{teamId}/memberships/{membershipId}",
+    "/teams/{teamId}/memberships/{membershipId}/status",
+    "/teams/{teamId}/prefs",
+    "/users",
+    "/users/argon2",
+    "/users/bcrypt",
+    "/users/identities",
+    "/users/identities/{identityId}",
+    "/users/md5",
+    "/users/phpass",
+    "/users/scrypt",
+    "/users/scrypt-modified",
+    "/users/sha",
+    "/users/{userId}",
+    "/users/{userId}/email",
+    "/users/{userId}/labels",
+    "/users/{userId}/logs",
+    "/users/{userId}/memberships",
+    "/users/{userId}/mfa",
+    "/users/{userId}/mfa/authenticators/{type}",
+    "/users/{userId}/mfa/factors",
+    "/users/{userId}/mfa/recovery-codes",
+    "/users/{userId}/name",
+    "/users/{userId}/password",
+    "/users/{userId}/phone",
+    "/users/{userId}/prefs",
+    "/users/{userId}/sessions",
+    "/users/{userId}/sessions/{sessionId}",
+    "/users/{userId}/status",
+    "/users/{userId}/targets",
+    "/users/{userId}/targets/{targetId}",
+    "/users/{userId}/tokens",
+    "/users/{userId}/verification",
+    "/users/{userId}/verification/phone",
+];
+
+/// Find the template matching `path`. Literal segments must match exactly and
+/// `{placeholder}` segments match any value; when several templates match, the
+/// one with the most literal segments wins (`/users/identities` over
+/// `/users/{userId}`). Unknown paths are returned unchanged.
+pub(crate) fn path_template(path: &str) -> &str {
+    let path = path.split('?').next().unwrap_or(path);
+    let segments: Vec<&str> = path.split('/').collect();
+    ROUTES
+        .iter()
+        .filter_map(|route| {
+            let parts: Vec<&str> = route.split('/').collect();
+            if parts.len() != segments.len() {
+                return None;
+            }
+            let mut literals = 0;
+            for (part, segment) in parts.iter().zip(&segments) {
+                if part.starts_with('{') {
+                    continue;
+                }
+                if part != segment {
+                    return None;
+                }
+                literals += 1;
+            }
+            Some((literals, *route))
+        })
+        .max_by_key(|(literals, _)| *literals)
+        .map(|(_, route)| route)
+        .unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_template() {
+        assert_eq!(
+            path_template("/databases/6618eec2/collections"),
+            "/databases/{databaseId}/collections"
+        );
+        assert_eq!(path_template("/users/identities"), "/users/identities");
+        assert_eq!(path_template("/users/6618eec2"), "/users/{userId}");
+        assert_eq!(
+            path_template("/databases/db/collections/col/attributes/string/title"),
+            "/databases/{databaseId}/collections/{collectionId}/attributes/string/{key}"
+        );
+        assert_eq!(path_template("/not/a/route"), "/not/a/route");
+    }
+}
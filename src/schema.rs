@@ -0,0 +1,759 @@
+//! # Schema
+//!
+//! Describe a collection's attributes and indexes in Rust and create them
+//! with one call. With the `derive` feature, `#[derive(AppwriteCollection)]`
+//! builds the [`CollectionSchema`] from a struct:
+//!
+//! ```ignore
+//! use serde::{Deserialize, Serialize};
+//! use unofficial_appwrite::schema::AppwriteCollection;
+//!
+//! #[derive(Serialize, Deserialize, AppwriteCollection)]
+//! #[appwrite(collection = "posts", name = "Posts", document_security)]
+//! #[appwrite(index(key = "recent", type = "key", attributes = ["status", "published_at"], orders = ["ASC", "DESC"]))]
+//! struct Post {
+//!     #[appwrite(size = 255, required, index = "fulltext")]
+//!     title: String,
+//!     #[appwrite(min = 0, default = 0)]
+//!     views: Option<i64>,
+//!     #[appwrite(elements = ["draft", "published"], default = "draft")]
+//!     status: Option<String>,
+//!     #[appwrite(datetime)]
+//!     published_at: Option<String>,
+//!     tags: Vec<String>,
+//!     #[appwrite(relationship(collection = "authors", kind = "manyToOne", two_way, two_way_key = "posts"))]
+//!     author: Option<serde_json::Value>,
+//! }
+//!
+//! Post::schema().create(&client, "blog").await?;
+//! let query = Post::STATUS.equal("published");
+//! ```
+//!
+//! Field attributes:
+//!
+//! - `key = "…"`: attribute key, defaults to the `serde` name of the field;
+//! - `required` (implied by fields that aren't `Option<_>` and have no
+//!   `default`; `required = false` opts out), `default = …`, `array`
+//!   (implied by `Vec<_>` fields);
+//! - the type, inferred from the field's Rust type unless given as `string`,
+//!   `integer`, `float`, `boolean`, `datetime`, `email`, `url`, `ip`,
+//!   `elements = [...]` (enum) or `relationship(collection = "…", kind =
+//!   "oneToOne" | "oneToMany" | "manyToOne" | "manyToMany", two_way,
+//!   two_way_key = "…", on_delete = "cascade" | "restrict" | "setNull")`;
+//! - `size = …` for strings (default [`DEFAULT_STRING_SIZE`]), `min = …`,
+//!   `max = …` for numbers;
+//! - `index = "key" | "unique" | "fulltext"` for a single-attribute index;
+//! - `skip` to leave the field out, as are fields with `#[serde(skip)]`.
+//!
+//! The derive also adds a [`Field`] constant per attribute, named after the
+//! field in upper case, for building queries. Comparisons need a [`Scalar`]
+//! field type; `Vec<_>` fields only get `contains`.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[cfg(feature = "derive")]
+pub use unofficial_appwrite_derive::AppwriteCollection;
+
+use crate::{
+    client::Client,
+    enums::{
+        index_type::IndexType, relation_mutate::RelationMutate, relationship_type::RelationshipType,
+    },
+    error::Error,
+    models::{collection::Collection, index::Index},
+    query::Query,
+    services::server::databases::Databases,
+};
+
+pub mod plan;
+
+/// Size of string attributes that don't set one.
+pub const DEFAULT_STRING_SIZE: u64 = 255;
+
+/// A type stored as the documents of an Appwrite collection.
+pub trait AppwriteCollection {
+    fn schema() -> CollectionSchema;
+}
+
+/// A database and the collections it should contain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatabaseSchema {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub collections: Vec<CollectionSchema>,
+}
+
+impl DatabaseSchema {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            collections: Vec::new(),
+        }
+    }
+
+    pub fn collection(mut self, collection: CollectionSchema) -> Self {
+        self.collections.push(collection);
+        self
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, Error> {
+        toml::from_str(toml).map_err(|err| Error::Config(err.to_string()))
+    }
+}
+
+/// A collection with its attributes and indexes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionSchema {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub document_security: bool,
+    #[serde(default)]
+    pub attributes: Vec<AttributeSchema>,
+    #[serde(default)]
+    pub indexes: Vec<IndexSchema>,
+}
+
+impl CollectionSchema {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            document_security: false,
+            attributes: Vec::new(),
+            indexes: Vec::new(),
+        }
+    }
+
+    pub fn document_security(mut self, document_security: bool) -> Self {
+        self.document_security = document_security;
+        self
+    }
+
+    pub fn attribute(mut self, attribute: AttributeSchema) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+
+    pub fn index(mut self, index: IndexSchema) -> Self {
+        self.indexes.push(index);
+        self
+    }
+
+    /// Create the collection in `database_id`, then its attributes and
+    /// indexes. Returns once the attributes are available, see
+    /// [`Databases::wait_for_attribute`].
+    pub async fn create(&self, client: &Client, database_id: &str) -> Result<Collection, Error> {
+        let collection = Databases::create_collection(
+            client,
+            database_id,
+            &self.id,
+            &self.name,
+            None,
+            Some(self.document_security),
+            None,
+        )
+        .await?;
+        for attribute in &self.attributes {
+            attribute.create(client, database_id, &self.id).await?;
+        }
+        for attribute in &self.attributes {
+            Databases::wait_for_attribute(client, database_id, &self.id, &attribute.key, None)
+                .await?;
+        }
+        for index in &self.indexes {
+            index.create(client, database_id, &self.id).await?;
+        }
+        Ok(collection)
+    }
+}
+
+/// The type of an attribute and its type-specific settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AttributeKind {
+    String {
+        #[serde(default = "default_string_size")]
+        size: u64,
+    },
+    Integer {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<i64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<i64>,
+    },
+    Float {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<f64>,
+    },
+    Boolean,
+    Datetime,
+    Email,
+    Url,
+    Ip,
+    Enum {
+        elements: Vec<String>,
+    },
+    Relationship(RelationshipSchema),
+}
+
+fn default_string_size() -> u64 {
+    DEFAULT_STRING_SIZE
+}
+
+/// A relationship attribute's other side.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationshipSchema {
+    pub related_collection: String,
+    pub relation_type: RelationshipType,
+    #[serde(default)]
+    pub two_way: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub two_way_key: Option<String>,
+    #[serde(default = "default_on_delete")]
+    pub on_delete: RelationMutate,
+}
+
+fn default_on_delete() -> RelationMutate {
+    RelationMutate::Restrict
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeSchema {
+    pub key: String,
+    #[serde(flatten)]
+    pub kind: AttributeKind,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub array: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+}
+
+impl AttributeSchema {
+    pub fn new(key: impl Into<String>, kind: AttributeKind) -> Self {
+        Self {
+            key: key.into(),
+            kind,
+            required: false,
+            array: false,
+            default: None,
+        }
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn array(mut self, array: bool) -> Self {
+        self.array = array;
+        self
+    }
+
+    pub fn default_value(mut self, default: impl Into<Value>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// The default converted with `convert`, an error if it doesn't fit.
+    fn default_as<'a, T>(
+        &'a self,
+        convert: impl FnOnce(&'a Value) -> Option<T>,
+    ) -> Result<Option<T>, Error> {
+        match &self.default {
+            Some(default) => convert(default)
+                .map(Some)
+                .ok_or_else(|| Error::InvalidParameter {
+                    param: "default",
+                    reason: format!("{default} doesn't fit attribute `{}`", self.key),
+                }),
+            None => Ok(None),
+        }
+    }
+
+    /// Create the attribute on collection `collection_id`.
+    pub async fn create(
+        &self,
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+    ) -> Result<(), Error> {
+        let (key, required, array) = (self.key.as_str(), self.required, Some(self.array));
+        let (db, collection) = (database_id, collection_id);
+        match &self.kind {
+            AttributeKind::String { size } => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::create_string_attribute(
+                    client, db, collection, key, *size, required, default, array, None,
+                )
+                .await?;
+            }
+            AttributeKind::Integer { min, max } => {
+                let default = self.default_as(Value::as_i64)?;
+                Databases::create_integer_attribute(
+                    client, db, collection, key, required, *min, *max, default, array,
+                )
+                .await?;
+            }
+            AttributeKind::Float { min, max } => {
+                let default = self.default_as(Value::as_f64)?;
+                Databases::create_float_attribute(
+                    client, db, collection, key, required, *min, *max, default, array,
+                )
+                .await?;
+            }
+            AttributeKind::Boolean => {
+                let default = self.default_as(Value::as_bool)?;
+                Databases::create_boolean_attribute(
+                    client, db, collection, key, required, default, array,
+                )
+                .await?;
+            }
+            AttributeKind::Datetime => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::create_date_time_attribute(
+                    client, db, collection, key, required, default, array,
+                )
+                .await?;
+            }
+            AttributeKind::Email => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::create_email_attribute(
+                    client, db, collection, key, required, default, array,
+                )
+                .await?;
+            }
+            AttributeKind::Url => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::create_url_attribute(
+                    client, db, collection, key, required, default, array,
+                )
+                .await?;
+            }
+            AttributeKind::Ip => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::create_ip_attribute(
+                    client, db, collection, key, required, default, array,
+                )
+                .await?;
+            }
+            AttributeKind::Enum { elements } => {
+                let default = self.default_as(Value::as_str)?;
+                let elements = elements.iter().map(String::as_str).collect();
+                Databases::create_enum_attribute(
+                    client, db, collection, key, elements, required, default, array,
+                )
+                .await?;
+            }
+            AttributeKind::Relationship(relationship) => {
+                Databases::create_relationship_attribute(
+                    client,
+                    db,
+                    collection,
+                    &relationship.related_collection,
+                    relationship.relation_type.clone(),
+                    Some(relationship.two_way),
+                    Some(key),
+                    relationship.two_way_key.as_deref(),
+                    Some(relationship.on_delete.clone()),
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Update the existing attribute on collection `collection_id` to this
+    /// one's required flag, default and bounds or elements; a relationship
+    /// only updates `on_delete`. The type, size and array flag are left as
+    /// they are.
+    pub async fn update(
+        &self,
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+    ) -> Result<(), Error> {
+        let (key, required) = (self.key.as_str(), self.required);
+        let (db, collection) = (database_id, collection_id);
+        match &self.kind {
+            AttributeKind::String { .. } => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::update_string_attribute(client, db, collection, key, required, default)
+                    .await?;
+            }
+            AttributeKind::Integer { min, max } => {
+                let default = self.default_as(Value::as_i64)?;
+                Databases::update_integer_attribute(
+                    client, db, collection, key, *min, *max, required, default,
+                )
+                .await?;
+            }
+            AttributeKind::Float { min, max } => {
+                let default = self.default_as(Value::as_f64)?;
+                Databases::update_float_attribute(
+                    client, db, collection, key, *min, *max, required, default,
+                )
+                .await?;
+            }
+            AttributeKind::Boolean => {
+                let default = self.default_as(Value::as_bool)?;
+                Databases::update_boolean_attribute(client, db, collection, key, required, default)
+                    .await?;
+            }
+            AttributeKind::Datetime => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::update_date_time_attribute(
+                    client, db, collection, key, required, default,
+                )
+                .await?;
+            }
+            AttributeKind::Email => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::update_email_attribute(client, db, collection, key, required, default)
+                    .await?;
+            }
+            AttributeKind::Url => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::update_url_attribute(client, db, collection, key, required, default)
+                    .await?;
+            }
+            AttributeKind::Ip => {
+                let default = self.default_as(Value::as_str)?;
+                Databases::update_ip_attribute(client, db, collection, key, required, default)
+                    .await?;
+            }
+            AttributeKind::Enum { elements } => {
+                let default = self.default_as(Value::as_str)?;
+                let elements = elements.iter().map(String::as_str).collect();
+                Databases::update_enum_attribute(
+                    client, db, collection, key, elements, required, default,
+                )
+                .await?;
+            }
+            AttributeKind::Relationship(relationship) => {
+                Databases::update_relationship_attribute(
+                    client,
+                    db,
+                    collection,
+                    key,
+                    Some(relationship.on_delete.clone()),
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexSchema {
+    pub key: String,
+    #[serde(rename = "type")]
+    pub index_type: IndexType,
+    pub attributes: Vec<String>,
+    /// `ASC` or `DESC` per attribute; empty for the server's default.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orders: Vec<String>,
+}
+
+impl IndexSchema {
+    pub fn new(
+        key: impl Into<String>,
+        index_type: IndexType,
+        attributes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            key: key.into(),
+            index_type,
+            attributes: attributes.into_iter().map(Into::into).collect(),
+            orders: Vec::new(),
+        }
+    }
+
+    pub fn orders(mut self, orders: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.orders = orders.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Create the index on collection `collection_id`.
+    pub async fn create(
+        &self,
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+    ) -> Result<Index, Error> {
+        Databases::create_index(
+            client,
+            database_id,
+            collection_id,
+            &self.key,
+            self.index_type.clone(),
+            self.attributes.iter().map(String::as_str).collect(),
+            (!self.orders.is_empty()).then(|| self.orders.iter().map(String::as_str).collect()),
+        )
+        .await
+    }
+}
+
+/// The key of an attribute holding `T` values, for building queries.
+pub struct Field<T> {
+    key: &'static str,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Field<T> {}
+
+impl<T> fmt::Debug for Field<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Field").field(&self.key).finish()
+    }
+}
+
+impl<T> fmt::Display for Field<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key)
+    }
+}
+
+impl<T> AsRef<str> for Field<T> {
+    fn as_ref(&self) -> &str {
+        self.key
+    }
+}
+
+/// A single attribute value a [`Field`] can be compared with. Implement it
+/// for your own types, e.g. a date type stored in a `datetime` attribute.
+pub trait Scalar {
+    fn into_value(self) -> Value;
+}
+
+macro_rules! impl_scalar {
+    ($($ty:ty),*) => {
+        $(impl Scalar for $ty {
+            fn into_value(self) -> Value {
+                Value::from(self)
+            }
+        })*
+    };
+}
+
+// Floats that JSON can't represent (NaN and the infinities) become `null`.
+impl_scalar!(String, bool, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+impl<T> Field<T> {
+    pub const fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            _type: PhantomData,
+        }
+    }
+
+    pub const fn key(&self) -> &'static str {
+        self.key
+    }
+
+    pub fn is_null(&self) -> Query {
+        Query::is_null(self.key)
+    }
+
+    pub fn is_not_null(&self) -> Query {
+        Query::is_not_null(self.key)
+    }
+
+    pub fn order_asc(&self) -> Query {
+        Query::order_asc(self.key)
+    }
+
+    pub fn order_desc(&self) -> Query {
+        Query::order_desc(self.key)
+    }
+}
+
+impl<T: Scalar> Field<T> {
+    pub fn equal(&self, value: impl Into<T>) -> Query {
+        Query::equal(self.key, Value::Array(vec![value.into().into_value()]))
+    }
+
+    pub fn not_equal(&self, value: impl Into<T>) -> Query {
+        Query::not_equal(self.key, Value::Array(vec![value.into().into_value()]))
+    }
+
+    pub fn less_than(&self, value: impl Into<T>) -> Query {
+        Query::less_than(self.key, value.into().into_value())
+    }
+
+    pub fn less_than_equal(&self, value: impl Into<T>) -> Query {
+        Query::less_than_equal(self.key, value.into().into_value())
+    }
+
+    pub fn greater_than(&self, value: impl Into<T>) -> Query {
+        Query::greater_than(self.key, value.into().into_value())
+    }
+
+    pub fn greater_than_equal(&self, value: impl Into<T>) -> Query {
+        Query::greater_than_equal(self.key, value.into().into_value())
+    }
+
+    pub fn between(&self, start: impl Into<T>, end: impl Into<T>) -> Query {
+        Query::between(self.key, start.into().into_value(), end.into().into_value())
+    }
+}
+
+impl Field<String> {
+    pub fn search(&self, value: &str) -> Query {
+        Query::search(self.key, value)
+    }
+
+    pub fn starts_with(&self, value: &str) -> Query {
+        Query::starts_with(self.key, value)
+    }
+
+    pub fn ends_with(&self, value: &str) -> Query {
+        Query::ends_with(self.key, value)
+    }
+}
+
+impl<T: Scalar> Field<Vec<T>> {
+    pub fn contains(&self, value: impl Into<T>) -> Query {
+        Query::contains(self.key, Value::Array(vec![value.into().into_value()]))
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[allow(dead_code)]
+    #[derive(Serialize, AppwriteCollection)]
+    #[appwrite(collection = "posts", document_security)]
+    #[appwrite(index(key = "recent", type = "key", attributes = ["status", "publishedAt"], orders = ["ASC", "DESC"]))]
+    struct Post {
+        #[appwrite(size = 128, required, index = "fulltext")]
+        title: String,
+        #[appwrite(min = -10, max = 1000, default = 0)]
+        score: Option<i64>,
+        #[appwrite(elements = ["draft", "published"], default = "draft")]
+        status: Option<String>,
+        #[appwrite(datetime, key = "publishedAt")]
+        published_at: Option<String>,
+        tags: Vec<String>,
+        #[appwrite(relationship(
+            collection = "authors",
+            kind = "manyToOne",
+            two_way,
+            two_way_key = "posts"
+        ))]
+        author: Option<Value>,
+        #[appwrite(required = false)]
+        slug: String,
+        #[appwrite(skip)]
+        cached: bool,
+        #[serde(skip)]
+        rendered: String,
+    }
+
+    #[test]
+    fn test_derive_schema() {
+        let schema = Post::schema();
+        assert_eq!(
+            schema,
+            CollectionSchema::new("posts", "Post")
+                .document_security(true)
+                .attribute(
+                    AttributeSchema::new("title", AttributeKind::String { size: 128 })
+                        .required(true)
+                )
+                .attribute(
+                    AttributeSchema::new(
+                        "score",
+                        AttributeKind::Integer {
+                            min: Some(-10),
+                            max: Some(1000)
+                        }
+                    )
+                    .default_value(0)
+                )
+                .attribute(
+                    AttributeSchema::new(
+                        "status",
+                        AttributeKind::Enum {
+                            elements: vec![String::from("draft"), String::from("published")]
+                        }
+                    )
+                    .default_value("draft")
+                )
+                .attribute(AttributeSchema::new("publishedAt", AttributeKind::Datetime))
+                .attribute(
+                    AttributeSchema::new(
+                        "tags",
+                        AttributeKind::String {
+                            size: DEFAULT_STRING_SIZE
+                        }
+                    )
+                    .required(true)
+                    .array(true)
+                )
+                .attribute(AttributeSchema::new(
+                    "author",
+                    AttributeKind::Relationship(RelationshipSchema {
+                        related_collection: String::from("authors"),
+                        relation_type: RelationshipType::ManyToOne,
+                        two_way: true,
+                        two_way_key: Some(String::from("posts")),
+                        on_delete: RelationMutate::Restrict,
+                    })
+                ))
+                .attribute(AttributeSchema::new(
+                    "slug",
+                    AttributeKind::String {
+                        size: DEFAULT_STRING_SIZE
+                    }
+                ))
+                .index(
+                    IndexSchema::new("recent", IndexType::Key, ["status", "publishedAt"])
+                        .orders(["ASC", "DESC"])
+                )
+                .index(IndexSchema::new(
+                    "title_index",
+                    IndexType::Fulltext,
+                    ["title"]
+                ))
+        );
+
+        assert_eq!(Post::PUBLISHED_AT.key(), "publishedAt");
+        assert_eq!(
+            Post::STATUS.equal("published"),
+            Query::equal("status", json!(["published"]))
+        );
+        assert_eq!(
+            Post::SCORE.between(1, 5),
+            Query::between("score", json!(1), json!(5))
+        );
+        assert_eq!(
+            Post::TAGS.contains("rust"),
+            Query::contains("tags", json!(["rust"]))
+        );
+    }
+}
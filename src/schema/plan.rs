@@ -0,0 +1,744 @@
+//! # Migrations
+//!
+//! Diff a [`DatabaseSchema`] against the server and apply the difference.
+//! The schema can be built in Rust, from `#[derive(AppwriteCollection)]`
+//! structs, or loaded from JSON (or TOML with the `toml` feature):
+//!
+//! ```no_run
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//! use unofficial_appwrite::schema::DatabaseSchema;
+//!
+//! # async fn run() -> Result<(), Error> {
+//! let client = ClientBuilder::default().set_project("5ff3...")?.build()?;
+//!
+//! let schema = DatabaseSchema::from_json(
+//!     r#"{
+//!         "id": "blog",
+//!         "name": "Blog",
+//!         "collections": [{
+//!             "id": "posts",
+//!             "name": "Posts",
+//!             "attributes": [
+//!                 {"key": "title", "type": "string", "size": 255, "required": true},
+//!                 {"key": "views", "type": "integer", "min": 0, "default": 0}
+//!             ],
+//!             "indexes": [{"key": "title_index", "type": "fulltext", "attributes": ["title"]}]
+//!         }]
+//!     }"#,
+//! )?;
+//!
+//! let plan = schema.plan(&client).await?;
+//! println!("{plan}");
+//! plan.apply(&client, false).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Settings left out of the schema, like an integer's `min` or an index's
+//! `orders`, are not compared with the server. Changes the server can't make
+//! in place, e.g. a string's `size` or an attribute's type, delete the
+//! attribute and create it again, together with the indexes that use it.
+//! Collection permissions and whether a collection is enabled are not part of
+//! the schema: they are never compared with the server or sent with a change.
+
+use std::{fmt, mem};
+
+use futures_util::TryStreamExt;
+use serde_json::Value;
+
+use super::{
+    AttributeKind, AttributeSchema, CollectionSchema, DatabaseSchema, IndexSchema,
+    RelationshipSchema, DEFAULT_STRING_SIZE,
+};
+use crate::{
+    client::Client,
+    error::{AppwriteErrorKind, Error},
+    models::index::Index,
+    services::server::databases::Databases,
+    wait::WaitOptions,
+};
+
+/// One step of a [`Plan`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    CreateDatabase {
+        id: String,
+        name: String,
+    },
+    CreateCollection {
+        id: String,
+        name: String,
+        document_security: bool,
+    },
+    UpdateCollection {
+        id: String,
+        name: String,
+        document_security: bool,
+    },
+    DeleteCollection(String),
+    CreateAttribute {
+        collection: String,
+        attribute: AttributeSchema,
+    },
+    UpdateAttribute {
+        collection: String,
+        attribute: AttributeSchema,
+    },
+    DeleteAttribute {
+        collection: String,
+        key: String,
+    },
+    CreateIndex {
+        collection: String,
+        index: IndexSchema,
+    },
+    DeleteIndex {
+        collection: String,
+        key: String,
+    },
+}
+
+impl Change {
+    /// Whether applying the change loses documents or their data.
+    pub fn is_destructive(&self) -> bool {
+        matches!(
+            self,
+            Change::DeleteCollection(_) | Change::DeleteAttribute { .. }
+        )
+    }
+
+    async fn apply(&self, client: &Client, database_id: &str) -> Result<(), Error> {
+        match self {
+            Change::CreateDatabase { id, name } => {
+                Databases::create(client, id, name, None).await?;
+            }
+            Change::CreateCollection {
+                id,
+                name,
+                document_security,
+            } => {
+                Databases::create_collection(
+                    client,
+                    database_id,
+                    id,
+                    name,
+                    None,
+                    Some(*document_security),
+                    None,
+                )
+                .await?;
+            }
+            Change::UpdateCollection {
+                id,
+                name,
+                document_security,
+            } => {
+                Databases::update_collection(
+                    client,
+                    database_id,
+                    id,
+                    name,
+                    None,
+                    Some(*document_security),
+                    None,
+                )
+                .await?;
+            }
+            Change::DeleteCollection(id) => {
+                Databases::delete_collection(client, database_id, id).await?;
+            }
+            Change::CreateAttribute {
+                collection,
+                attribute,
+            } => attribute.create(client, database_id, collection).await?,
+            Change::UpdateAttribute {
+                collection,
+                attribute,
+            } => attribute.update(client, database_id, collection).await?,
+            Change::DeleteAttribute { collection, key } => {
+                Databases::delete_attribute(client, database_id, collection, key).await?;
+            }
+            Change::CreateIndex { collection, index } => {
+                index.create(client, database_id, collection).await?;
+            }
+            Change::DeleteIndex { collection, key } => {
+                Databases::delete_index(client, database_id, collection, key).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::CreateDatabase { id, .. } => write!(f, "+ database {id}"),
+            Change::CreateCollection { id, .. } => write!(f, "+ collection {id}"),
+            Change::UpdateCollection { id, .. } => write!(f, "~ collection {id}"),
+            Change::DeleteCollection(id) => write!(f, "- collection {id}"),
+            Change::CreateAttribute {
+                collection,
+                attribute,
+            } => write!(f, "+ attribute {collection}.{}", attribute.key),
+            Change::UpdateAttribute {
+                collection,
+                attribute,
+            } => write!(f, "~ attribute {collection}.{}", attribute.key),
+            Change::DeleteAttribute { collection, key } => {
+                write!(f, "- attribute {collection}.{key}")
+            }
+            Change::CreateIndex { collection, index } => {
+                write!(f, "+ index {collection}.{}", index.key)
+            }
+            Change::DeleteIndex { collection, key } => write!(f, "- index {collection}.{key}"),
+        }
+    }
+}
+
+/// The changes that bring a database in line with its [`DatabaseSchema`],
+/// in the order they are applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plan {
+    pub database_id: String,
+    pub changes: Vec<Change>,
+}
+
+impl Plan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn is_destructive(&self) -> bool {
+        self.changes.iter().any(Change::is_destructive)
+    }
+
+    /// Apply the changes in order. Unless `allow_destructive` is set, a plan
+    /// that deletes collections or attributes fails before any change is made.
+    ///
+    /// Deleted attributes and indexes are waited for until they are gone, and
+    /// created or updated attributes until they are available before any
+    /// index is created and before returning.
+    pub async fn apply(&self, client: &Client, allow_destructive: bool) -> Result<(), Error> {
+        if !allow_destructive {
+            if let Some(change) = self.changes.iter().find(|change| change.is_destructive()) {
+                return Err(Error::InvalidParameter {
+                    param: "allow_destructive",
+                    reason: format!("the plan is destructive: `{change}`"),
+                });
+            }
+        }
+        let (database_id, options) = (self.database_id.as_str(), WaitOptions::default());
+        let mut processing = Vec::new();
+        for change in &self.changes {
+            if let Change::CreateIndex { .. } = change {
+                wait_for_attributes(client, database_id, &mut processing).await?;
+            }
+            change.apply(client, database_id).await?;
+            match change {
+                Change::CreateAttribute {
+                    collection,
+                    attribute,
+                }
+                | Change::UpdateAttribute {
+                    collection,
+                    attribute,
+                } => processing.push((collection, &attribute.key)),
+                Change::DeleteAttribute { collection, key } => {
+                    Databases::wait_for_attribute_deletion(
+                        client,
+                        database_id,
+                        collection,
+                        key,
+                        &options,
+                    )
+                    .await?
+                }
+                Change::DeleteIndex { collection, key } => {
+                    Databases::wait_for_index_deletion(
+                        client,
+                        database_id,
+                        collection,
+                        key,
+                        &options,
+                    )
+                    .await?
+                }
+                _ => {}
+            }
+        }
+        wait_for_attributes(client, database_id, &mut processing).await
+    }
+}
+
+async fn wait_for_attributes(
+    client: &Client,
+    database_id: &str,
+    attributes: &mut Vec<(&String, &String)>,
+) -> Result<(), Error> {
+    for (collection, key) in attributes.drain(..) {
+        Databases::wait_for_attribute(client, database_id, collection, key, None).await?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return write!(f, "database {} is up to date", self.database_id);
+        }
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+impl DatabaseSchema {
+    /// Read the database's collections, attributes and indexes from the
+    /// server and plan the changes to match this schema.
+    pub async fn plan(&self, client: &Client) -> Result<Plan, Error> {
+        let live = match Databases::get(client, &self.id).await {
+            Ok(_) => Some(live_collections(client, &self.id).await?),
+            Err(err) if err.kind() == Some(AppwriteErrorKind::DatabaseNotFound) => None,
+            Err(err) => return Err(err),
+        };
+        Ok(diff(self, live))
+    }
+}
+
+async fn live_collections(
+    client: &Client,
+    database_id: &str,
+) -> Result<Vec<CollectionSchema>, Error> {
+    let collections: Vec<_> =
+        Databases::list_collections_stream(client, database_id, None, None, None)
+            .try_collect()
+            .await?;
+    let mut schemas = Vec::new();
+    for collection in collections {
+        let attributes: Vec<Value> =
+            Databases::list_attributes_stream(client, database_id, &collection.id, None, None)
+                .try_collect()
+                .await?;
+        let indexes: Vec<Index> =
+            Databases::list_indexes_stream(client, database_id, &collection.id, None, None)
+                .try_collect()
+                .await?;
+        schemas.push(CollectionSchema {
+            id: collection.id,
+            name: collection.name,
+            document_security: collection.document_security,
+            attributes: attributes.iter().filter_map(live_attribute).collect(),
+            indexes: indexes
+                .into_iter()
+                .filter_map(|index| {
+                    let strings = |values: Vec<Value>| {
+                        values
+                            .into_iter()
+                            .filter_map(|value| value.as_str().map(String::from))
+                            .collect()
+                    };
+                    Some(IndexSchema {
+                        key: index.key,
+                        index_type: serde_json::from_value(Value::String(index.index_type)).ok()?,
+                        attributes: strings(index.attributes),
+                        orders: match index.orders {
+                            Some(Value::Array(orders)) => strings(orders),
+                            _ => Vec::new(),
+                        },
+                    })
+                })
+                .collect(),
+        });
+    }
+    Ok(schemas)
+}
+
+/// An attribute as listed by the server; `None` for types this crate can't
+/// create and the child side of two-way relationships.
+fn live_attribute(value: &Value) -> Option<AttributeSchema> {
+    let text = |name| value.get(name).and_then(Value::as_str);
+    let kind = match (text("type")?, text("format").unwrap_or_default()) {
+        ("string", "email") => AttributeKind::Email,
+        ("string", "url") => AttributeKind::Url,
+        ("string", "ip") => AttributeKind::Ip,
+        ("string", "enum") => AttributeKind::Enum {
+            elements: serde_json::from_value(value.get("elements")?.clone()).ok()?,
+        },
+        ("string", _) => AttributeKind::String {
+            size: value["size"].as_u64().unwrap_or(DEFAULT_STRING_SIZE),
+        },
+        ("integer", _) => AttributeKind::Integer {
+            min: value["min"].as_i64(),
+            max: value["max"].as_i64(),
+        },
+        ("double", _) => AttributeKind::Float {
+            min: value["min"].as_f64(),
+            max: value["max"].as_f64(),
+        },
+        ("boolean", _) => AttributeKind::Boolean,
+        ("datetime", _) => AttributeKind::Datetime,
+        ("relationship", _) if text("side") != Some("child") => {
+            AttributeKind::Relationship(RelationshipSchema {
+                related_collection: text("relatedCollection")?.to_string(),
+                relation_type: serde_json::from_value(value.get("relationType")?.clone()).ok()?,
+                two_way: value["twoWay"].as_bool().unwrap_or_default(),
+                two_way_key: text("twoWayKey")
+                    .filter(|key| !key.is_empty())
+                    .map(String::from),
+                on_delete: serde_json::from_value(value.get("onDelete")?.clone()).ok()?,
+            })
+        }
+        _ => return None,
+    };
+    Some(AttributeSchema {
+        key: text("key")?.to_string(),
+        kind,
+        required: value["required"].as_bool().unwrap_or_default(),
+        array: value["array"].as_bool().unwrap_or_default(),
+        default: value
+            .get("default")
+            .filter(|value| !value.is_null())
+            .cloned(),
+    })
+}
+
+enum Difference {
+    None,
+    Update,
+    Replace,
+}
+
+/// How `live` has to change to match `desired`; settings `desired` leaves
+/// unset are not compared.
+fn compare(desired: &AttributeSchema, live: &AttributeSchema) -> Difference {
+    fn unset_or<T: PartialEq>(desired: &Option<T>, live: &Option<T>) -> bool {
+        desired.is_none() || desired == live
+    }
+
+    if desired.array != live.array {
+        return Difference::Replace;
+    }
+    let settings_match = match (&desired.kind, &live.kind) {
+        (AttributeKind::String { size }, AttributeKind::String { size: live_size }) => {
+            if size != live_size {
+                return Difference::Replace;
+            }
+            true
+        }
+        (
+            AttributeKind::Integer { min, max },
+            AttributeKind::Integer {
+                min: live_min,
+                max: live_max,
+            },
+        ) => unset_or(min, live_min) && unset_or(max, live_max),
+        (
+            AttributeKind::Float { min, max },
+            AttributeKind::Float {
+                min: live_min,
+                max: live_max,
+            },
+        ) => unset_or(min, live_min) && unset_or(max, live_max),
+        (
+            AttributeKind::Enum { elements },
+            AttributeKind::Enum {
+                elements: live_elements,
+            },
+        ) => elements == live_elements,
+        (AttributeKind::Relationship(desired), AttributeKind::Relationship(live)) => {
+            if desired.related_collection != live.related_collection
+                || desired.relation_type != live.relation_type
+                || desired.two_way != live.two_way
+                || !unset_or(&desired.two_way_key, &live.two_way_key)
+            {
+                return Difference::Replace;
+            }
+            desired.on_delete == live.on_delete
+        }
+        (kind, live_kind) if mem::discriminant(kind) == mem::discriminant(live_kind) => true,
+        _ => return Difference::Replace,
+    };
+    let relationship = matches!(desired.kind, AttributeKind::Relationship(_));
+    if settings_match
+        && (relationship || (desired.required == live.required && desired.default == live.default))
+    {
+        Difference::None
+    } else {
+        Difference::Update
+    }
+}
+
+/// Plan the changes turning `live` (`None` if the database doesn't exist)
+/// into `schema`.
+fn diff(schema: &DatabaseSchema, live: Option<Vec<CollectionSchema>>) -> Plan {
+    let mut databases = Vec::new();
+    let mut create_collections = Vec::new();
+    let mut update_collections = Vec::new();
+    let mut delete_indexes = Vec::new();
+    let mut delete_attributes = Vec::new();
+    let mut create_attributes = Vec::new();
+    let mut create_relationships = Vec::new();
+    let mut update_attributes = Vec::new();
+    let mut create_indexes = Vec::new();
+    let mut delete_collections = Vec::new();
+
+    let live = live.unwrap_or_else(|| {
+        databases.push(Change::CreateDatabase {
+            id: schema.id.clone(),
+            name: schema.name.clone(),
+        });
+        Vec::new()
+    });
+
+    let (no_attributes, no_indexes) = (Vec::new(), Vec::new());
+    for desired in &schema.collections {
+        let collection = || desired.id.clone();
+        let (live_attributes, live_indexes) = match live.iter().find(|live| live.id == desired.id) {
+            Some(live) => {
+                if live.name != desired.name || live.document_security != desired.document_security
+                {
+                    update_collections.push(Change::UpdateCollection {
+                        id: collection(),
+                        name: desired.name.clone(),
+                        document_security: desired.document_security,
+                    });
+                }
+                (&live.attributes, &live.indexes)
+            }
+            None => {
+                create_collections.push(Change::CreateCollection {
+                    id: collection(),
+                    name: desired.name.clone(),
+                    document_security: desired.document_security,
+                });
+                (&no_attributes, &no_indexes)
+            }
+        };
+
+        let mut replaced = Vec::new();
+        for attribute in &desired.attributes {
+            let create = Change::CreateAttribute {
+                collection: collection(),
+                attribute: attribute.clone(),
+            };
+            let creates = match attribute.kind {
+                AttributeKind::Relationship(_) => &mut create_relationships,
+                _ => &mut create_attributes,
+            };
+            match live_attributes
+                .iter()
+                .find(|live| live.key == attribute.key)
+            {
+                None => creates.push(create),
+                Some(live) => match compare(attribute, live) {
+                    Difference::None => {}
+                    Difference::Update => update_attributes.push(Change::UpdateAttribute {
+                        collection: collection(),
+                        attribute: attribute.clone(),
+                    }),
+                    Difference::Replace => {
+                        delete_attributes.push(Change::DeleteAttribute {
+                            collection: collection(),
+                            key: attribute.key.clone(),
+                        });
+                        creates.push(create);
+                        replaced.push(&attribute.key);
+                    }
+                },
+            }
+        }
+        for live in live_attributes {
+            if !desired
+                .attributes
+                .iter()
+                .any(|desired| desired.key == live.key)
+            {
+                delete_attributes.push(Change::DeleteAttribute {
+                    collection: collection(),
+                    key: live.key.clone(),
+                });
+            }
+        }
+
+        for index in &desired.indexes {
+            let live = live_indexes.iter().find(|live| live.key == index.key);
+            // An index on a replaced attribute is rebuilt along with it.
+            let matches = live.is_some_and(|live| {
+                !live.attributes.iter().any(|key| replaced.contains(&key))
+                    && live.index_type == index.index_type
+                    && live.attributes == index.attributes
+                    && (index.orders.is_empty() || live.orders == index.orders)
+            });
+            if matches {
+                continue;
+            }
+            if live.is_some() {
+                delete_indexes.push(Change::DeleteIndex {
+                    collection: collection(),
+                    key: index.key.clone(),
+                });
+            }
+            create_indexes.push(Change::CreateIndex {
+                collection: collection(),
+                index: index.clone(),
+            });
+        }
+        for live in live_indexes {
+            if !desired
+                .indexes
+                .iter()
+                .any(|desired| desired.key == live.key)
+            {
+                delete_indexes.push(Change::DeleteIndex {
+                    collection: collection(),
+                    key: live.key.clone(),
+                });
+            }
+        }
+    }
+    for live in &live {
+        if !schema
+            .collections
+            .iter()
+            .any(|desired| desired.id == live.id)
+        {
+            delete_collections.push(Change::DeleteCollection(live.id.clone()));
+        }
+    }
+
+    Plan {
+        database_id: schema.id.clone(),
+        changes: [
+            databases,
+            create_collections,
+            update_collections,
+            delete_indexes,
+            delete_attributes,
+            create_attributes,
+            create_relationships,
+            update_attributes,
+            create_indexes,
+            delete_collections,
+        ]
+        .concat(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::enums::{
+        index_type::IndexType, relation_mutate::RelationMutate, relationship_type::RelationshipType,
+    };
+
+    #[test]
+    fn test_diff() {
+        let schema = DatabaseSchema::from_json(
+            &json!({
+                "id": "blog",
+                "name": "Blog",
+                "collections": [
+                    {
+                        "id": "posts",
+                        "name": "Posts",
+                        "attributes": [
+                            {"key": "title", "type": "string", "size": 512, "required": true},
+                            {"key": "views", "type": "integer", "min": 0},
+                            {"key": "author", "type": "relationship", "related_collection": "authors", "relation_type": "manyToOne"},
+                        ],
+                        "indexes": [
+                            {"key": "views_index", "type": "key", "attributes": ["views"]},
+                            {"key": "title_index", "type": "fulltext", "attributes": ["title"]},
+                        ],
+                    },
+                    {"id": "authors", "name": "Authors"},
+                ],
+            })
+            .to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            schema.collections[0].attributes[2].kind,
+            AttributeKind::Relationship(RelationshipSchema {
+                related_collection: String::from("authors"),
+                relation_type: RelationshipType::ManyToOne,
+                two_way: false,
+                two_way_key: None,
+                on_delete: RelationMutate::Restrict,
+            })
+        );
+
+        let live = [
+            json!({"key": "title", "type": "string", "size": 255, "required": true, "array": false, "default": null}),
+            json!({"key": "views", "type": "integer", "min": -100, "max": 9223372036854775807i64, "required": false, "array": false, "default": null}),
+            json!({"key": "draft", "type": "boolean", "required": false, "array": false}),
+            json!({"key": "comments", "type": "relationship", "side": "child", "relatedCollection": "comments", "relationType": "oneToMany", "twoWay": true, "twoWayKey": "post", "onDelete": "cascade"}),
+        ];
+        let live = vec![
+            CollectionSchema {
+                id: String::from("posts"),
+                name: String::from("Posts"),
+                document_security: false,
+                attributes: live.iter().filter_map(live_attribute).collect(),
+                indexes: vec![
+                    IndexSchema::new("views_index", IndexType::Key, ["views"]),
+                    IndexSchema::new("title_index", IndexType::Fulltext, ["title"]),
+                ],
+            },
+            CollectionSchema::new("drafts", "Drafts"),
+        ];
+        let plan = diff(&schema, Some(live));
+        assert_eq!(
+            plan.to_string(),
+            "+ collection authors\n\
+             - index posts.title_index\n\
+             - attribute posts.title\n\
+             - attribute posts.draft\n\
+             + attribute posts.title\n\
+             + attribute posts.author\n\
+             ~ attribute posts.views\n\
+             + index posts.title_index\n\
+             - collection drafts\n"
+        );
+        assert!(plan.is_destructive());
+
+        let plan = diff(&schema, None);
+        assert_eq!(plan.changes[0].to_string(), "+ database blog");
+        assert!(!plan.is_destructive());
+    }
+
+    #[tokio::test]
+    async fn test_apply_refuses_destructive_plan() {
+        use crate::{client::ClientBuilder, transport::CannedTransport};
+
+        let transport = CannedTransport::new();
+        let client = ClientBuilder::default()
+            .set_transport(transport.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        let plan = Plan {
+            database_id: String::from("blog"),
+            changes: vec![
+                Change::CreateCollection {
+                    id: String::from("authors"),
+                    name: String::from("Authors"),
+                    document_security: false,
+                },
+                Change::DeleteCollection(String::from("drafts")),
+            ],
+        };
+        assert!(matches!(
+            plan.apply(&client, false).await,
+            Err(Error::InvalidParameter {
+                param: "allow_destructive",
+                ..
+            })
+        ));
+        assert!(transport.requests().is_empty());
+    }
+}
@@ -4,6 +4,8 @@
 
 use serde_json::{json, Value};
 
+use futures_util::Stream;
+
 use crate::{
     api_params, app_json_header,
     client::Client,
@@ -14,11 +16,13 @@ use crate::{
     },
     error::Error,
     models::{
-        identity_list::IdentityList, jwt::JWT, log_list::LogList, mfa_challenge::MfaChallenge,
-        mfa_factors::MfaFactors, mfa_recovery_codes::MfaRecoveryCodes, mfa_type::MfaType,
-        preferences::Preferences, session::Session, session_list::SessionList, token::Token,
-        user::User,
+        identity::Identity, identity_list::IdentityList, jwt::JWT, log::Log, log_list::LogList,
+        mfa_challenge::MfaChallenge, mfa_factors::MfaFactors, mfa_recovery_codes::MfaRecoveryCodes,
+        mfa_type::MfaType, preferences::Preferences, session::Session, session_list::SessionList,
+        token::Token, user::User,
     },
+    pagination::paginate,
+    query::{check_queries, Query},
     utils::get_content_header_value,
 };
 
@@ -39,7 +43,7 @@ impl Account {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     //Create account
@@ -73,7 +77,7 @@ impl Account {
             .call(HttpMethod::POST, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update email
@@ -100,7 +104,7 @@ impl Account {
             .call(HttpMethod::PATCH, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List Identities
@@ -108,8 +112,10 @@ impl Account {
     /// Get the list of identities for the currently logged in user.
     pub async fn list_identities(
         client: &Client,
-        queries: Option<String>,
+        queries: Option<Vec<Query>>,
     ) -> Result<IdentityList, Error> {
+        check_queries(&queries)?;
+
         const API_PATH: &str = "/account/identities";
 
         let api_params = api_params! {
@@ -122,7 +128,21 @@ impl Account {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List Identities (stream)
+    ///
+    /// Every result of [`Self::list_identities`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_identities_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Identity, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_identities(client, Some(queries))
+        })
     }
 
     /// Delete Identity
@@ -168,17 +188,16 @@ impl Account {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List logs
     ///
     /// Get the list of latest security activity logs for the currently logged in
     /// user. Each log returns user IP address, location and date and time of log.
-    pub async fn list_logs(
-        client: &Client,
-        queries: Option<Vec<String>>,
-    ) -> Result<LogList, Error> {
+    pub async fn list_logs(client: &Client, queries: Option<Vec<Query>>) -> Result<LogList, Error> {
+        check_queries(&queries)?;
+
         const API_PATH: &str = "/account/logs";
 
         let api_params = api_params! {
@@ -191,7 +210,21 @@ impl Account {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List logs (stream)
+    ///
+    /// Every result of [`Self::list_logs`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_logs_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Log, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_logs(client, Some(queries))
+        })
     }
 
     /// Update MFA
@@ -210,7 +243,7 @@ impl Account {
             .call(HttpMethod::PATCH, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Add Authenticator
@@ -239,7 +272,7 @@ impl Account {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Verify Authenticator
@@ -270,7 +303,7 @@ impl Account {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete Authenticator
@@ -299,7 +332,7 @@ impl Account {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create 2FA Challenge
@@ -323,7 +356,7 @@ impl Account {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create MFA Challenge (confirmation)
@@ -368,7 +401,7 @@ impl Account {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get MFA Recovery Codes
@@ -388,7 +421,7 @@ impl Account {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create MFA Recovery Codes
@@ -409,7 +442,7 @@ impl Account {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Regenerate MFA Recovery Codes
@@ -429,7 +462,7 @@ impl Account {
             .call(HttpMethod::PATCH, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update name
@@ -448,7 +481,7 @@ impl Account {
             .call(HttpMethod::PATCH, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update password
@@ -474,7 +507,7 @@ impl Account {
             .call(HttpMethod::PATCH, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update phone
@@ -498,7 +531,7 @@ impl Account {
             .call(HttpMethod::PATCH, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get account preferences
@@ -515,7 +548,7 @@ impl Account {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update preferences
@@ -536,7 +569,7 @@ impl Account {
             .call(HttpMethod::PATCH, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create password recovery
@@ -563,7 +596,7 @@ impl Account {
             .call(HttpMethod::POST, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create password recovery (confirmation)
@@ -600,7 +633,7 @@ impl Account {
             .call(HttpMethod::PUT, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List sessions
@@ -618,7 +651,7 @@ impl Account {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete sessions
@@ -659,7 +692,7 @@ impl Account {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create email password session
@@ -688,7 +721,7 @@ impl Account {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update magic URL session
@@ -714,7 +747,7 @@ impl Account {
             .call(HttpMethod::PUT, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update phone session
@@ -740,7 +773,7 @@ impl Account {
             .call(HttpMethod::PUT, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create session
@@ -766,7 +799,7 @@ impl Account {
             .call(HttpMethod::PUT, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get session
@@ -792,7 +825,7 @@ impl Account {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update session
@@ -819,7 +852,7 @@ impl Account {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete session
@@ -867,7 +900,7 @@ impl Account {
             .call(HttpMethod::DELETE, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create email token (OTP)
@@ -902,7 +935,7 @@ impl Account {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create magic URL token
@@ -945,7 +978,7 @@ impl Account {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create OAuth2 token
@@ -1025,7 +1058,7 @@ impl Account {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create email verification
@@ -1058,7 +1091,7 @@ impl Account {
             .call(HttpMethod::POST, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create email verification (confirmation)
@@ -1085,7 +1118,7 @@ impl Account {
             .call(HttpMethod::PUT, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create phone verification
@@ -1109,7 +1142,7 @@ impl Account {
             .call(HttpMethod::POST, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create phone verification (confirmation)
@@ -1136,6 +1169,6 @@ impl Account {
             .call(HttpMethod::PUT, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 }
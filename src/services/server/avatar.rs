@@ -5,7 +5,7 @@
 
 use crate::{
     api_params, app_json_header, client::Client, enumm::HttpMethod, enums::flag::Flag,
-    error::Error, utils::get_content_header_value,
+    error::Error, params::avatar::GetInitials, utils::get_content_header_value,
 };
 use serde_json::json;
 
@@ -53,7 +53,7 @@ impl Avatars {
             )
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 
     /// Get credit card icon
@@ -96,7 +96,7 @@ impl Avatars {
             )
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 
     /// Get favicon
@@ -119,7 +119,7 @@ impl Avatars {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 
     /// Get country flag
@@ -163,7 +163,7 @@ impl Avatars {
             )
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 
     /// Get image from URL
@@ -200,7 +200,7 @@ impl Avatars {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 
     /// Get user initials
@@ -245,7 +245,20 @@ impl Avatars {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
+    }
+
+    /// [`Avatars::get_initials`] taking a [`GetInitials`] builder, validated before sending.
+    pub async fn get_initials_with(client: &Client, params: GetInitials) -> Result<Vec<u8>, Error> {
+        params.validate()?;
+        Self::get_initials(
+            client,
+            params.name.as_deref(),
+            params.width,
+            params.height,
+            params.background.as_deref(),
+        )
+        .await
     }
 
     /// Get QR code
@@ -277,6 +290,6 @@ impl Avatars {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 }
@@ -2,6 +2,8 @@
 //!
 //! The Databases service allows you to create structured collections of
 //! documents, query and filter lists of documents
+use futures_util::{Stream, StreamExt};
+
 use crate::{
     api_params, app_json_header,
     client::Client,
@@ -19,12 +21,33 @@ use crate::{
         attribute_url::AttributeUrl, collection::Collection, collection_list::CollectionList,
         database::Database, database_list::DatabaseList, document::Document,
         document_list::DocumentList, index::Index, index_list::IndexList,
+        typed_document::TypedDocument, typed_document_list::TypedDocumentList,
+    },
+    pagination::paginate,
+    params::databases::{
+        CreateBooleanAttribute, CreateDateTimeAttribute, CreateEmailAttribute, CreateEnumAttribute,
+        CreateFloatAttribute, CreateIndex, CreateIntegerAttribute, CreateIpAttribute,
+        CreateRelationshipAttribute, CreateStringAttribute, CreateUrlAttribute,
     },
+    query::{check_queries, Query},
+    wait::WaitOptions,
 };
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::{json, Map, Value};
 
 pub struct Databases;
 
+/// The user fields of a document, serialized from `data`.
+fn document_data(data: &impl Serialize) -> Result<Map<String, Value>, Error> {
+    match serde_json::to_value(data)? {
+        Value::Object(fields) => Ok(fields),
+        _ => Err(Error::InvalidParameter {
+            param: "data",
+            reason: String::from("must serialize to a JSON object"),
+        }),
+    }
+}
+
 impl Databases {
     /// List databases
     ///
@@ -33,8 +56,10 @@ impl Databases {
     pub async fn list(
         client: &Client,
         search: Option<String>,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<DatabaseList, Error> {
+        check_queries(&queries)?;
+
         const API_PATH: &str = "/databases";
 
         let api_params = api_params!(
@@ -48,7 +73,22 @@ impl Databases {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List databases (stream)
+    ///
+    /// Every result of [`Self::list`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_stream<'a>(
+        client: &'a Client,
+        search: Option<String>,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Database, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list(client, search.clone(), Some(queries))
+        })
     }
 
     /// Create database
@@ -75,7 +115,7 @@ impl Databases {
             .call(HttpMethod::POST, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get database
@@ -99,7 +139,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update database
@@ -130,7 +170,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete database
@@ -166,8 +206,10 @@ impl Databases {
         client: &Client,
         database_id: &str,
         search: Option<String>,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<CollectionList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/databases";
         let api_path = "/databases/{databaseId}/collections".replace("{databaseId}", database_id);
 
@@ -188,7 +230,23 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List collections (stream)
+    ///
+    /// Every result of [`Self::list_collections`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_collections_stream<'a>(
+        client: &'a Client,
+        database_id: &'a str,
+        search: Option<String>,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Collection, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_collections(client, database_id, search.clone(), Some(queries))
+        })
     }
 
     /// Create collection
@@ -229,7 +287,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get collection
@@ -260,7 +318,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update collection
@@ -299,7 +357,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete collection
@@ -340,8 +398,10 @@ impl Databases {
         client: &Client,
         database_id: &str,
         collection_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<AttributeList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/databases";
         let api_path = "/databases/{databaseId}/collections/{collectionId}/attributes"
             .replace("{databaseId}", database_id)
@@ -363,7 +423,23 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List attributes (stream)
+    ///
+    /// Every result of [`Self::list_attributes`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_attributes_stream<'a>(
+        client: &'a Client,
+        database_id: &'a str,
+        collection_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_attributes(client, database_id, collection_id, Some(queries))
+        })
     }
 
     /// Create boolean attribute
@@ -403,7 +479,26 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_boolean_attribute`] taking a [`CreateBooleanAttribute`] builder, validated before sending.
+    pub async fn create_boolean_attribute_with(
+        client: &Client,
+        params: CreateBooleanAttribute,
+    ) -> Result<AttributeBoolean, Error> {
+        params.validate()?;
+        let attribute = Self::create_boolean_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.required,
+            params.default,
+            params.array,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update boolean attribute
@@ -442,7 +537,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create datetime attribute
@@ -454,7 +549,7 @@ impl Databases {
         collection_id: &str,
         key: &str,
         x_required: bool,
-        x_default: Option<bool>,
+        x_default: Option<&str>,
         array: Option<bool>,
     ) -> Result<AttributeDateTime, Error> {
         //const API_PATH: &str = "/databases";
@@ -481,7 +576,26 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_date_time_attribute`] taking a [`CreateDateTimeAttribute`] builder, validated before sending.
+    pub async fn create_date_time_attribute_with(
+        client: &Client,
+        params: CreateDateTimeAttribute,
+    ) -> Result<AttributeDateTime, Error> {
+        params.validate()?;
+        let attribute = Self::create_date_time_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.required,
+            params.default.as_deref(),
+            params.array,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update dateTime attribute
@@ -494,7 +608,7 @@ impl Databases {
         collection_id: &str,
         key: &str,
         x_required: bool,
-        x_default: Option<bool>,
+        x_default: Option<&str>,
     ) -> Result<AttributeDateTime, Error> {
         //const API_PATH: &str = "/databases";
         let api_path =
@@ -520,7 +634,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create email attribute
@@ -560,7 +674,26 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_email_attribute`] taking a [`CreateEmailAttribute`] builder, validated before sending.
+    pub async fn create_email_attribute_with(
+        client: &Client,
+        params: CreateEmailAttribute,
+    ) -> Result<AttributeEmail, Error> {
+        params.validate()?;
+        let attribute = Self::create_email_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.required,
+            params.default.as_deref(),
+            params.array,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update email attribute
@@ -599,7 +732,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create enum attribute
@@ -611,7 +744,7 @@ impl Databases {
         key: &str,
         elements: Vec<&str>,
         x_required: bool,
-        x_default: Option<bool>,
+        x_default: Option<&str>,
         array: Option<bool>,
     ) -> Result<AttributeEnum, Error> {
         //const API_PATH: &str = "/databases";
@@ -639,7 +772,27 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_enum_attribute`] taking a [`CreateEnumAttribute`] builder, validated before sending.
+    pub async fn create_enum_attribute_with(
+        client: &Client,
+        params: CreateEnumAttribute,
+    ) -> Result<AttributeEnum, Error> {
+        params.validate()?;
+        let attribute = Self::create_enum_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.elements.iter().map(String::as_str).collect(),
+            params.required,
+            params.default.as_deref(),
+            params.array,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update enum attribute
@@ -654,7 +807,7 @@ impl Databases {
         key: &str,
         elements: Vec<&str>,
         x_required: bool,
-        x_default: Option<bool>,
+        x_default: Option<&str>,
     ) -> Result<AttributeEnum, Error> {
         //const API_PATH: &str = "/databases";
         let api_path = "/databases/{databaseId}/collections/{collectionId}/attributes/enum/{key}"
@@ -680,7 +833,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create float attribute
@@ -725,7 +878,28 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_float_attribute`] taking a [`CreateFloatAttribute`] builder, validated before sending.
+    pub async fn create_float_attribute_with(
+        client: &Client,
+        params: CreateFloatAttribute,
+    ) -> Result<AttributeFloat, Error> {
+        params.validate()?;
+        let attribute = Self::create_float_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.required,
+            params.min,
+            params.max,
+            params.default,
+            params.array,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update float attribute
@@ -768,7 +942,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create integer attribute
@@ -782,9 +956,9 @@ impl Databases {
         collection_id: &str,
         key: &str,
         x_required: bool,
-        min: Option<u64>,
-        max: Option<u64>,
-        x_default: Option<u64>,
+        min: Option<i64>,
+        max: Option<i64>,
+        x_default: Option<i64>,
         array: Option<bool>,
     ) -> Result<AttributeInteger, Error> {
         //const API_PATH: &str = "/databases";
@@ -813,7 +987,28 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_integer_attribute`] taking a [`CreateIntegerAttribute`] builder, validated before sending.
+    pub async fn create_integer_attribute_with(
+        client: &Client,
+        params: CreateIntegerAttribute,
+    ) -> Result<AttributeInteger, Error> {
+        params.validate()?;
+        let attribute = Self::create_integer_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.required,
+            params.min,
+            params.max,
+            params.default,
+            params.array,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update integer attribute
@@ -826,10 +1021,10 @@ impl Databases {
         database_id: &str,
         collection_id: &str,
         key: &str,
-        min: Option<u64>,
-        max: Option<u64>,
+        min: Option<i64>,
+        max: Option<i64>,
         x_required: bool,
-        x_default: Option<u64>,
+        x_default: Option<i64>,
     ) -> Result<AttributeInteger, Error> {
         //const API_PATH: &str = "/databases";
         let api_path =
@@ -857,7 +1052,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create IP address attribute
@@ -897,7 +1092,26 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_ip_attribute`] taking a [`CreateIpAttribute`] builder, validated before sending.
+    pub async fn create_ip_attribute_with(
+        client: &Client,
+        params: CreateIpAttribute,
+    ) -> Result<AttributeIp, Error> {
+        params.validate()?;
+        let attribute = Self::create_ip_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.required,
+            params.default.as_deref(),
+            params.array,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update IP address attribute
@@ -936,7 +1150,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create relationship attribute
@@ -981,7 +1195,29 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_relationship_attribute`] taking a [`CreateRelationshipAttribute`] builder, validated before sending.
+    pub async fn create_relationship_attribute_with(
+        client: &Client,
+        params: CreateRelationshipAttribute,
+    ) -> Result<AttributeRelationship, Error> {
+        params.validate()?;
+        let attribute = Self::create_relationship_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.related_collection_id,
+            params.relationship_type.clone(),
+            params.two_way,
+            params.key.as_deref(),
+            params.two_way_key.as_deref(),
+            params.on_delete.clone(),
+        )
+        .await?;
+        let key = attribute.key.clone();
+        waited(client, &params, &key, attribute).await
     }
 
     /// Create string attribute
@@ -1025,7 +1261,28 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_string_attribute`] taking a [`CreateStringAttribute`] builder, validated before sending.
+    pub async fn create_string_attribute_with(
+        client: &Client,
+        params: CreateStringAttribute,
+    ) -> Result<AttributeString, Error> {
+        params.validate()?;
+        let attribute = Self::create_string_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.size,
+            params.required,
+            params.default.as_deref(),
+            params.array,
+            params.encrypt,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update string attribute
@@ -1070,7 +1327,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create URL attribute
@@ -1110,7 +1367,26 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_url_attribute`] taking a [`CreateUrlAttribute`] builder, validated before sending.
+    pub async fn create_url_attribute_with(
+        client: &Client,
+        params: CreateUrlAttribute,
+    ) -> Result<AttributeUrl, Error> {
+        params.validate()?;
+        let attribute = Self::create_url_attribute(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.required,
+            params.default.as_deref(),
+            params.array,
+        )
+        .await?;
+        waited(client, &params, &params.key, attribute).await
     }
 
     /// Update URL attribute
@@ -1149,7 +1425,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get attribute
@@ -1181,7 +1457,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json::<Value>().await?)
+        res.json::<Value>()
     }
 
     /// Delete attribute
@@ -1251,7 +1527,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List documents
@@ -1262,8 +1538,10 @@ impl Databases {
         client: &Client,
         database_id: &str,
         collection_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<DocumentList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/databases";
         let api_path = "/databases/{databaseId}/collections/{collectionId}/documents"
             .replace("{databaseId}", database_id)
@@ -1285,7 +1563,23 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List documents (stream)
+    ///
+    /// Every result of [`Self::list_documents`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_documents_stream<'a>(
+        client: &'a Client,
+        database_id: &'a str,
+        collection_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Document, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_documents(client, database_id, collection_id, Some(queries))
+        })
     }
 
     /// Create document
@@ -1326,7 +1620,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get document
@@ -1338,8 +1632,10 @@ impl Databases {
         database_id: &str,
         collection_id: &str,
         document_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<Document, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/databases";
         let api_path = "/databases/{databaseId}/collections/{collectionId}/documents/{documentId}"
             .replace("{databaseId}", database_id)
@@ -1354,7 +1650,7 @@ impl Databases {
 
         let res = client
             .call(
-                HttpMethod::POST,
+                HttpMethod::GET,
                 api_path.as_str(),
                 api_headers,
                 &api_params,
@@ -1362,7 +1658,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update document
@@ -1400,7 +1696,105 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// Get document as `T`
+    ///
+    /// [`Self::get_document`], with the user fields deserialized into `T`.
+    pub async fn get_document_as<T: DeserializeOwned>(
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<TypedDocument<T>, Error> {
+        Self::get_document(client, database_id, collection_id, document_id, queries)
+            .await?
+            .into_typed()
+    }
+
+    /// List documents as `T`
+    ///
+    /// [`Self::list_documents`], with the user fields deserialized into `T`.
+    pub async fn list_documents_as<T: DeserializeOwned>(
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<TypedDocumentList<T>, Error> {
+        let list = Self::list_documents(client, database_id, collection_id, queries).await?;
+        Ok(TypedDocumentList {
+            total: list.total,
+            documents: list
+                .documents
+                .into_iter()
+                .map(Document::into_typed)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// List documents as `T` (stream)
+    ///
+    /// [`Self::list_documents_stream`], with the user fields deserialized
+    /// into `T`.
+    pub fn list_documents_as_stream<'a, T: DeserializeOwned + 'a>(
+        client: &'a Client,
+        database_id: &'a str,
+        collection_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<TypedDocument<T>, Error>> + 'a {
+        Self::list_documents_stream(client, database_id, collection_id, queries, page_size)
+            .map(|document| document?.into_typed())
+    }
+
+    /// Create document from `T`
+    ///
+    /// [`Self::create_documents`], with the user fields serialized from `data`.
+    pub async fn create_document_as<T: Serialize + DeserializeOwned>(
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        data: &T,
+        permissions: Option<Vec<String>>,
+    ) -> Result<TypedDocument<T>, Error> {
+        Self::create_documents(
+            client,
+            database_id,
+            collection_id,
+            document_id,
+            document_data(data)?,
+            permissions,
+        )
+        .await?
+        .into_typed()
+    }
+
+    /// Update document as `T`
+    ///
+    /// [`Self::update_document`], with the changed fields serialized from
+    /// `data` (a `T` or any struct holding a subset of its fields) and the
+    /// result deserialized into `T`.
+    pub async fn update_document_as<T: DeserializeOwned>(
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        data: &impl Serialize,
+        permissions: Option<Vec<String>>,
+    ) -> Result<TypedDocument<T>, Error> {
+        Self::update_document(
+            client,
+            database_id,
+            collection_id,
+            document_id,
+            Some(document_data(data)?),
+            permissions,
+        )
+        .await?
+        .into_typed()
     }
 
     /// Delete document
@@ -1442,8 +1836,10 @@ impl Databases {
         client: &Client,
         database_id: &str,
         collection_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<IndexList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/databases";
         let api_path = "/databases/{databaseId}/collections/{collectionId}/indexes"
             .replace("{databaseId}", database_id)
@@ -1465,7 +1861,23 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List indexes (stream)
+    ///
+    /// Every result of [`Self::list_indexes`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_indexes_stream<'a>(
+        client: &'a Client,
+        database_id: &'a str,
+        collection_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Index, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_indexes(client, database_id, collection_id, Some(queries))
+        })
     }
 
     /// Create index
@@ -1506,7 +1918,37 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Databases::create_index`] taking a [`CreateIndex`] builder, validated before sending.
+    pub async fn create_index_with(client: &Client, params: CreateIndex) -> Result<Index, Error> {
+        params.validate()?;
+        let index = Self::create_index(
+            client,
+            &params.database_id,
+            &params.collection_id,
+            &params.key,
+            params.index_type,
+            params.attributes.iter().map(String::as_str).collect(),
+            params
+                .orders
+                .as_ref()
+                .map(|orders| orders.iter().map(String::as_str).collect()),
+        )
+        .await?;
+        let Some(options) = params.wait else {
+            return Ok(index);
+        };
+        let (database_id, collection_id) = (&params.database_id, &params.collection_id);
+        Self::wait_for_index(
+            client,
+            database_id,
+            collection_id,
+            &params.key,
+            Some(options),
+        )
+        .await
     }
 
     /// Get index
@@ -1538,7 +1980,7 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete index
@@ -1570,6 +2012,62 @@ impl Databases {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 }
+
+/// An attribute builder, read by [`waited`].
+trait AttributeParams {
+    fn collection(&self) -> (&str, &str);
+    fn wait_options(&self) -> Option<&WaitOptions>;
+}
+
+macro_rules! attribute_params {
+    ($($name:ident),* $(,)?) => {$(
+        impl AttributeParams for $name {
+            fn collection(&self) -> (&str, &str) {
+                (&self.database_id, &self.collection_id)
+            }
+
+            fn wait_options(&self) -> Option<&WaitOptions> {
+                self.wait.as_ref()
+            }
+        }
+    )*};
+}
+
+attribute_params!(
+    CreateBooleanAttribute,
+    CreateDateTimeAttribute,
+    CreateEmailAttribute,
+    CreateEnumAttribute,
+    CreateFloatAttribute,
+    CreateIntegerAttribute,
+    CreateIpAttribute,
+    CreateRelationshipAttribute,
+    CreateStringAttribute,
+    CreateUrlAttribute,
+);
+
+/// Attribute `key` as it is once available if `params` asks to wait, else
+/// `created`.
+async fn waited<T: DeserializeOwned>(
+    client: &Client,
+    params: &impl AttributeParams,
+    key: &str,
+    created: T,
+) -> Result<T, Error> {
+    let Some(options) = params.wait_options() else {
+        return Ok(created);
+    };
+    let (database_id, collection_id) = params.collection();
+    let attribute = Databases::wait_for_attribute(
+        client,
+        database_id,
+        collection_id,
+        key,
+        Some(options.clone()),
+    )
+    .await?;
+    Ok(serde_json::from_value(attribute)?)
+}
@@ -17,6 +17,9 @@ use crate::{
         execution_list::ExecutionList, function::Func, function_list::FunctionList,
         runtime_list::RuntimeList, variable::Variable, variable_list::VariableList, UploadType,
     },
+    pagination::paginate,
+    params::functions::{CreateExecution, CreateFunction, UpdateFunction},
+    query::{check_queries, Query},
     upload_progress::UploadProgress,
     utils::get_content_header_value,
 };
@@ -30,9 +33,11 @@ impl Functions {
     /// filter your results.
     pub async fn list(
         client: &Client,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<FunctionList, Error> {
+        check_queries(&queries)?;
+
         const API_PATH: &str = "/functions";
 
         let api_params = api_params!(
@@ -46,7 +51,22 @@ impl Functions {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List functions (stream)
+    ///
+    /// Every result of [`Self::list`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Func, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list(client, Some(queries), search.clone())
+        })
     }
 
     /// Create function
@@ -109,7 +129,42 @@ impl Functions {
             .call(HttpMethod::POST, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Functions::create`] taking a [`CreateFunction`] builder, validated before sending.
+    pub async fn create_with(client: &Client, params: CreateFunction) -> Result<Func, Error> {
+        params.validate()?;
+        Self::create(
+            client,
+            &params.function_id,
+            &params.name,
+            params.runtime,
+            params
+                .execute
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .events
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.schedule.as_deref(),
+            params.timeout,
+            params.enabled,
+            params.logging,
+            params.entry_point.as_deref(),
+            params.commands.as_deref(),
+            params.installation_id.as_deref(),
+            params.provider_repository_id.as_deref(),
+            params.provider_branch.as_deref(),
+            params.provider_silent_mode,
+            params.provider_root_directory.as_deref(),
+            params.template_repository.as_deref(),
+            params.template_owner.as_deref(),
+            params.template_root_directory.as_deref(),
+            params.template_branch.as_deref(),
+        )
+        .await
     }
 
     /// List runtimes
@@ -127,7 +182,7 @@ impl Functions {
             .call(HttpMethod::GET, API_PATH, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get function
@@ -151,7 +206,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update function
@@ -209,7 +264,38 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Functions::update`] taking a [`UpdateFunction`] builder, validated before sending.
+    pub async fn update_with(client: &Client, params: UpdateFunction) -> Result<Func, Error> {
+        params.validate()?;
+        Self::update(
+            client,
+            &params.function_id,
+            &params.name,
+            params.runtime,
+            params
+                .execute
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .events
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.schedule.as_deref(),
+            params.timeout,
+            params.enabled,
+            params.logging,
+            params.entry_point.as_deref(),
+            params.commands.as_deref(),
+            params.installation_id.as_deref(),
+            params.provider_repository_id.as_deref(),
+            params.provider_branch.as_deref(),
+            params.provider_silent_mode,
+            params.provider_root_directory.as_deref(),
+        )
+        .await
     }
 
     /// Delete function
@@ -243,9 +329,11 @@ impl Functions {
     pub async fn list_deployments(
         client: &Client,
         function_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<DeploymentList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/functions/{functionId}/deployments".replace("{functionId}", function_id);
 
@@ -266,7 +354,23 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List deployments (stream)
+    ///
+    /// Every result of [`Self::list_deployments`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_deployments_stream<'a>(
+        client: &'a Client,
+        function_id: &'a str,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Deployment, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_deployments(client, function_id, Some(queries), search.clone())
+        })
     }
 
     /// Create deployment
@@ -389,7 +493,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update function deployment
@@ -421,7 +525,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete deployment
@@ -484,7 +588,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Download Deployment
@@ -518,7 +622,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 
     /// List executions
@@ -528,9 +632,11 @@ impl Functions {
     pub async fn list_executions(
         client: &Client,
         function_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<ExecutionList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/functions/{functionId}/executions".replace("{functionId}", function_id);
 
@@ -551,7 +657,23 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List executions (stream)
+    ///
+    /// Every result of [`Self::list_executions`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_executions_stream<'a>(
+        client: &'a Client,
+        function_id: &'a str,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Execution, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_executions(client, function_id, Some(queries), search.clone())
+        })
     }
 
     /// Create execution
@@ -592,7 +714,25 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Functions::create_executions`] taking a [`CreateExecution`] builder, validated before sending.
+    pub async fn create_executions_with(
+        client: &Client,
+        params: CreateExecution,
+    ) -> Result<Execution, Error> {
+        params.validate()?;
+        Self::create_executions(
+            client,
+            &params.function_id,
+            params.body.as_deref(),
+            params.x_async,
+            params.path.as_deref(),
+            params.method,
+            params.headers,
+        )
+        .await
     }
 
     /// Get execution
@@ -622,7 +762,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List variables
@@ -646,7 +786,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create variable
@@ -679,7 +819,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get variable
@@ -709,7 +849,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update variable
@@ -744,7 +884,7 @@ impl Functions {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete variable
@@ -29,7 +29,7 @@ impl Graphql {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// GraphQL endpoint
@@ -51,6 +51,6 @@ impl Graphql {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 }
@@ -35,7 +35,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get antivirus
@@ -53,7 +53,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get cache
@@ -72,7 +72,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get the SSL certificate for a domain
@@ -95,7 +95,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get DB
@@ -113,7 +113,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get pubsub
@@ -131,7 +131,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get queue
@@ -150,7 +150,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get builds queue
@@ -174,7 +174,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get certificates queue
@@ -199,7 +199,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get databases queue
@@ -225,7 +225,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get deletes queue
@@ -249,7 +249,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get number of failed queue jobs
@@ -280,7 +280,7 @@ impl Health {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get functions queue
@@ -304,7 +304,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get logs queue
@@ -328,7 +328,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get mails queue
@@ -352,7 +352,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get messaging queue
@@ -376,7 +376,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get migrations queue
@@ -400,7 +400,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get usage queue
@@ -424,7 +424,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get usage dump queue
@@ -448,7 +448,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get webhooks queue
@@ -472,7 +472,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get storage
@@ -490,7 +490,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get local storage
@@ -508,7 +508,7 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get time
@@ -532,6 +532,6 @@ impl Health {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 }
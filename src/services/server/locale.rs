@@ -37,7 +37,7 @@ impl Locale {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List Locale Codes
@@ -56,7 +56,7 @@ impl Locale {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List continents
@@ -75,7 +75,7 @@ impl Locale {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List countries
@@ -94,7 +94,7 @@ impl Locale {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List EU countries
@@ -113,7 +113,7 @@ impl Locale {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List countries phone codes
@@ -132,7 +132,7 @@ impl Locale {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List currencies
@@ -152,7 +152,7 @@ impl Locale {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List languages
@@ -171,6 +171,6 @@ impl Locale {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 }
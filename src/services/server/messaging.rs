@@ -5,6 +5,8 @@
 
 use serde_json::{Map, Value};
 
+use futures_util::Stream;
+
 use crate::{
     api_params, app_json_header,
     client::Client,
@@ -12,10 +14,21 @@ use crate::{
     enums::smtp_encryption::SmtpEncryption,
     error::Error,
     models::{
-        log_list::LogList, message::Message, message_list::MessageList, provider::Provider,
-        provider_list::ProviderList, subscriber::Subscriber, subscriber_list::SubscriberList,
-        target_list::TargetList, topic::Topic, topic_list::TopicList,
+        log::Log, log_list::LogList, message::Message, message_list::MessageList,
+        provider::Provider, provider_list::ProviderList, subscriber::Subscriber,
+        subscriber_list::SubscriberList, target::Target, target_list::TargetList, topic::Topic,
+        topic_list::TopicList,
+    },
+    pagination::paginate,
+    params::messaging::{
+        CreateApnsProvider, CreateEmail, CreateMailGunProvider, CreateMsg91Provider, CreatePush,
+        CreateSendGridProvider, CreateSms, CreateSmtpProvider, CreateTelesignProvider,
+        CreateTextMagicProvider, CreateTwilioProvider, CreateVonageProvider, UpdateApnsProvider,
+        UpdateEmail, UpdateMailGunProvider, UpdateMsg91Provider, UpdatePush,
+        UpdateSendGridProvider, UpdateSms, UpdateSmtpProvider, UpdateTelesignProvider,
+        UpdateTextMagicProvider, UpdateTwilioProvider, UpdateVonageProvider,
     },
+    query::{check_queries, Query},
 };
 
 pub struct Messaging;
@@ -26,9 +39,11 @@ impl Messaging {
     /// Get a list of all messages from the current Appwrite project.
     pub async fn list_messages(
         client: &Client,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<MessageList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/messaging/messages";
 
@@ -43,7 +58,22 @@ impl Messaging {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List messages (stream)
+    ///
+    /// Every result of [`Self::list_messages`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_messages_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Message, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_messages(client, Some(queries), search.clone())
+        })
     }
 
     /// Create email
@@ -88,7 +118,46 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_email`] taking a [`CreateEmail`] builder, validated before sending.
+    pub async fn create_email_with(client: &Client, params: CreateEmail) -> Result<Message, Error> {
+        params.validate()?;
+        Self::create_email(
+            client,
+            &params.message_id,
+            &params.subject,
+            &params.content,
+            params
+                .topics
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .users
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .targets
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .cc
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .bcc
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .attachments
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.draft,
+            params.html,
+            params.scheduled_at,
+        )
+        .await
     }
 
     /// Update email
@@ -137,7 +206,42 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_email`] taking a [`UpdateEmail`] builder, validated before sending.
+    pub async fn update_email_with(client: &Client, params: UpdateEmail) -> Result<Message, Error> {
+        params.validate()?;
+        Self::update_email(
+            client,
+            &params.message_id,
+            params
+                .topics
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .users
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .targets
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.subject.as_deref(),
+            params.content.as_deref(),
+            params.draft,
+            params.html,
+            params
+                .cc
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .bcc
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.scheduled_at.as_deref(),
+        )
+        .await
     }
 
     /// Create push notification
@@ -189,7 +293,41 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_push`] taking a [`CreatePush`] builder, validated before sending.
+    pub async fn create_push_with(client: &Client, params: CreatePush) -> Result<Message, Error> {
+        params.validate()?;
+        Self::create_push(
+            client,
+            &params.message_id,
+            &params.title,
+            &params.body,
+            params
+                .topics
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .users
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .targets
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.data,
+            params.action.as_deref(),
+            params.image.as_deref(),
+            params.icon.as_deref(),
+            params.sound.as_deref(),
+            params.color.as_deref(),
+            params.tag.as_deref(),
+            params.badge.as_deref(),
+            params.draft,
+            params.scheduled_at.as_deref(),
+        )
+        .await
     }
 
     /// Update push notification
@@ -247,7 +385,41 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_push`] taking a [`UpdatePush`] builder, validated before sending.
+    pub async fn update_push_with(client: &Client, params: UpdatePush) -> Result<Message, Error> {
+        params.validate()?;
+        Self::update_push(
+            client,
+            &params.message_id,
+            params
+                .topics
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .users
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .targets
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.title.as_deref(),
+            params.body.as_deref(),
+            params.data,
+            params.action.as_deref(),
+            params.image.as_deref(),
+            params.icon.as_deref(),
+            params.sound.as_deref(),
+            params.color.as_deref(),
+            params.tag.as_deref(),
+            params.badge,
+            params.draft,
+            params.scheduled_at.as_deref(),
+        )
+        .await
     }
 
     /// Create SMS
@@ -282,7 +454,32 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_sms`] taking a [`CreateSms`] builder, validated before sending.
+    pub async fn create_sms_with(client: &Client, params: CreateSms) -> Result<Message, Error> {
+        params.validate()?;
+        Self::create_sms(
+            client,
+            &params.message_id,
+            &params.content,
+            params
+                .topics
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .users
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .targets
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.draft,
+            params.scheduled_at.as_deref(),
+        )
+        .await
     }
 
     /// Update SMS
@@ -323,7 +520,32 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_sms`] taking a [`UpdateSms`] builder, validated before sending.
+    pub async fn update_sms_with(client: &Client, params: UpdateSms) -> Result<Message, Error> {
+        params.validate()?;
+        Self::update_sms(
+            client,
+            &params.message_id,
+            params
+                .topics
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .users
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params
+                .targets
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.content.as_deref(),
+            params.draft,
+            params.scheduled_at.as_deref(),
+        )
+        .await
     }
 
     /// Get message
@@ -348,7 +570,7 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete message
@@ -382,8 +604,10 @@ impl Messaging {
     pub async fn list_message_logs(
         client: &Client,
         message_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<LogList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = format!("/messaging/messages/{}/logs", message_id);
 
@@ -403,7 +627,22 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List message logs (stream)
+    ///
+    /// Every result of [`Self::list_message_logs`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_message_logs_stream<'a>(
+        client: &'a Client,
+        message_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Log, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_message_logs(client, message_id, Some(queries))
+        })
     }
 
     /// List message targets
@@ -412,8 +651,10 @@ impl Messaging {
     pub async fn list_targets(
         client: &Client,
         message_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<TargetList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = format!("/messaging/messages/{}/targets", message_id);
 
@@ -433,7 +674,22 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List message targets (stream)
+    ///
+    /// Every result of [`Self::list_targets`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_targets_stream<'a>(
+        client: &'a Client,
+        message_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Target, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_targets(client, message_id, Some(queries))
+        })
     }
 
     /// List providers
@@ -441,9 +697,11 @@ impl Messaging {
     /// Get a list of all providers from the current Appwrite project.
     pub async fn list_providers(
         client: &Client,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<Vec<&str>>,
     ) -> Result<ProviderList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/messaging/providers";
 
@@ -458,7 +716,22 @@ impl Messaging {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List providers (stream)
+    ///
+    /// Every result of [`Self::list_providers`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_providers_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        search: Option<Vec<&'a str>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Provider, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_providers(client, Some(queries), search.clone())
+        })
     }
 
     /// Create APNS provider
@@ -495,7 +768,27 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_apns_provider`] taking a [`CreateApnsProvider`] builder, validated before sending.
+    pub async fn create_apns_provider_with(
+        client: &Client,
+        params: CreateApnsProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_apns_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            params.auth_key.as_deref(),
+            params.auth_key_id.as_deref(),
+            params.team_id.as_deref(),
+            params.bundle_id.as_deref(),
+            params.sandbox,
+            params.enabled,
+        )
+        .await
     }
 
     /// Update APNS provider
@@ -537,7 +830,27 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_apns_provider`] taking a [`UpdateApnsProvider`] builder, validated before sending.
+    pub async fn update_apns_provider_with(
+        client: &Client,
+        params: UpdateApnsProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_apns_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.enabled,
+            params.auth_key.as_deref(),
+            params.auth_key_id.as_deref(),
+            params.team_id.as_deref(),
+            params.bundle_id.as_deref(),
+            params.sandbox,
+        )
+        .await
     }
 
     /// Create FCM provider
@@ -566,7 +879,7 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update FCM provider
@@ -600,7 +913,7 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create Mailgun provider
@@ -641,7 +954,29 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_mail_gun_provider`] taking a [`CreateMailGunProvider`] builder, validated before sending.
+    pub async fn create_mail_gun_provider_with(
+        client: &Client,
+        params: CreateMailGunProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_mail_gun_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            params.api_key.as_deref(),
+            params.domain.as_deref(),
+            params.is_eu_region,
+            params.from_name.as_deref(),
+            params.from_email.as_deref(),
+            params.reply_to_name.as_deref(),
+            params.reply_to_email.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Update Mailgun provider
@@ -687,7 +1022,29 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_mail_gun_provider`] taking a [`UpdateMailGunProvider`] builder, validated before sending.
+    pub async fn update_mail_gun_provider_with(
+        client: &Client,
+        params: UpdateMailGunProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_mail_gun_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.api_key.as_deref(),
+            params.domain.as_deref(),
+            params.is_eu_region,
+            params.enabled,
+            params.from_name.as_deref(),
+            params.from_email.as_deref(),
+            params.reply_to_name.as_deref(),
+            params.reply_to_email.as_deref(),
+        )
+        .await
     }
 
     /// Create Msg91 provider
@@ -720,7 +1077,25 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_msg_91_provider`] taking a [`CreateMsg91Provider`] builder, validated before sending.
+    pub async fn create_msg_91_provider_with(
+        client: &Client,
+        params: CreateMsg91Provider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_msg_91_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            params.template_id.as_deref(),
+            params.sender_id.as_deref(),
+            params.auth_key.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Update Msg91 provider
@@ -758,7 +1133,25 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_msg_91_provider`] taking a [`UpdateMsg91Provider`] builder, validated before sending.
+    pub async fn update_msg_91_provider_with(
+        client: &Client,
+        params: UpdateMsg91Provider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_msg_91_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.enabled,
+            params.template_id.as_deref(),
+            params.sender_id.as_deref(),
+            params.auth_key.as_deref(),
+        )
+        .await
     }
 
     /// Create Sendgrid provider
@@ -795,7 +1188,27 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_send_grid_provider`] taking a [`CreateSendGridProvider`] builder, validated before sending.
+    pub async fn create_send_grid_provider_with(
+        client: &Client,
+        params: CreateSendGridProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_send_grid_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            params.api_key.as_deref(),
+            params.from_name.as_deref(),
+            params.from_email.as_deref(),
+            params.reply_to_name.as_deref(),
+            params.reply_to_email.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Update Sendgrid provider
@@ -837,7 +1250,27 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_send_grid_provider`] taking a [`UpdateSendGridProvider`] builder, validated before sending.
+    pub async fn update_send_grid_provider_with(
+        client: &Client,
+        params: UpdateSendGridProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_send_grid_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.enabled,
+            params.api_key.as_deref(),
+            params.from_name.as_deref(),
+            params.from_email.as_deref(),
+            params.reply_to_name.as_deref(),
+            params.reply_to_email.as_deref(),
+        )
+        .await
     }
 
     /// Create SMTP provider
@@ -886,7 +1319,33 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_smtp_provider`] taking a [`CreateSmtpProvider`] builder, validated before sending.
+    pub async fn create_smtp_provider_with(
+        client: &Client,
+        params: CreateSmtpProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_smtp_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            &params.host,
+            params.port,
+            params.username.as_deref(),
+            params.password.as_deref(),
+            params.encryption,
+            params.auto_tls,
+            params.mailer.as_deref(),
+            params.from_name.as_deref(),
+            params.from_email.as_deref(),
+            params.reply_to_name.as_deref(),
+            params.reply_to_email.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Update SMTP provider
@@ -940,7 +1399,33 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_smtp_provider`] taking a [`UpdateSmtpProvider`] builder, validated before sending.
+    pub async fn update_smtp_provider_with(
+        client: &Client,
+        params: UpdateSmtpProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_smtp_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.host.as_deref(),
+            params.port,
+            params.username.as_deref(),
+            params.password.as_deref(),
+            params.encryption,
+            params.auto_tls,
+            params.mailer.as_deref(),
+            params.from_name.as_deref(),
+            params.from_email.as_deref(),
+            params.reply_to_name.as_deref(),
+            params.reply_to_email.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Create Telesign provider
@@ -973,7 +1458,25 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_telesign_provider`] taking a [`CreateTelesignProvider`] builder, validated before sending.
+    pub async fn create_telesign_provider_with(
+        client: &Client,
+        params: CreateTelesignProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_telesign_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            params.from.as_deref(),
+            params.customer_id.as_deref(),
+            params.api_key.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Update Telesign provider
@@ -1011,7 +1514,25 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_telesign_provider`] taking a [`UpdateTelesignProvider`] builder, validated before sending.
+    pub async fn update_telesign_provider_with(
+        client: &Client,
+        params: UpdateTelesignProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_telesign_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.enabled,
+            params.customer_id.as_deref(),
+            params.api_key.as_deref(),
+            params.from.as_deref(),
+        )
+        .await
     }
 
     /// Create Textmagic provider
@@ -1044,7 +1565,25 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_text_magic_provider`] taking a [`CreateTextMagicProvider`] builder, validated before sending.
+    pub async fn create_text_magic_provider_with(
+        client: &Client,
+        params: CreateTextMagicProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_text_magic_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            params.from.as_deref(),
+            params.username.as_deref(),
+            params.api_key.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Update Textmagic provider
@@ -1082,7 +1621,25 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_text_magic_provider`] taking a [`UpdateTextMagicProvider`] builder, validated before sending.
+    pub async fn update_text_magic_provider_with(
+        client: &Client,
+        params: UpdateTextMagicProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_text_magic_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.enabled,
+            params.username.as_deref(),
+            params.api_key.as_deref(),
+            params.from.as_deref(),
+        )
+        .await
     }
 
     /// Create Twilio provider
@@ -1115,7 +1672,25 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_twilio_provider`] taking a [`CreateTwilioProvider`] builder, validated before sending.
+    pub async fn create_twilio_provider_with(
+        client: &Client,
+        params: CreateTwilioProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_twilio_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            params.from.as_deref(),
+            params.account_id.as_deref(),
+            params.auth_token.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Update Twilio provider
@@ -1153,7 +1728,25 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_twilio_provider`] taking a [`UpdateTwilioProvider`] builder, validated before sending.
+    pub async fn update_twilio_provider_with(
+        client: &Client,
+        params: UpdateTwilioProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_twilio_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.enabled,
+            params.accounts_id.as_deref(),
+            params.auth_token.as_deref(),
+            params.from.as_deref(),
+        )
+        .await
     }
 
     /// Create Vonage provider
@@ -1186,7 +1779,25 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::create_vonage_provider`] taking a [`CreateVonageProvider`] builder, validated before sending.
+    pub async fn create_vonage_provider_with(
+        client: &Client,
+        params: CreateVonageProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::create_vonage_provider(
+            client,
+            &params.provider_id,
+            &params.name,
+            params.from.as_deref(),
+            params.api_key.as_deref(),
+            params.api_secret.as_deref(),
+            params.enabled,
+        )
+        .await
     }
 
     /// Update Vonage provider
@@ -1224,7 +1835,25 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Messaging::update_vonage_provider`] taking a [`UpdateVonageProvider`] builder, validated before sending.
+    pub async fn update_vonage_provider_with(
+        client: &Client,
+        params: UpdateVonageProvider,
+    ) -> Result<Provider, Error> {
+        params.validate()?;
+        Self::update_vonage_provider(
+            client,
+            &params.provider_id,
+            params.name.as_deref(),
+            params.enabled,
+            params.api_key.as_deref(),
+            params.api_secret.as_deref(),
+            params.from.as_deref(),
+        )
+        .await
     }
 
     /// Get provider
@@ -1249,7 +1878,7 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete provider
@@ -1282,8 +1911,10 @@ impl Messaging {
     pub async fn list_provider_logs(
         client: &Client,
         provider_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<LogList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = format!("/messaging/providers/{}/logs", provider_id);
 
@@ -1303,7 +1934,22 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List provider logs (stream)
+    ///
+    /// Every result of [`Self::list_provider_logs`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_provider_logs_stream<'a>(
+        client: &'a Client,
+        provider_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Log, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_provider_logs(client, provider_id, Some(queries))
+        })
     }
 
     /// List subscriber logs
@@ -1312,8 +1958,10 @@ impl Messaging {
     pub async fn list_subscriber_logs(
         client: &Client,
         subscriber_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<LogList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = format!("/messaging/subscribers/{}/logs", subscriber_id);
 
@@ -1333,7 +1981,22 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List subscriber logs (stream)
+    ///
+    /// Every result of [`Self::list_subscriber_logs`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_subscriber_logs_stream<'a>(
+        client: &'a Client,
+        subscriber_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Log, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_subscriber_logs(client, subscriber_id, Some(queries))
+        })
     }
 
     /// List topics
@@ -1341,9 +2004,11 @@ impl Messaging {
     /// Get a list of all topics from the current Appwrite project.
     pub async fn list_topics(
         client: &Client,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<TopicList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/messaging/topics";
 
@@ -1358,7 +2023,22 @@ impl Messaging {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List topics (stream)
+    ///
+    /// Every result of [`Self::list_topics`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_topics_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Topic, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_topics(client, Some(queries), search.clone())
+        })
     }
 
     /// Create topic
@@ -1385,7 +2065,7 @@ impl Messaging {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get topic
@@ -1410,7 +2090,7 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update topic
@@ -1443,7 +2123,7 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete topic
@@ -1476,8 +2156,10 @@ impl Messaging {
     pub async fn list_topic_logs(
         client: &Client,
         topic_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<LogList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = format!("/messaging/topics/{}/logs", topic_id);
 
@@ -1497,7 +2179,22 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List topic logs (stream)
+    ///
+    /// Every result of [`Self::list_topic_logs`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_topic_logs_stream<'a>(
+        client: &'a Client,
+        topic_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Log, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_topic_logs(client, topic_id, Some(queries))
+        })
     }
 
     /// List subscribers
@@ -1506,9 +2203,11 @@ impl Messaging {
     pub async fn list_subscribers(
         client: &Client,
         topic_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<SubscriberList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = format!("/messaging/topics/{}/subscribers", topic_id);
 
@@ -1529,7 +2228,23 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List subscribers (stream)
+    ///
+    /// Every result of [`Self::list_subscribers`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_subscribers_stream<'a>(
+        client: &'a Client,
+        topic_id: &'a str,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Subscriber, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_subscribers(client, topic_id, Some(queries), search.clone())
+        })
     }
 
     /// Create subscriber
@@ -1561,7 +2276,7 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get subscriber
@@ -1593,7 +2308,7 @@ impl Messaging {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     // Delete subscriber
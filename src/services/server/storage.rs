@@ -13,6 +13,9 @@ use crate::{
     models::{
         bucket::Bucket, bucket_list::BucketList, file::File, file_list::FileList, UploadType,
     },
+    pagination::paginate,
+    params::storage::{CreateBucket, GetFilePreview, UpdateBucket},
+    query::{check_queries, Query},
     upload_progress::UploadProgress,
     utils::get_content_header_value,
 };
@@ -26,9 +29,11 @@ impl Storage {
     /// filter your results.
     pub async fn list_buckets(
         client: &Client,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<BucketList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/storage/buckets";
 
@@ -43,7 +48,22 @@ impl Storage {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List buckets (stream)
+    ///
+    /// Every result of [`Self::list_buckets`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_buckets_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Bucket, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_buckets(client, Some(queries), search.clone())
+        })
     }
 
     /// Create bucket
@@ -84,7 +104,32 @@ impl Storage {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Storage::create_bucket`] taking a [`CreateBucket`] builder, validated before sending.
+    pub async fn create_bucket_with(
+        client: &Client,
+        params: CreateBucket,
+    ) -> Result<Bucket, Error> {
+        params.validate()?;
+        Self::create_bucket(
+            client,
+            &params.bucket_id,
+            &params.name,
+            params.permissions,
+            params.file_security,
+            params.enabled,
+            params.maximum_file_size,
+            params
+                .allowed_file_extensions
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.compression,
+            params.encryption,
+            params.antivirus,
+        )
+        .await
     }
 
     /// Get bucket
@@ -109,7 +154,7 @@ impl Storage {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update bucket
@@ -155,7 +200,32 @@ impl Storage {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Storage::update_bucket`] taking a [`UpdateBucket`] builder, validated before sending.
+    pub async fn update_bucket_with(
+        client: &Client,
+        params: UpdateBucket,
+    ) -> Result<Bucket, Error> {
+        params.validate()?;
+        Self::update_bucket(
+            client,
+            &params.bucket_id,
+            &params.name,
+            params.permissions,
+            params.file_security,
+            params.enabled,
+            params.maximum_file_size,
+            params
+                .allowed_file_extensions
+                .as_ref()
+                .map(|v| v.iter().map(String::as_str).collect()),
+            params.compression,
+            params.encryption,
+            params.antivirus,
+        )
+        .await
     }
 
     /// Delete bucket
@@ -189,9 +259,11 @@ impl Storage {
     pub async fn list_files(
         client: &Client,
         bucket_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<FileList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/storage/buckets/{bucketId}/files".replace("{bucketId}", bucket_id);
 
@@ -212,7 +284,23 @@ impl Storage {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List files (stream)
+    ///
+    /// Every result of [`Self::list_files`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_files_stream<'a>(
+        client: &'a Client,
+        bucket_id: &'a str,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<File, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_files(client, bucket_id, Some(queries), search.clone())
+        })
     }
 
     /// Create file
@@ -338,7 +426,7 @@ impl Storage {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update file
@@ -374,7 +462,7 @@ impl Storage {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete File
@@ -436,7 +524,7 @@ impl Storage {
             )
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 
     /// Get file preview
@@ -495,7 +583,32 @@ impl Storage {
             )
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
+    }
+
+    /// [`Storage::get_file_preview`] taking a [`GetFilePreview`] builder, validated before sending.
+    pub async fn get_file_preview_with(
+        client: &Client,
+        params: GetFilePreview,
+    ) -> Result<Vec<u8>, Error> {
+        params.validate()?;
+        Self::get_file_preview(
+            client,
+            &params.bucket_id,
+            &params.file_id,
+            params.width,
+            params.height,
+            params.gravity,
+            params.quality,
+            params.border_width,
+            params.border_color.as_deref(),
+            params.border_radius,
+            params.opacity,
+            params.rotation,
+            params.background.as_deref(),
+            params.output,
+        )
+        .await
     }
 
     /// Get file for view
@@ -530,6 +643,6 @@ impl Storage {
             )
             .await?;
 
-        Ok(res.bytes().await?.to_vec())
+        Ok(res.bytes())
     }
 }
@@ -3,11 +3,20 @@
 ///! them to share read and write access to your project resources
 use serde_json::{Map, Value};
 
+use futures_util::Stream;
+
 use crate::{
-    api_params, app_json_header, client::Client, enumm::HttpMethod, error::Error, models::{
+    api_params, app_json_header,
+    client::Client,
+    enumm::HttpMethod,
+    error::Error,
+    models::{
         membership::Membership, membership_list::MembershipList, preferences::Preferences,
         team::Team, team_list::TeamList,
-    }
+    },
+    pagination::paginate,
+    params::teams::CreateMembership,
+    query::{check_queries, Query},
 };
 
 pub struct Teams;
@@ -19,9 +28,11 @@ impl Teams {
     /// use the parameters to filter your results.
     pub async fn list(
         client: &Client,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<TeamList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/teams";
 
@@ -36,7 +47,22 @@ impl Teams {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List teams (stream)
+    ///
+    /// Every result of [`Self::list`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Team, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list(client, Some(queries), search.clone())
+        })
     }
 
     /// Create team
@@ -65,7 +91,7 @@ impl Teams {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get team
@@ -89,7 +115,7 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update name
@@ -115,7 +141,7 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete team
@@ -150,9 +176,11 @@ impl Teams {
     pub async fn list_memberships(
         client: &Client,
         team_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<MembershipList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/teams/{teamId}/memberships".replace("{teamId}", team_id);
 
@@ -173,7 +201,23 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List team memberships (stream)
+    ///
+    /// Every result of [`Self::list_memberships`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_memberships_stream<'a>(
+        client: &'a Client,
+        team_id: &'a str,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Membership, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_memberships(client, team_id, Some(queries), search.clone())
+        })
     }
 
     /// Create team membership
@@ -233,7 +277,26 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Teams::create_memberships`] taking a [`CreateMembership`] builder, validated before sending.
+    pub async fn create_memberships_with(
+        client: &Client,
+        params: CreateMembership,
+    ) -> Result<Membership, Error> {
+        params.validate()?;
+        Self::create_memberships(
+            client,
+            &params.team_id,
+            params.roles.iter().map(String::as_str).collect(),
+            params.email.as_deref(),
+            params.user_id.as_deref(),
+            params.phone.as_deref(),
+            params.url.as_deref(),
+            params.name.as_deref(),
+        )
+        .await
     }
 
     /// Get team membership
@@ -264,7 +327,7 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update membership
@@ -300,7 +363,7 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete team membership
@@ -373,7 +436,7 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get team preferences
@@ -399,7 +462,7 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update preferences
@@ -431,6 +494,6 @@ impl Teams {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 }
@@ -4,6 +4,8 @@
 
 use serde_json::{json, Map, Value};
 
+use futures_util::Stream;
+
 use crate::{
     api_params, app_json_header,
     client::Client,
@@ -14,11 +16,15 @@ use crate::{
     },
     error::Error,
     models::{
-        identity_list::IdentityList, log_list::LogList, membership_list::MembershipList,
-        mfa_factors::MfaFactors, mfa_recovery_codes::MfaRecoveryCodes, preferences::Preferences,
-        session::Session, session_list::SessionList, target::Target, target_list::TargetList,
-        token::Token, user::User, user_list::UserList,
+        identity::Identity, identity_list::IdentityList, log::Log, log_list::LogList,
+        membership_list::MembershipList, mfa_factors::MfaFactors,
+        mfa_recovery_codes::MfaRecoveryCodes, preferences::Preferences, session::Session,
+        session_list::SessionList, target::Target, target_list::TargetList, token::Token,
+        user::User, user_list::UserList,
     },
+    pagination::paginate,
+    params::users::{CreateScryptUser, CreateUser},
+    query::{check_queries, Query},
 };
 
 pub struct Users;
@@ -30,9 +36,11 @@ impl Users {
     /// filter your results.
     pub async fn list(
         client: &Client,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<UserList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/users";
 
@@ -47,7 +55,22 @@ impl Users {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List users (stream)
+    ///
+    /// Every result of [`Self::list`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<User, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list(client, Some(queries), search.clone())
+        })
     }
 
     /// Create user
@@ -78,7 +101,21 @@ impl Users {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Users::create`] taking a [`CreateUser`] builder, validated before sending.
+    pub async fn create_with(client: &Client, params: CreateUser) -> Result<User, Error> {
+        params.validate()?;
+        Self::create(
+            client,
+            &params.user_id,
+            params.email.as_deref(),
+            params.phone.as_deref(),
+            params.password.as_deref(),
+            params.name.as_deref(),
+        )
+        .await
     }
 
     /// Create user with Argon2 password
@@ -110,7 +147,7 @@ impl Users {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create user with bcrypt password
@@ -142,7 +179,7 @@ impl Users {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List Identities
@@ -150,9 +187,11 @@ impl Users {
     /// Get identities for all users.
     pub async fn list_identities(
         client: &Client,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<String>,
     ) -> Result<IdentityList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/users/identities";
 
@@ -167,7 +206,22 @@ impl Users {
             .call(HttpMethod::GET, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List Identities (stream)
+    ///
+    /// Every result of [`Self::list_identities`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_identities_stream<'a>(
+        client: &'a Client,
+        queries: Option<Vec<Query>>,
+        search: Option<String>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Identity, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_identities(client, Some(queries), search.clone())
+        })
     }
 
     /// Delete Identity
@@ -223,7 +277,7 @@ impl Users {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create user with PHPass password
@@ -255,7 +309,7 @@ impl Users {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create user with Scrypt password
@@ -297,7 +351,29 @@ impl Users {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// [`Users::create_scrypt_user`] taking a [`CreateScryptUser`] builder, validated before sending.
+    pub async fn create_scrypt_user_with(
+        client: &Client,
+        params: CreateScryptUser,
+    ) -> Result<User, Error> {
+        params.validate()?;
+        // The cost parameters are set, `validate` checked them.
+        Self::create_scrypt_user(
+            client,
+            &params.user_id,
+            &params.email,
+            &params.password,
+            &params.password_salt,
+            params.password_cpu.unwrap_or_default(),
+            params.password_memory.unwrap_or_default(),
+            params.password_parallel.unwrap_or_default(),
+            params.password_length.unwrap_or_default(),
+            params.name.as_deref(),
+        )
+        .await
     }
 
     /// Create user with Scrypt modified password
@@ -336,7 +412,7 @@ impl Users {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create user with SHA password
@@ -370,7 +446,7 @@ impl Users {
             .call(HttpMethod::POST, api_path, api_headers, &api_params, None)
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get user
@@ -394,7 +470,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete user
@@ -449,7 +525,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update user labels
@@ -484,7 +560,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List user logs
@@ -493,8 +569,10 @@ impl Users {
     pub async fn list_logs(
         client: &Client,
         user_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<LogList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/users/{userId}/logs".replace("{userId}", user_id);
 
@@ -514,7 +592,22 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List user logs (stream)
+    ///
+    /// Every result of [`Self::list_logs`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_logs_stream<'a>(
+        client: &'a Client,
+        user_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Log, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_logs(client, user_id, Some(queries))
+        })
     }
 
     /// List user memberships
@@ -538,7 +631,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update MFA
@@ -564,7 +657,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete Authenticator
@@ -592,7 +685,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List Factors
@@ -616,7 +709,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get MFA Recovery Codes
@@ -646,7 +739,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Regenerate MFA Recovery Codes
@@ -676,7 +769,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create MFA Recovery Codes
@@ -706,7 +799,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update name
@@ -732,7 +825,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update password
@@ -762,7 +855,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update phone
@@ -788,7 +881,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get user preferences
@@ -812,7 +905,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update user preferences
@@ -844,7 +937,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List user sessions
@@ -868,7 +961,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Create session
@@ -897,7 +990,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete user sessions
@@ -982,7 +1075,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// List User Targets
@@ -991,8 +1084,10 @@ impl Users {
     pub async fn list_targets(
         client: &Client,
         user_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
     ) -> Result<TargetList, Error> {
+        check_queries(&queries)?;
+
         //const API_PATH: &str = "/functions";
         let api_path = "/users/{userId}/targets".replace("{userId}", user_id);
 
@@ -1012,7 +1107,22 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
+    }
+
+    /// List User Targets (stream)
+    ///
+    /// Every result of [`Self::list_targets`], fetched page by page with
+    /// `page_size` items per request. See [`crate::pagination`].
+    pub fn list_targets_stream<'a>(
+        client: &'a Client,
+        user_id: &'a str,
+        queries: Option<Vec<Query>>,
+        page_size: Option<usize>,
+    ) -> impl Stream<Item = Result<Target, Error>> + 'a {
+        paginate(queries, page_size, move |queries| {
+            Self::list_targets(client, user_id, Some(queries))
+        })
     }
 
     /// Create User Target
@@ -1050,7 +1160,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Get User Target
@@ -1080,7 +1190,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update User target
@@ -1117,7 +1227,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Delete user target
@@ -1183,7 +1293,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update email verification
@@ -1213,7 +1323,7 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 
     /// Update phone verification
@@ -1243,6 +1353,6 @@ impl Users {
             )
             .await?;
 
-        Ok(res.json().await?)
+        res.json()
     }
 }
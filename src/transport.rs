@@ -0,0 +1,344 @@
+//! # Transport
+//!
+//! The HTTP layer [`Client`](crate::client::Client) sends its requests
+//! through. [`ReqwestTransport`] is used unless another [`Transport`] is set
+//! with [`ClientBuilder::set_transport`](crate::client::ClientBuilder::set_transport),
+//! e.g. [`CannedTransport`], an in-process fake returning canned JSON in
+//! tests:
+//!
+//! ```
+//! use serde_json::json;
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::services::server::users::Users;
+//! use unofficial_appwrite::transport::CannedTransport;
+//!
+//! # async fn run() -> Result<(), unofficial_appwrite::error::Error> {
+//! let transport = CannedTransport::new().json("/users", json!({"total": 0, "users": []}));
+//! let client = ClientBuilder::default()
+//!     .set_transport(transport.clone())?
+//!     .build()?;
+//!
+//! assert_eq!(Users::list(&client, None, None).await?.total, 0);
+//! assert_eq!(transport.paths(), ["/v1/users"]);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use futures_util::future::BoxFuture;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
+    multipart::{Form, Part},
+    StatusCode,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{enumm::HttpMethod, error::Error};
+
+/// Sends a fully prepared [`HttpRequest`] and returns the buffered response.
+///
+/// Implementations only move bytes: status codes are turned into errors by the
+/// client, so a 404 must be returned as an `Ok` response with status 404.
+pub trait Transport: Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>>;
+}
+
+/// Request handed to a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    /// Absolute URL, including the query string for `GET` requests.
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: RequestBody,
+}
+
+/// Body of an [`HttpRequest`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestBody {
+    Empty,
+    Json(Value),
+    Multipart(MultipartForm),
+}
+
+/// A `multipart/form-data` body, kept in memory so it can be inspected,
+/// recorded or sent again on retry.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MultipartForm {
+    pub parts: Vec<MultipartPart>,
+}
+
+/// One field of a [`MultipartForm`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MultipartPart {
+    Text {
+        name: String,
+        value: String,
+    },
+    File {
+        name: String,
+        file_name: String,
+        bytes: Vec<u8>,
+    },
+}
+
+impl MultipartForm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a text field.
+    pub fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(MultipartPart::Text {
+            name: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Add a file field.
+    pub fn file(
+        mut self,
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        bytes: Vec<u8>,
+    ) -> Self {
+        self.parts.push(MultipartPart::File {
+            name: name.into(),
+            file_name: file_name.into(),
+            bytes,
+        });
+        self
+    }
+}
+
+impl From<MultipartForm> for Form {
+    fn from(form: MultipartForm) -> Self {
+        form.parts
+            .into_iter()
+            .fold(Form::new(), |form, part| match part {
+                MultipartPart::Text { name, value } => form.text(name, value),
+                MultipartPart::File {
+                    name,
+                    file_name,
+                    bytes,
+                } => form.part(name, Part::bytes(bytes).file_name(file_name)),
+            })
+    }
+}
+
+/// Buffered response returned by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// A response with a JSON body.
+    pub fn from_json(status: StatusCode, body: &Value) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        Self {
+            status,
+            headers,
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    /// Add a response header, e.g. `set-cookie`.
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Deserialize the JSON body.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// The body as text, replacing invalid UTF-8.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// The raw body.
+    pub fn bytes(self) -> Vec<u8> {
+        self.body
+    }
+}
+
+/// Default [`Transport`], backed by a pooled `reqwest` client.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    http: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(http: reqwest::Client) -> Self {
+        Self { http }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            let req = match request.method {
+                HttpMethod::GET => self.http.get(request.url),
+                HttpMethod::POST => self.http.post(request.url),
+                HttpMethod::PUT => self.http.put(request.url),
+                HttpMethod::DELETE => self.http.delete(request.url),
+                HttpMethod::PATCH => self.http.patch(request.url),
+            };
+            let mut headers = request.headers;
+            let req = match request.body {
+                RequestBody::Empty => req.headers(headers),
+                RequestBody::Json(body) => req.headers(headers).json(&body),
+                RequestBody::Multipart(form) => {
+                    // reqwest sets the content type itself, with the form's boundary.
+                    headers.remove(CONTENT_TYPE);
+                    req.headers(headers).multipart(form.into())
+                }
+            };
+            let res = req.send().await?;
+            Ok(HttpResponse {
+                status: res.status(),
+                headers: res.headers().clone(),
+                body: res.bytes().await?.to_vec(),
+            })
+        })
+    }
+}
+
+/// In-process [`Transport`] answering with canned responses and recording
+/// every request, for tests.
+///
+/// Responses are matched on the end of the request path, in the order the
+/// paths were added. Several responses for the same path are returned one
+/// after the other, the last one for every request after it. Requests no
+/// path matches get a `404` with an Appwrite error body, unless a
+/// [`fallback`](Self::fallback) is set. Clones share the responses and the
+/// request log.
+#[derive(Debug, Clone, Default)]
+pub struct CannedTransport {
+    state: Arc<Mutex<CannedState>>,
+}
+
+#[derive(Debug, Default)]
+struct CannedState {
+    routes: Vec<(String, VecDeque<Canned>)>,
+    fallback: Option<Canned>,
+    requests: Vec<HttpRequest>,
+}
+
+#[derive(Debug, Clone)]
+enum Canned {
+    Response(HttpResponse),
+    /// Fail with [`Error::Custom`], like a connection error.
+    Error(String),
+}
+
+impl CannedTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer requests whose path ends with `path` with a `200` JSON `body`.
+    pub fn json(self, path: impl Into<String>, body: Value) -> Self {
+        self.respond(path, HttpResponse::from_json(StatusCode::OK, &body))
+    }
+
+    /// Answer requests whose path ends with `path` with `response`.
+    pub fn respond(self, path: impl Into<String>, response: HttpResponse) -> Self {
+        self.push(path.into(), Canned::Response(response))
+    }
+
+    /// Fail requests whose path ends with `path` with [`Error::Custom`].
+    pub fn fail(self, path: impl Into<String>, message: impl Into<String>) -> Self {
+        self.push(path.into(), Canned::Error(message.into()))
+    }
+
+    /// Answer requests no path matches with `response`.
+    pub fn fallback(self, response: HttpResponse) -> Self {
+        self.state.lock().unwrap().fallback = Some(Canned::Response(response));
+        self
+    }
+
+    fn push(self, path: String, canned: Canned) -> Self {
+        let mut state = self.state.lock().unwrap();
+        match state.routes.iter_mut().find(|(route, _)| *route == path) {
+            Some((_, responses)) => responses.push_back(canned),
+            None => state.routes.push((path, VecDeque::from([canned]))),
+        }
+        drop(state);
+        self
+    }
+
+    /// Every request sent so far.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// The path of every request sent so far, without the query string.
+    pub fn paths(&self) -> Vec<String> {
+        self.requests()
+            .iter()
+            .map(|request| request_path(&request.url).to_string())
+            .collect()
+    }
+}
+
+/// Path of an absolute URL, e.g. `/v1/users` for `https://host/v1/users?x=1`.
+fn request_path(url: &str) -> &str {
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    let path = url.split_once("://").map_or(url, |(_, rest)| rest);
+    path.find('/').map_or("/", |start| &path[start..])
+}
+
+impl Transport for CannedTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        let mut state = self.state.lock().unwrap();
+        let path = request_path(&request.url).to_string();
+        let canned = match state
+            .routes
+            .iter_mut()
+            .find(|(route, _)| path.ends_with(route.as_str()))
+        {
+            Some((_, responses)) if responses.len() > 1 => responses.pop_front(),
+            Some((_, responses)) => responses.front().cloned(),
+            None => state.fallback.clone(),
+        };
+        state.requests.push(request);
+        drop(state);
+        let canned = canned.unwrap_or_else(|| {
+            let body = serde_json::json!({
+                "message": format!("no canned response for {path}"),
+                "code": 404,
+                "type": "general_route_not_found",
+                "version": "1.5.0",
+            });
+            Canned::Response(HttpResponse::from_json(StatusCode::NOT_FOUND, &body))
+        });
+        Box::pin(async move {
+            match canned {
+                Canned::Response(response) => Ok(response),
+                Canned::Error(message) => Err(Error::Custom(message)),
+            }
+        })
+    }
+}
@@ -29,7 +29,41 @@ macro_rules! app_json_header {
 
 pub fn get_content_header_value<'a>(client: &'a Client, value: &'a str) -> Option<&'a str> {
     client
-        .header
-        .get(format!("x-appwrite-{value}"))
+        .header(&format!("x-appwrite-{value}"))
         .and_then(|g| g.to_str().ok())
 }
+
+/// Emit a `tracing` event when the `tracing` feature is enabled; expands to
+/// nothing otherwise.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    };
+}
+pub(crate) use trace_event;
+
+/// Query parameters whose values are credentials and must never be recorded.
+#[cfg(any(feature = "tracing", feature = "cassette"))]
+pub(crate) const SENSITIVE_PARAMS: &[&str] =
+    &["key", "secret", "jwt", "session", "password", "token"];
+
+/// Replace the values of credential-bearing query parameters in `url` with
+/// `[REDACTED]`.
+#[cfg(feature = "tracing")]
+pub(crate) fn redact_url(url: &str) -> String {
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_string();
+    };
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if SENSITIVE_PARAMS.contains(&key.to_lowercase().as_str()) => {
+                format!("{key}=[REDACTED]")
+            }
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{base}?{query}")
+}
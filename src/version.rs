@@ -0,0 +1,135 @@
+//! # Version
+//!
+//! The Appwrite server versions this crate's models were written for, and how
+//! a [`Client`](crate::client::Client) reacts to other versions.
+//!
+//! ```no_run
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//! use unofficial_appwrite::version::{VersionCheck, RESPONSE_FORMAT};
+//!
+//! # async fn run() -> Result<(), Error> {
+//! let client = ClientBuilder::default()
+//!     .set_project("5ff3...")?
+//!     .set_response_format(RESPONSE_FORMAT)?
+//!     .set_version_check(VersionCheck::Error)?
+//!     .build()?;
+//!
+//! println!("server runs Appwrite {}", client.server_version().await?);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use crate::{error::Error, query::QuerySyntax};
+
+/// The API version the models in [`crate::models`] deserialize, for
+/// [`ClientBuilder::set_response_format`](crate::client::ClientBuilder::set_response_format).
+pub const RESPONSE_FORMAT: &str = "1.5.0";
+
+/// Oldest supported server version.
+pub const MIN_SUPPORTED: ServerVersion = ServerVersion::new(1, 5, 0);
+
+/// Oldest supported server version when queries are written in
+/// [`QuerySyntax::Legacy`].
+pub const MIN_SUPPORTED_LEGACY: ServerVersion = ServerVersion::new(1, 4, 0);
+
+/// First server version that is no longer supported.
+pub const MAX_SUPPORTED_EXCLUSIVE: ServerVersion = ServerVersion::new(1, 6, 0);
+
+/// What a client does when the server version is outside
+/// [`MIN_SUPPORTED`]..[`MAX_SUPPORTED_EXCLUSIVE`] (from [`MIN_SUPPORTED_LEGACY`]
+/// with [`QuerySyntax::Legacy`]). The version is probed before the first
+/// request; if it can't be determined the client goes on and probes again
+/// later, backing off after every failure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VersionCheck {
+    /// Don't probe the server version.
+    #[default]
+    Off,
+    /// Emit a `tracing` warning (with the `tracing` feature) and go on; read
+    /// the version with
+    /// [`Client::server_version`](crate::client::Client::server_version).
+    Warn,
+    /// Fail every request with [`Error::UnsupportedServerVersion`].
+    Error,
+}
+
+/// An Appwrite server version, e.g. `1.5.7`. Pre-release suffixes are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServerVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl ServerVersion {
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Whether this crate's models were written for this version.
+    pub fn is_supported(&self) -> bool {
+        self.is_supported_with(QuerySyntax::Json)
+    }
+
+    /// Whether this version is supported by a client writing queries in
+    /// `syntax`.
+    pub fn is_supported_with(&self, syntax: QuerySyntax) -> bool {
+        (Self::min_supported(syntax)..MAX_SUPPORTED_EXCLUSIVE).contains(self)
+    }
+
+    /// Oldest server version supported by a client writing queries in `syntax`.
+    pub const fn min_supported(syntax: QuerySyntax) -> ServerVersion {
+        match syntax {
+            QuerySyntax::Json => MIN_SUPPORTED,
+            QuerySyntax::Legacy => MIN_SUPPORTED_LEGACY,
+        }
+    }
+}
+
+impl FromStr for ServerVersion {
+    type Err = Error;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::Custom(format!("invalid Appwrite version {version:?}"));
+        let release = version.trim().split(['-', '+']).next().unwrap_or_default();
+        let mut parts = release.split('.').map(|part| part.parse::<u64>());
+        let major = parts.next().ok_or_else(invalid)?.map_err(|_| invalid())?;
+        let minor = parts.next().unwrap_or(Ok(0)).map_err(|_| invalid())?;
+        let patch = parts.next().unwrap_or(Ok(0)).map_err(|_| invalid())?;
+        Ok(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_support_range() {
+        let version: ServerVersion = "1.5.7".parse().unwrap();
+        assert_eq!(version, ServerVersion::new(1, 5, 7));
+        assert!(version.is_supported());
+        assert_eq!(
+            "1.6.0-RC1".parse::<ServerVersion>().unwrap(),
+            MAX_SUPPORTED_EXCLUSIVE
+        );
+        let legacy = "1.4.13".parse::<ServerVersion>().unwrap();
+        assert!(!legacy.is_supported());
+        assert!(legacy.is_supported_with(QuerySyntax::Legacy));
+        assert!(!ServerVersion::new(1, 3, 0).is_supported_with(QuerySyntax::Legacy));
+        assert!("latest".parse::<ServerVersion>().is_err());
+    }
+}
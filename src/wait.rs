@@ -0,0 +1,301 @@
+//! # Waiting for provisioning
+//!
+//! Appwrite creates attributes and indexes in the background: they start out
+//! `processing` and documents can't use them until they are `available`.
+//! [`Databases::wait_for_attribute`] and [`Databases::wait_for_index`] poll
+//! with backoff until then, and fail with [`Error::ProvisioningFailed`] on
+//! `failed` / `stuck` or [`Error::ProvisioningTimeout`].
+//!
+//! ```no_run
+//! use unofficial_appwrite::client::ClientBuilder;
+//! use unofficial_appwrite::error::Error;
+//! use unofficial_appwrite::services::server::databases::Databases;
+//!
+//! # async fn run() -> Result<(), Error> {
+//! let client = ClientBuilder::default().set_project("5ff3...")?.build()?;
+//!
+//! Databases::create_string_attribute(&client, "blog", "posts", "title", 255, true, None, None, None)
+//!     .await?;
+//! Databases::wait_for_attribute(&client, "blog", "posts", "title", None).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Databases::wait_for_attribute`]: crate::services::server::databases::Databases::wait_for_attribute
+//! [`Databases::wait_for_index`]: crate::services::server::databases::Databases::wait_for_index
+
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use serde_json::Value;
+
+use crate::{
+    client::Client,
+    error::{AppwriteErrorKind, Error},
+    models::index::Index,
+    services::server::databases::Databases,
+};
+
+/// How long and how often to poll.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaitOptions {
+    /// Give up with [`Error::ProvisioningTimeout`] after this long.
+    pub timeout: Duration,
+    /// Delay before the second poll. Doubled for every following one.
+    pub base_delay: Duration,
+    /// Upper bound for a single delay.
+    pub max_delay: Duration,
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(60),
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+/// The outcome of one poll.
+pub(crate) enum Poll<T> {
+    Ready(T),
+    Pending(String),
+}
+
+/// Call `check` with backoff until it is ready or `options.timeout` expires.
+pub(crate) async fn poll<T, F, Fut>(
+    options: &WaitOptions,
+    resource: &'static str,
+    key: &str,
+    mut check: F,
+) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Poll<T>, Error>>,
+{
+    let start = Instant::now();
+    let mut delay = options.base_delay;
+    loop {
+        let status = match check().await? {
+            Poll::Ready(ready) => return Ok(ready),
+            Poll::Pending(status) => status,
+        };
+        let left = options.timeout.saturating_sub(start.elapsed());
+        if left.is_zero() {
+            return Err(Error::ProvisioningTimeout {
+                resource,
+                key: key.to_string(),
+                status,
+                timeout: options.timeout,
+            });
+        }
+        tokio::time::sleep(delay.min(left)).await;
+        delay = delay.saturating_mul(2).min(options.max_delay);
+    }
+}
+
+/// Ready once `status` is `available`, an error once it is `failed` or
+/// `stuck`.
+fn provisioned<T>(
+    resource: &'static str,
+    key: &str,
+    status: &str,
+    message: &str,
+    value: T,
+) -> Result<Poll<T>, Error> {
+    match status {
+        "available" => Ok(Poll::Ready(value)),
+        "failed" | "stuck" => Err(Error::ProvisioningFailed {
+            resource,
+            key: key.to_string(),
+            status: status.to_string(),
+            message: message.to_string(),
+        }),
+        status => Ok(Poll::Pending(status.to_string())),
+    }
+}
+
+impl Databases {
+    /// Poll an attribute until it is `available` and return it.
+    pub async fn wait_for_attribute(
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        options: Option<WaitOptions>,
+    ) -> Result<Value, Error> {
+        poll(&options.unwrap_or_default(), "attribute", key, || async {
+            let attribute = Self::get_attribute(client, database_id, collection_id, key).await?;
+            let text = |name| attribute[name].as_str().unwrap_or_default().to_string();
+            let (status, message) = (text("status"), text("error"));
+            provisioned("attribute", key, &status, &message, attribute)
+        })
+        .await
+    }
+
+    /// Poll an index until it is `available` and return it.
+    pub async fn wait_for_index(
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        options: Option<WaitOptions>,
+    ) -> Result<Index, Error> {
+        poll(&options.unwrap_or_default(), "index", key, || async {
+            let index = Self::get_index(client, database_id, collection_id, key).await?;
+            let (status, message) = (index.status.clone(), index.error.clone());
+            provisioned("index", key, &status, &message, index)
+        })
+        .await
+    }
+
+    /// Poll until a deleted attribute is gone, so its key can be reused.
+    pub(crate) async fn wait_for_attribute_deletion(
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        options: &WaitOptions,
+    ) -> Result<(), Error> {
+        poll(options, "attribute", key, || async {
+            match Self::get_attribute(client, database_id, collection_id, key).await {
+                Ok(attribute) => Ok(Poll::Pending(
+                    attribute["status"].as_str().unwrap_or_default().to_string(),
+                )),
+                Err(err) if err.kind() == Some(AppwriteErrorKind::AttributeNotFound) => {
+                    Ok(Poll::Ready(()))
+                }
+                Err(err) => Err(err),
+            }
+        })
+        .await
+    }
+
+    /// Poll until a deleted index is gone, so its key can be reused.
+    pub(crate) async fn wait_for_index_deletion(
+        client: &Client,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        options: &WaitOptions,
+    ) -> Result<(), Error> {
+        poll(options, "index", key, || async {
+            match Self::get_index(client, database_id, collection_id, key).await {
+                Ok(index) => Ok(Poll::Pending(index.status)),
+                Err(err) if err.kind() == Some(AppwriteErrorKind::IndexNotFound) => {
+                    Ok(Poll::Ready(()))
+                }
+                Err(err) => Err(err),
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_poll_backoff_and_errors() {
+        let options = WaitOptions {
+            timeout: Duration::from_secs(1),
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(4),
+        };
+        let polls = Cell::new(0);
+        let key = poll(&options, "attribute", "title", || {
+            polls.set(polls.get() + 1);
+            let status = if polls.get() < 4 {
+                "processing"
+            } else {
+                "available"
+            };
+            async move { provisioned("attribute", "title", status, "", "title") }
+        })
+        .await
+        .unwrap();
+        assert_eq!((key, polls.get()), ("title", 4));
+
+        let err = poll(&options, "index", "title_index", || async {
+            provisioned("index", "title_index", "failed", "attribute missing", ())
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "index `title_index` is failed: attribute missing"
+        );
+
+        let options = WaitOptions {
+            timeout: Duration::from_millis(10),
+            ..options
+        };
+        let err = poll(&options, "attribute", "title", || async {
+            provisioned("attribute", "title", "processing", "", ())
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ProvisioningTimeout { status, .. } if status == "processing"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_attribute_with_wait() {
+        use serde_json::json;
+
+        use crate::{
+            client::ClientBuilder, params::databases::CreateStringAttribute,
+            transport::CannedTransport,
+        };
+
+        let attribute = |status| json!({"key": "title", "type": "string", "status": status, "error": "", "size": 255});
+        let transport = CannedTransport::new()
+            .json("/attributes/string", attribute("processing"))
+            .json("/attributes/title", attribute("processing"))
+            .json("/attributes/title", attribute("available"));
+        let polls = || {
+            transport
+                .paths()
+                .iter()
+                .filter(|path| path.ends_with("/attributes/title"))
+                .count()
+        };
+        let client = ClientBuilder::default()
+            .set_transport(transport.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        let params = CreateStringAttribute::new("blog", "posts", "title", 255, true);
+        let attribute = Databases::create_string_attribute_with(&client, params.clone())
+            .await
+            .unwrap();
+        assert_eq!((attribute.status.as_str(), polls()), ("processing", 0));
+
+        let options = WaitOptions {
+            timeout: Duration::ZERO,
+            ..Default::default()
+        };
+        let err = Databases::create_string_attribute_with(&client, params.clone().wait(options))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ProvisioningTimeout { .. }));
+        assert_eq!(polls(), 1);
+
+        let options = WaitOptions {
+            base_delay: Duration::from_millis(1),
+            ..Default::default()
+        };
+        let attribute = Databases::create_string_attribute_with(&client, params.wait(options))
+            .await
+            .unwrap();
+        assert_eq!((attribute.status.as_str(), polls()), ("available", 2));
+    }
+}